@@ -61,6 +61,12 @@ pub struct Cli {
     /// telemetry, if telemetry is enabled.
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
+
+    /// Print the resolved EVM tracing configuration as JSON and exit.
+    ///
+    /// Useful for verifying that the EVM tracing flags are parsed as expected.
+    #[arg(long)]
+    pub print_evm_tracing_config: bool,
 }
 
 /// Possible subcommands of the main binary.
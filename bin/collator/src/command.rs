@@ -176,6 +176,14 @@ impl SubstrateCli for RelayChainCli {
 pub fn run() -> Result<()> {
     let cli = Cli::from_args();
 
+    if cli.print_evm_tracing_config {
+        let evm_tracing_config = cli.eth_api_options.new_rpc_config();
+        let output = serde_json::to_string_pretty(&evm_tracing_config)
+            .map_err(|err| format!("Failed to serialize EVM tracing config: {}", err))?;
+        println!("{}", output);
+        return Ok(());
+    }
+
     match &cli.subcommand {
         Some(Subcommand::BuildSpec(cmd)) => {
             let runner = cli.create_runner(cmd)?;
@@ -18,9 +18,10 @@
 
 use crate::rpc::FrontierBackendType;
 use clap::Parser;
+use serde::Serialize;
 
 /// Defines the frontier backend configuration.
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub enum FrontierBackendConfig {
     KeyValue,
     Sql {
@@ -38,7 +39,8 @@ impl Default for FrontierBackendConfig {
 }
 
 /// EVM tracing CLI flags.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum EthApi {
     /// Enable EVM debug RPC methods.
     Debug,
@@ -67,7 +69,7 @@ impl std::str::FromStr for EthApi {
 }
 
 #[allow(dead_code)]
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 /// Overall Frontier (EVM compatibility) configuration:
 /// Controls enabled APIs, tracing, and backend storage.
 pub struct FrontierConfig {
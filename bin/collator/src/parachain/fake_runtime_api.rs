@@ -21,7 +21,13 @@
 #[cfg(feature = "runtime-benchmarks")]
 extern crate alloc;
 
-use astar_primitives::{AccountId, Balance, Block, Nonce};
+use astar_primitives::{
+    dapp_staking::{
+        DAppId, DAppTierRewardsView, EraNumber, PeriodNumber, RankedTier, SmartContract,
+        StakePreflightError, StakerStateExport, StakingConstants, TierConfigView, TierId,
+    },
+    AccountId, Balance, Block, BlockNumber, Nonce,
+};
 use ethereum::AuthorizationList;
 use frame_support::weights::Weight;
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
@@ -32,9 +38,10 @@ use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
     traits::Block as BlockT,
     transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult, Permill,
+    ApplyExtrinsicResult, Perbill, Permill,
 };
 use sp_version::RuntimeVersion;
+use std::collections::BTreeMap;
 
 pub struct Runtime;
 
@@ -292,6 +299,128 @@ impl_runtime_apis! {
         }
     }
 
+    impl dapp_staking_runtime_api::DappStakingApi<Block> for Runtime {
+        fn periods_per_cycle() -> PeriodNumber {
+            unimplemented!()
+        }
+
+        fn eras_per_voting_subperiod() -> EraNumber {
+            unimplemented!()
+        }
+
+        fn eras_per_build_and_earn_subperiod() -> EraNumber {
+            unimplemented!()
+        }
+
+        fn blocks_per_era() -> BlockNumber {
+            unimplemented!()
+        }
+
+        fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
+            unimplemented!()
+        }
+
+        fn estimate_apr(_smart_contract: SmartContract<AccountId>, _stake: Balance) -> Option<Perbill> {
+            unimplemented!()
+        }
+
+        fn staking_constants() -> StakingConstants {
+            unimplemented!()
+        }
+
+        fn dapp_current_standing(_smart_contract: SmartContract<AccountId>) -> Option<(Balance, Balance, Option<TierId>)> {
+            unimplemented!()
+        }
+
+        fn bonus_status(_account: AccountId, _smart_contract: SmartContract<AccountId>) -> Option<u8> {
+            unimplemented!()
+        }
+
+        fn period_era_range(_period: PeriodNumber) -> Option<(EraNumber, EraNumber)> {
+            unimplemented!()
+        }
+
+        fn tier_config() -> TierConfigView {
+            unimplemented!()
+        }
+
+        fn available_tier_slots() -> Vec<u16> {
+            unimplemented!()
+        }
+
+        fn history_cleanup_status() -> (EraNumber, EraNumber, u32) {
+            unimplemented!()
+        }
+
+        fn can_stake(_account: AccountId, _smart_contract: SmartContract<AccountId>, _amount: Balance) -> Result<(), StakePreflightError> {
+            unimplemented!()
+        }
+
+        fn total_unlocking() -> Balance {
+            unimplemented!()
+        }
+
+        fn dapp_lifetime_rewards(_smart_contract: SmartContract<AccountId>) -> Balance {
+            unimplemented!()
+        }
+
+        fn export_staker_state(_account: AccountId) -> StakerStateExport<SmartContract<AccountId>> {
+            unimplemented!()
+        }
+
+        fn claimed_eras(_account: AccountId) -> Vec<EraNumber> {
+            unimplemented!()
+        }
+
+        fn stake_needed_for_tier(_tier_id: TierId) -> Option<Balance> {
+            unimplemented!()
+        }
+
+        fn effective_staking_power(_smart_contract: SmartContract<AccountId>) -> Option<(Balance, TierId)> {
+            unimplemented!()
+        }
+
+        fn stake_age(_account: AccountId, _smart_contract: SmartContract<AccountId>) -> Option<(EraNumber, u32)> {
+            unimplemented!()
+        }
+
+        fn stake_expiry_warning(_account: AccountId) -> Vec<(SmartContract<AccountId>, PeriodNumber)> {
+            unimplemented!()
+        }
+
+        fn dapp_ids(_contracts: Vec<SmartContract<AccountId>>) -> Vec<Option<DAppId>> {
+            unimplemented!()
+        }
+
+        fn dapps_by_owner(_owner: AccountId) -> Vec<SmartContract<AccountId>> {
+            unimplemented!()
+        }
+
+        fn current_reward_pools() -> (Balance, Balance, Balance) {
+            unimplemented!()
+        }
+
+        fn dapp_tier_rewards(_era: EraNumber) -> Option<DAppTierRewardsView> {
+            unimplemented!()
+        }
+
+        fn retained_era_window() -> (EraNumber, EraNumber) {
+            unimplemented!()
+        }
+
+        fn reconcile_held_funds() -> (Balance, Balance, bool) {
+            unimplemented!()
+        }
+
+        fn claimable_dapp_rewards(_smart_contract: SmartContract<AccountId>) -> Vec<EraNumber> {
+            unimplemented!()
+        }
+
+        fn eras_until_subperiod_change() -> EraNumber {
+            unimplemented!()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(_extra: bool) -> (Vec<frame_benchmarking::BenchmarkList>, Vec<frame_support::traits::StorageInfo>) {
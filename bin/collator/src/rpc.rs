@@ -55,7 +55,11 @@ use moonbeam_rpc_trace::{Trace, TraceServer};
 
 use crate::evm_tracing_types::{FrontierBackendConfig, FrontierConfig};
 use astar_primitives::*;
+use dapp_staking::DappStakingApiServer;
+use dev::DevDappStakingApiServer;
 
+pub mod dapp_staking;
+pub mod dev;
 pub mod tracing;
 
 type HashFor<Block> = <Block as BlockT>::Hash;
@@ -219,7 +223,8 @@ where
         + AuraApi<Block, AuraId>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
-        + RelayParentOffsetApi<Block>,
+        + RelayParentOffsetApi<Block>
+        + dapp_staking_runtime_api::DappStakingApi<Block>,
     P: TransactionPool<Block = Block, Hash = HashFor<Block>> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -289,14 +294,17 @@ where
         + AuraApi<Block, AuraId>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
-        + RelayParentOffsetApi<Block>,
+        + RelayParentOffsetApi<Block>
+        + dapp_staking_runtime_api::DappStakingApi<Block>,
     P: TransactionPool<Block = Block, Hash = HashFor<Block>> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
     BE::Blockchain: BlockchainBackend<Block>,
 {
     let client = Arc::clone(&deps.client);
+    let pool = Arc::clone(&deps.pool);
     let graph = Arc::clone(&deps.graph);
+    let command_sink = deps.command_sink.clone();
 
     let mut io = create_full_rpc_local_dev(
         deps,
@@ -305,6 +313,10 @@ where
         local_para_id,
     )?;
 
+    if let Some(command_sink) = command_sink {
+        io.merge(dev::DevDappStaking::new(Arc::clone(&client), pool, command_sink).into_rpc())?;
+    }
+
     if tracing_config.enable_txpool {
         io.merge(TxPool::new(Arc::clone(&client), graph).into_rpc())?;
     }
@@ -357,7 +369,8 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
         + AuraApi<Block, AuraId>
-        + RelayParentOffsetApi<Block>,
+        + RelayParentOffsetApi<Block>
+        + dapp_staking_runtime_api::DappStakingApi<Block>,
     P: TransactionPool<Block = Block, Hash = HashFor<Block>> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -399,7 +412,8 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
         + AuraApi<Block, AuraId>
-        + RelayParentOffsetApi<Block>,
+        + RelayParentOffsetApi<Block>
+        + dapp_staking_runtime_api::DappStakingApi<Block>,
     P: TransactionPool<Block = Block, Hash = HashFor<Block>> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -442,7 +456,8 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
         + AuraApi<Block, AuraId>
-        + RelayParentOffsetApi<Block>,
+        + RelayParentOffsetApi<Block>
+        + dapp_staking_runtime_api::DappStakingApi<Block>,
     P: TransactionPool<Block = Block, Hash = HashFor<Block>> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -470,6 +485,7 @@ where
 
     io.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(dapp_staking::DappStaking::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone()).into_rpc())?;
 
     if let Some(command_sink) = command_sink {
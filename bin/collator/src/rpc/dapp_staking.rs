@@ -0,0 +1,151 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! dApp staking RPC methods.
+
+use dapp_staking_runtime_api::DappStakingApi as DappStakingRuntimeApi;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::INTERNAL_ERROR_CODE, ErrorObjectOwned},
+};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use std::{collections::BTreeMap, sync::Arc};
+
+use astar_primitives::{
+    dapp_staking::{DAppId, DAppTierRewardsView, EraNumber, PeriodNumber, Rank, TierId},
+    *,
+};
+
+/// Tier & rank of a dApp in the tier assignment.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DAppTierEntry {
+    pub dapp_id: DAppId,
+    pub tier: TierId,
+    pub rank: Rank,
+}
+
+/// dApp tier assignment & rewards for a finished era.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TierAssignment {
+    /// Period during which the era ended.
+    pub period: PeriodNumber,
+    /// dApps and their tiers, excluding the ones which have already claimed their reward.
+    pub dapps: Vec<DAppTierEntry>,
+    /// Reward for each tier, ordered from the best tier to the worst one.
+    pub rewards: Vec<Balance>,
+    /// Reward per rank for each tier, ordered from the best tier to the worst one.
+    pub rank_rewards: Vec<Balance>,
+    /// Undistributed reward remainders, assigned to the top dApp of each tier.
+    pub remainders: BTreeMap<DAppId, Balance>,
+}
+
+impl From<DAppTierRewardsView> for TierAssignment {
+    fn from(view: DAppTierRewardsView) -> Self {
+        Self {
+            period: view.period,
+            dapps: view
+                .dapps
+                .into_iter()
+                .map(|(dapp_id, ranked_tier)| DAppTierEntry {
+                    dapp_id,
+                    tier: ranked_tier.tier(),
+                    rank: ranked_tier.rank(),
+                })
+                .collect(),
+            rewards: view.rewards,
+            rank_rewards: view.rank_rewards,
+            remainders: view.remainders,
+        }
+    }
+}
+
+/// dApp staking RPC methods.
+#[rpc(server)]
+pub trait DappStakingApi<BlockHash> {
+    /// Total amount of funds in the unlocking process, across all accounts.
+    #[method(name = "dappStaking_totalUnlocking")]
+    fn total_unlocking(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+    /// dApp tier assignment & rewards for the given era.
+    ///
+    /// Returns `null` if there is no tier assignment for the era, e.g. for voting subperiod eras,
+    /// the ongoing era, or expired eras.
+    #[method(name = "dappStaking_tierAssignment")]
+    fn tier_assignment(
+        &self,
+        era: EraNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<TierAssignment>>;
+}
+
+/// Implements the [`DappStakingApiServer`] RPC trait using the dApp staking runtime API.
+pub struct DappStaking<C> {
+    client: Arc<C>,
+}
+
+impl<C> DappStaking<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> DappStakingApiServer<Hash> for DappStaking<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappStakingRuntimeApi<Block>,
+{
+    fn total_unlocking(&self, at: Option<Hash>) -> RpcResult<Balance> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .total_unlocking(at)
+            .map_err(|err| {
+                ErrorObjectOwned::owned(
+                    INTERNAL_ERROR_CODE,
+                    "Unable to query the total unlocking amount.",
+                    Some(err.to_string()),
+                )
+            })
+    }
+
+    fn tier_assignment(
+        &self,
+        era: EraNumber,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<TierAssignment>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .dapp_tier_rewards(at, era)
+            .map(|maybe_view| maybe_view.map(Into::into))
+            .map_err(|err| {
+                ErrorObjectOwned::owned(
+                    INTERNAL_ERROR_CODE,
+                    "Unable to query the dApp tier assignment.",
+                    Some(err.to_string()),
+                )
+            })
+    }
+}
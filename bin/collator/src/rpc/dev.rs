@@ -0,0 +1,195 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Development-only RPC methods, available exclusively on the local dev node.
+
+use futures::{channel::mpsc::Sender, SinkExt};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::{error::INTERNAL_ERROR_CODE, ErrorObjectOwned},
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::CallApiAt;
+use sc_consensus_manual_seal::{rpc::CreatedBlock, EngineCommand};
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{sr25519, Pair};
+use sp_runtime::{generic, traits::Block as BlockT};
+use std::sync::Arc;
+use substrate_frame_rpc_system::AccountNonceApi;
+
+use astar_primitives::*;
+
+/// Dev account which is the sudo key of the development chain.
+const SUDO_SEED: &str = "//Alice";
+
+/// dApp staking development RPC methods.
+#[rpc(server)]
+pub trait DevDappStakingApi {
+    /// Advances dApp staking by the specified number of eras.
+    ///
+    /// For each era, a sudo `force` call is submitted and sealed, followed by the block
+    /// in which the era change happens. Returns the last sealed block.
+    #[method(name = "dev_advanceEra")]
+    async fn advance_era(&self, eras: u32) -> RpcResult<CreatedBlock<Hash>>;
+}
+
+/// Implements the [`DevDappStakingApiServer`] RPC trait for the local dev node.
+pub struct DevDappStaking<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    command_sink: Sender<EngineCommand<Hash>>,
+}
+
+impl<C, P> DevDappStaking<C, P> {
+    pub fn new(client: Arc<C>, pool: Arc<P>, command_sink: Sender<EngineCommand<Hash>>) -> Self {
+        Self {
+            client,
+            pool,
+            command_sink,
+        }
+    }
+}
+
+fn internal_error(message: impl ToString) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INTERNAL_ERROR_CODE, message.to_string(), None::<()>)
+}
+
+impl<C, P> DevDappStaking<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + CallApiAt<Block> + Send + Sync + 'static,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+    P: TransactionPool<Block = Block, Hash = <Block as BlockT>::Hash> + Send + Sync + 'static,
+{
+    /// Creates a sudo-signed `force` call which triggers an era change in the next block.
+    fn force_era_extrinsic(&self, at: Hash) -> Result<<Block as BlockT>::Extrinsic, String> {
+        let pair = sr25519::Pair::from_string(SUDO_SEED, None)
+            .map_err(|err| format!("Invalid sudo seed: {:?}", err))?;
+        let account: AccountId = pair.public().into();
+
+        let nonce = self
+            .client
+            .runtime_api()
+            .account_nonce(at, account.clone())
+            .map_err(|err| format!("Failed to read the sudo account nonce: {}", err))?;
+        let version = self
+            .client
+            .runtime_version_at(at)
+            .map_err(|err| format!("Failed to read the runtime version: {}", err))?;
+        let genesis_hash = self.client.info().genesis_hash;
+
+        let call = shibuya_runtime::RuntimeCall::Sudo(pallet_sudo::Call::sudo {
+            call: Box::new(shibuya_runtime::RuntimeCall::DappStaking(
+                pallet_dapp_staking::Call::force {
+                    forcing_type: pallet_dapp_staking::ForcingType::Era,
+                },
+            )),
+        });
+
+        let extra: shibuya_runtime::SignedExtra = (
+            frame_system::CheckSpecVersion::new(),
+            frame_system::CheckTxVersion::new(),
+            frame_system::CheckGenesis::new(),
+            frame_system::CheckEra::from(generic::Era::Immortal),
+            frame_system::CheckNonce::from(nonce),
+            frame_system::CheckWeight::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::new(false),
+        );
+        let payload = shibuya_runtime::SignedPayload::from_raw(
+            call,
+            extra,
+            (
+                version.spec_version,
+                version.transaction_version,
+                genesis_hash,
+                genesis_hash,
+                (),
+                (),
+                (),
+                None,
+            ),
+        );
+        let signature = payload.using_encoded(|encoded| pair.sign(encoded));
+        let (call, extra, _) = payload.deconstruct();
+
+        let extrinsic = shibuya_runtime::UncheckedExtrinsic::new_signed(
+            call,
+            Address::Id(account),
+            Signature::Sr25519(signature),
+            extra,
+        );
+
+        <Block as BlockT>::Extrinsic::decode(&mut &extrinsic.encode()[..])
+            .map_err(|err| format!("Failed to convert the extrinsic: {}", err))
+    }
+
+    /// Seals a new block, including any ready transactions from the pool.
+    async fn seal_block(&self) -> Result<CreatedBlock<Hash>, String> {
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        self.command_sink
+            .clone()
+            .send(EngineCommand::SealNewBlock {
+                create_empty: true,
+                finalize: true,
+                parent_hash: None,
+                sender: Some(sender),
+            })
+            .await
+            .map_err(|err| format!("Failed to send the seal command: {}", err))?;
+
+        receiver
+            .await
+            .map_err(|_| "Block authorship task has stopped".to_string())?
+            .map_err(|err| format!("Failed to seal a block: {}", err))
+    }
+}
+
+#[async_trait]
+impl<C, P> DevDappStakingApiServer for DevDappStaking<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + CallApiAt<Block> + Send + Sync + 'static,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+    P: TransactionPool<Block = Block, Hash = <Block as BlockT>::Hash> + Send + Sync + 'static,
+{
+    async fn advance_era(&self, eras: u32) -> RpcResult<CreatedBlock<Hash>> {
+        if eras == 0 {
+            return Err(internal_error("Number of eras must be greater than zero"));
+        }
+
+        let mut last_block = None;
+        for _ in 0..eras {
+            let best_hash = self.client.info().best_hash;
+            let extrinsic = self
+                .force_era_extrinsic(best_hash)
+                .map_err(internal_error)?;
+            self.pool
+                .submit_one(best_hash, TransactionSource::Local, extrinsic)
+                .await
+                .map_err(internal_error)?;
+
+            // The first block includes the `force` call, the era changes in the following one.
+            self.seal_block().await.map_err(internal_error)?;
+            last_block = Some(self.seal_block().await.map_err(internal_error)?);
+        }
+
+        last_block.ok_or_else(|| internal_error("No block was sealed"))
+    }
+}
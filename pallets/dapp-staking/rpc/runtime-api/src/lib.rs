@@ -18,18 +18,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[allow(unused_imports)]
-use astar_primitives::dapp_staking::TierId;
-use astar_primitives::dapp_staking::{DAppId, EraNumber, PeriodNumber, RankedTier};
-use astar_primitives::BlockNumber;
+use astar_primitives::dapp_staking::{
+    DAppId, DAppTierRewardsView, EraNumber, PeriodNumber, RankedTier, SmartContract,
+    StakePreflightError, StakerStateExport, StakingConstants, TierConfigView, TierId,
+};
+use astar_primitives::{AccountId, Balance, BlockNumber};
+use sp_arithmetic::Perbill;
 pub use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 
     /// dApp Staking Api.
     ///
     /// Used to provide information otherwise not available via RPC.
-    #[api_version(2)]
+    #[api_version(27)]
     pub trait DappStakingApi {
 
         /// How many periods are there in one cycle.
@@ -50,5 +53,162 @@ sp_api::decl_runtime_apis! {
 
         /// Get dApp ranked tier assignment for the given dApp.
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier>;
+
+        /// Estimate the annual percentage return for staking `stake` on the given smart contract.
+        ///
+        /// This is an approximation based on the current state, assuming that:
+        /// - the contract makes it into a tier if its stake, increased by `stake`, satisfies any tier threshold,
+        /// - total value staked & the staker reward pool remain unchanged for the entire cycle,
+        /// - one cycle lasts a year, with staker rewards earned only during build&earn eras,
+        /// - bonus rewards are excluded.
+        ///
+        /// Returns `None` if the contract isn't registered or the stake wouldn't make it into any tier.
+        #[api_version(3)]
+        fn estimate_apr(smart_contract: SmartContract<AccountId>, stake: Balance) -> Option<Perbill>;
+
+        /// Minimum lock & stake amounts, staking limits and the unlocking period in blocks.
+        #[api_version(4)]
+        fn staking_constants() -> StakingConstants;
+
+        /// Live standing of the given smart contract, as `(current_stake, next_era_stake, projected_tier)`.
+        ///
+        /// Projected tier is based on the current era stake & current tier thresholds, ignoring tier slot capacity.
+        /// Returns `None` if the contract isn't registered.
+        #[api_version(5)]
+        fn dapp_current_standing(smart_contract: SmartContract<AccountId>) -> Option<(Balance, Balance, Option<TierId>)>;
+
+        /// Raw bonus status of the account's stake on the given smart contract.
+        ///
+        /// `0` means the bonus reward is forfeited, otherwise the staker is eligible for it with `bonus_status - 1` safe moves remaining.
+        /// Returns `None` if the account has no staking info for the contract.
+        #[api_version(6)]
+        fn bonus_status(account: AccountId, smart_contract: SmartContract<AccountId>) -> Option<u8>;
+
+        /// First and last era (both inclusive) of the given period.
+        ///
+        /// For the ongoing period, the last era is a projection based on the cycle configuration.
+        /// Returns `None` for future periods, or if the period's era range is no longer known.
+        #[api_version(7)]
+        fn period_era_range(period: PeriodNumber) -> Option<(EraNumber, EraNumber)>;
+
+        /// Tier configuration currently in effect: slots per tier, tier thresholds & reward portions.
+        #[api_version(8)]
+        fn tier_config() -> TierConfigView;
+
+        /// Number of slots still available in each tier for the current era, based on the current stake amounts.
+        ///
+        /// All values are zero during the voting subperiod.
+        #[api_version(9)]
+        fn available_tier_slots() -> Vec<u16>;
+
+        /// Expired history cleanup status: `(oldest_kept_era, current_era, pending_entries)`.
+        ///
+        /// `pending_entries` is an estimate of the expired entries still awaiting cleanup.
+        #[api_version(10)]
+        fn history_cleanup_status() -> (EraNumber, EraNumber, u32);
+
+        /// Checks whether the account could stake the specified amount on the smart contract right now.
+        ///
+        /// Returns the condition preventing the stake, if any.
+        #[api_version(11)]
+        fn can_stake(account: AccountId, smart_contract: SmartContract<AccountId>, amount: Balance) -> Result<(), StakePreflightError>;
+
+        /// Total amount of funds in the unlocking process, across all accounts.
+        ///
+        /// Unlocking chunks are stored per account, so a breakdown by maturity isn't available.
+        #[api_version(12)]
+        fn total_unlocking() -> Balance;
+
+        /// Total amount of rewards claimed by the given smart contract since its registration.
+        ///
+        /// Returns zero if the contract isn't registered.
+        #[api_version(13)]
+        fn dapp_lifetime_rewards(smart_contract: SmartContract<AccountId>) -> Balance;
+
+        /// Complete dApp staking state of the account: ledger, and all staking info entries including bonus statuses.
+        ///
+        /// Intended for migration tooling, it contains everything needed to reconstruct the account's position.
+        #[api_version(14)]
+        fn export_staker_state(account: AccountId) -> StakerStateExport<SmartContract<AccountId>>;
+
+        /// Eras of the currently staked period for which the account has already claimed staker rewards.
+        ///
+        /// Since the ledger only tracks a claim cursor, this is a contiguous range rather than a sparse list,
+        /// and may include eras before the account's first stake in the period.
+        #[api_version(15)]
+        fn claimed_eras(account: AccountId) -> Vec<EraNumber>;
+
+        /// Minimum stake a dApp currently needs in order to be assigned to the specified tier.
+        ///
+        /// This is the tier threshold, unless all of the tier slots are taken, in which case the lowest staked dApp in the tier must be outstaked.
+        /// Returns `None` if the tier doesn't exist, or has no slots.
+        #[api_version(16)]
+        fn stake_needed_for_tier(tier_id: TierId) -> Option<Balance>;
+
+        /// Stake of the given smart contract for the ongoing era, together with its projected tier.
+        ///
+        /// Projected tier is consistent with the dApp tier assignment, taking tier slot capacity into account.
+        /// Returns `None` if the contract isn't registered, or it doesn't make it into any tier.
+        #[api_version(17)]
+        fn effective_staking_power(smart_contract: SmartContract<AccountId>) -> Option<(Balance, TierId)>;
+
+        /// Age of the account's stake on the given smart contract, as `(since, eras_staked)`.
+        ///
+        /// `since` is the era from which the latest stake update is effective, and `eras_staked` is the number of eras since then.
+        /// Returns `None` if the account has no staking info for the contract.
+        #[api_version(18)]
+        fn stake_age(account: AccountId, smart_contract: SmartContract<AccountId>) -> Option<(EraNumber, u32)>;
+
+        /// Smart contracts on which the account has rewards that will expire unless claimed, together with the expiry period.
+        ///
+        /// Covers unclaimed staker rewards & bonus rewards from past periods, consistent with the claim expiry checks.
+        #[api_version(19)]
+        fn stake_expiry_warning(account: AccountId) -> Vec<(SmartContract<AccountId>, PeriodNumber)>;
+
+        /// dApp Id of each of the given smart contracts, or `None` if the contract isn't registered.
+        ///
+        /// Output is aligned with the input, which is bounded by the maximum number of contracts; excess contracts are ignored.
+        #[api_version(20)]
+        fn dapp_ids(contracts: Vec<SmartContract<AccountId>>) -> Vec<Option<DAppId>>;
+
+        /// Smart contracts owned by the given account.
+        #[api_version(21)]
+        fn dapps_by_owner(owner: AccountId) -> Vec<SmartContract<AccountId>>;
+
+        /// Reward pools for the ongoing era, as `(staker_reward_pool, bonus_reward_pool, dapp_reward_pool)`.
+        ///
+        /// Staker & dApp reward pools are based on the current era stake, and are zero during the voting subperiod.
+        /// Bonus reward pool is for the ongoing period.
+        #[api_version(22)]
+        fn current_reward_pools() -> (Balance, Balance, Balance);
+
+        /// dApp tier assignment & rewards for the given era.
+        ///
+        /// Returns `None` if there is no tier assignment for the era, e.g. for voting subperiod eras, the ongoing era, or expired eras.
+        #[api_version(23)]
+        fn dapp_tier_rewards(era: EraNumber) -> Option<DAppTierRewardsView>;
+
+        /// Range of eras for which era rewards are still stored, as `(oldest_era, newest_era)`.
+        ///
+        /// Returns `(0, 0)` if no era rewards are stored.
+        #[api_version(24)]
+        fn retained_era_window() -> (EraNumber, EraNumber);
+
+        /// Reconciliation of the funds held by dApp staking, as `(ledgers_total, era_info_total, matches)`.
+        ///
+        /// Compares the sum of locked & unlocking amounts over all accounts against the totals in the current era info.
+        /// Iterates over all accounts, so it's expensive and should only be used for audits & monitoring.
+        #[api_version(25)]
+        fn reconcile_held_funds() -> (Balance, Balance, bool);
+
+        /// Eras for which the given smart contract has a claimable dApp reward, in ascending order.
+        ///
+        /// Follows the `claim_dapp_reward` eligibility rules. Empty if the contract isn't registered.
+        #[api_version(26)]
+        fn claimable_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<EraNumber>;
+
+        /// Number of eras remaining until the next subperiod starts, including the ongoing era.
+        #[api_version(27)]
+        fn eras_until_subperiod_change() -> EraNumber;
     }
 }
@@ -98,6 +98,104 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_dapp_auto_compound() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone(), true);
+
+        assert_last_event::<T>(
+            Event::<T>::DAppAutoCompoundUpdated {
+                smart_contract,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_tier_cap() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+        let max_tier = Some(T::NumberOfTiers::get().saturating_sub(1) as TierId);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone(), max_tier);
+
+        assert_last_event::<T>(
+            Event::<T>::DAppTierCapSet {
+                smart_contract,
+                max_tier,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_dapp_reward_opt_out() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone(), true);
+
+        assert_last_event::<T>(
+            Event::<T>::DAppRewardOptOutUpdated {
+                smart_contract,
+                opted_out: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_claim_reminder() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        let every = Some(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), every);
+
+        assert_last_event::<T>(
+            Event::<T>::ClaimReminderSet {
+                account: staker,
+                every,
+            }
+            .into(),
+        );
+    }
+
     #[benchmark]
     fn set_dapp_owner() {
         initial_config::<T>();
@@ -151,6 +249,41 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn unregister_inactive() -> Result<(), BenchmarkError> {
+        initial_config::<T>();
+        let inactivity_periods =
+            T::InactivityUnregisterPeriods::get().ok_or(BenchmarkError::Weightless)?;
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Advance enough periods for the dApp to be considered inactive.
+        let inactive_period = ActiveProtocolState::<T>::get()
+            .period_number()
+            .saturating_add(inactivity_periods)
+            .saturating_add(1);
+        force_advance_to_period::<T>(inactive_period);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::DAppUnregistered {
+                smart_contract,
+                era: ActiveProtocolState::<T>::get().era,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
     #[benchmark]
     fn lock_new_account() {
         initial_config::<T>();
@@ -336,6 +469,99 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn reclaim_abandoned(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        // Prepare staker account and lock some amount
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = (T::MinimumStakeAmount::get() + 1)
+            * Into::<Balance>::into(max_number_of_contracts::<T>())
+            + Into::<Balance>::into(T::MaxUnlockingChunks::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Ensure the reclaimed amount can be deposited regardless of the existential deposit.
+        let beneficiary = T::AbandonedChunkBeneficiary::get();
+        T::BenchmarkHelper::set_balance(&beneficiary, amount);
+
+        // Move over to the build&earn subperiod to ensure staking without a bonus status.
+        // This is needed so we can achieve staker entry cleanup after reclaiming the chunks.
+        force_advance_to_next_subperiod::<T>();
+
+        // Register required number of contracts and have staker stake on them.
+        for idx in 0..x {
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract,
+                T::MinimumStakeAmount::get() + 1,
+            ));
+        }
+
+        // Unlock some amount - but we want to fill up the whole vector with chunks.
+        let unlock_amount = 1;
+        for _ in 0..T::MaxUnlockingChunks::get() {
+            assert_ok!(DappStaking::<T>::unlock(
+                RawOrigin::Signed(staker.clone()).into(),
+                unlock_amount,
+            ));
+            run_for_blocks::<T>(One::one());
+        }
+        let unlock_amount = unlock_amount * Into::<Balance>::into(T::MaxUnlockingChunks::get());
+
+        // Hack
+        // In order to speed up the benchmark, we reduce how long it takes to unlock the chunks
+        let mut counter = 1u32;
+        Ledger::<T>::mutate(&staker, |ledger| {
+            ledger.unlocking.iter_mut().for_each(|unlocking| {
+                unlocking.unlock_block =
+                    (System::<T>::block_number() + counter.into()).saturated_into();
+            });
+            counter += 1;
+        });
+
+        // Advance to next period to ensure the old stake entries are cleaned up.
+        force_advance_to_next_period::<T>();
+
+        // Additionally, ensure the chunks have been abandoned for long enough.
+        let abandoned_blocks = T::CycleConfiguration::blocks_per_era();
+        let unlock_block = Ledger::<T>::get(&staker)
+            .unlocking
+            .last()
+            .expect("At least one entry must exist.")
+            .unlock_block;
+        run_to_block::<T>((unlock_block + abandoned_blocks).into());
+
+        #[block]
+        {
+            assert_ok!(DappStaking::<T>::internal_reclaim_abandoned(
+                staker.clone(),
+                abandoned_blocks
+            ));
+        }
+
+        assert_last_event::<T>(
+            Event::<T>::AbandonedChunksReclaimed {
+                account: staker,
+                beneficiary,
+                amount: unlock_amount,
+            }
+            .into(),
+        );
+    }
+
     #[benchmark]
     fn relock_unlocking() {
         initial_config::<T>();
@@ -380,6 +606,77 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn approve_position_transfer() {
+        initial_config::<T>();
+
+        let source: T::AccountId = account("source", 0, SEED);
+        let dest: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(dest.clone()),
+            source.clone(),
+            dest.clone(),
+        );
+
+        assert_last_event::<T>(Event::<T>::PositionTransferApproved { source, dest }.into());
+    }
+
+    #[benchmark]
+    fn transfer_position(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        // Prepare source account with a lock, unlocking chunks & stake on `x` contracts.
+        let source: T::AccountId = whitelisted_caller();
+        let amount = (T::MinimumStakeAmount::get() + 1)
+            * Into::<Balance>::into(max_number_of_contracts::<T>())
+            + T::MinimumLockedAmount::get()
+            + Into::<Balance>::into(T::MaxUnlockingChunks::get());
+        T::BenchmarkHelper::set_balance(&source, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(source.clone()).into(),
+            amount,
+        ));
+
+        for idx in 0..x {
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(source.clone()).into(),
+                smart_contract,
+                T::MinimumStakeAmount::get() + 1,
+            ));
+        }
+
+        for _ in 0..T::MaxUnlockingChunks::get() {
+            assert_ok!(DappStaking::<T>::unlock(
+                RawOrigin::Signed(source.clone()).into(),
+                1,
+            ));
+            run_for_blocks::<T>(One::one());
+        }
+
+        let dest: T::AccountId = account("dest", 0, SEED);
+        assert_ok!(DappStaking::<T>::approve_position_transfer(
+            RawOrigin::Signed(dest.clone()).into(),
+            source.clone(),
+            dest.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(source.clone()), dest.clone());
+
+        assert_last_event::<T>(Event::<T>::PositionTransferred { source, dest }.into());
+    }
+
     #[benchmark]
     fn stake() {
         initial_config::<T>();
@@ -1023,6 +1320,9 @@ mod benchmarks {
 
         let pre_cleanup_marker = HistoryCleanupMarker::<T>::get();
 
+        // Worst case, a scheduled cycle configuration change is applied.
+        PendingCycleConfig::<T>::put(current_cycle_parameters::<T>());
+
         #[block]
         {
             DappStaking::<T>::era_and_period_handler(new_era_start_block, TierAssignment::Dummy);
@@ -1032,6 +1332,7 @@ mod benchmarks {
             ActiveProtocolState::<T>::get().subperiod(),
             Subperiod::Voting
         );
+        assert!(PendingCycleConfig::<T>::get().is_none());
         assert_eq!(
             ActiveProtocolState::<T>::get().period_number(),
             snapshot_state.period_number() + 1,
@@ -1117,6 +1418,89 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn dapp_tier_assignment_from_snapshot(x: Linear<0, { max_number_of_contracts::<T>() }>) {
+        // Prepare init config (protocol state, tier params & config, etc.)
+        initial_config::<T>();
+
+        // Register & stake contracts, to prepare for tier assignment.
+        prepare_contracts_for_tier_assignment::<T>(x);
+        force_advance_to_next_era::<T>();
+
+        // Need to ensure settings remain unchanged even after the era change
+        init_tier_settings::<T>();
+
+        let reward_period = ActiveProtocolState::<T>::get().period_number();
+        let reward_pool = Balance::from(10_000 * UNIT as u128);
+
+        // Collect all dApp stakes in advance, as it would be done during `on_idle`.
+        DappStaking::<T>::dapp_stakes_snapshot_step(&Weight::MAX);
+        let snapshot = TierAssignmentSnapshot::<T>::get().expect("Snapshot must exist.");
+        assert!(snapshot.complete);
+
+        #[block]
+        {
+            let snapshot = TierAssignmentSnapshot::<T>::take().expect("Snapshot must exist.");
+            let dapp_tiers = Pallet::<T>::compute_dapp_tier_assignment_and_rewards(
+                snapshot.stakes.into_inner(),
+                reward_period,
+                reward_pool,
+            );
+            assert_eq!(dapp_tiers.dapps.len(), x as usize);
+        }
+    }
+
+    #[benchmark]
+    fn dapp_stakes_snapshot(x: Linear<0, { max_number_of_contracts::<T>() }>) {
+        // Prepare init config (protocol state, tier params & config, etc.)
+        initial_config::<T>();
+
+        // Register & stake contracts, and move to the `Build&Earn` subperiod.
+        prepare_contracts_for_tier_assignment::<T>(x);
+        force_advance_to_next_era::<T>();
+        assert_eq!(
+            ActiveProtocolState::<T>::get().subperiod(),
+            Subperiod::BuildAndEarn
+        );
+
+        #[block]
+        {
+            DappStaking::<T>::dapp_stakes_snapshot_step(&Weight::MAX);
+        }
+
+        let snapshot = TierAssignmentSnapshot::<T>::get().expect("Snapshot must exist.");
+        assert!(snapshot.complete);
+        assert_eq!(snapshot.stakes.len(), x as usize);
+    }
+
+    #[benchmark]
+    fn process_claim_reminders(x: Linear<0, { T::MaxClaimRemindersPerEra::get() }>) {
+        initial_config::<T>();
+
+        // Prepare reminders which are all due in the next era, so each one is updated.
+        let amount = T::MinimumLockedAmount::get();
+        for idx in 0..x {
+            let staker: T::AccountId = account("staker", idx, SEED);
+            T::BenchmarkHelper::set_balance(&staker, amount);
+            assert_ok!(DappStaking::<T>::lock(
+                RawOrigin::Signed(staker.clone()).into(),
+                amount,
+            ));
+            assert_ok!(DappStaking::<T>::set_claim_reminder(
+                RawOrigin::Signed(staker).into(),
+                Some(1),
+            ));
+        }
+        let due_era = ActiveProtocolState::<T>::get().era + 1;
+
+        #[block]
+        {
+            DappStaking::<T>::process_claim_reminders(due_era);
+        }
+
+        assert!(ClaimReminderEra::<T>::iter().all(|(_, reminder)| reminder.next_era == due_era + 1));
+    }
+
     #[benchmark]
     fn on_idle_cleanup() {
         // Prepare init config (protocol state, tier params & config, etc.)
@@ -1161,6 +1545,7 @@ mod benchmarks {
                 rank_rewards: vec![0; T::NumberOfTiers::get() as usize]
                     .try_into()
                     .expect("Using `NumberOfTiers` as length; QED."),
+                remainders: Default::default(),
             },
         );
 
@@ -1202,6 +1587,179 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_slots_per_tier_override() {
+        initial_config::<T>();
+
+        let slots: BoundedVec<u16, T::NumberOfTiers> = (0..T::NumberOfTiers::get())
+            .map(|tier| tier as u16 + 1)
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Must fit since the length equals the number of tiers.");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, Some(slots.clone()));
+
+        assert_eq!(SlotsPerTierOverride::<T>::get(), Some(slots.clone()));
+        assert_last_event::<T>(Event::<T>::SlotsPerTierOverrideSet { slots: Some(slots) }.into());
+    }
+
+    #[benchmark]
+    fn set_reward_split() {
+        initial_config::<T>();
+
+        let split = Perbill::from_percent(30);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, Some(split));
+
+        assert_eq!(RewardPoolSplitOverride::<T>::get(), Some(split));
+        assert_last_event::<T>(
+            Event::<T>::RewardPoolSplitOverrideSet { split: Some(split) }.into(),
+        );
+    }
+
+    #[benchmark]
+    fn force_set_bonus_status() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let amount = T::MinimumLockedAmount::get() + 1;
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            smart_contract.clone(),
+            amount
+        ));
+        let old_status = StakerInfo::<T>::get(&staker, &smart_contract)
+            .expect("Entry must exist after staking.")
+            .bonus_status;
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, staker.clone(), smart_contract.clone(), 0);
+
+        assert_last_event::<T>(
+            Event::<T>::BonusStatusForced {
+                account: staker,
+                smart_contract,
+                old_status,
+                new_status: 0,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_auto_relock() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), true);
+
+        assert_last_event::<T>(
+            Event::<T>::AutoRelockUpdated {
+                account: staker,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn schedule_cycle_config_change() {
+        initial_config::<T>();
+
+        let config = current_cycle_parameters::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, config);
+
+        assert_eq!(PendingCycleConfig::<T>::get(), Some(config));
+        assert_last_event::<T>(Event::<T>::CycleConfigChangeScheduled { config }.into());
+    }
+
+    #[benchmark]
+    fn set_dapp_min_stake() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let min_stake = Some(T::MinimumStakeAmount::get() * 10);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone(), min_stake);
+
+        assert_last_event::<T>(
+            Event::<T>::DAppMinStakeUpdated {
+                smart_contract,
+                min_stake,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_dapp_auto_claim() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone(), true);
+
+        assert_last_event::<T>(
+            Event::<T>::DAppAutoClaimUpdated {
+                smart_contract,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_stake_delegate() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner.clone()), Some(delegate.clone()));
+
+        assert_last_event::<T>(
+            Event::<T>::StakeDelegateSet {
+                owner,
+                delegate: Some(delegate),
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
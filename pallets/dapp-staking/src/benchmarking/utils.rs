@@ -289,3 +289,14 @@ pub(super) fn max_claim_size_ongoing_period<T: Config>() -> u32 {
     T::EraRewardSpanLength::get()
         .min(T::CycleConfiguration::eras_per_build_and_earn_subperiod() - 1)
 }
+
+/// Returns the runtime provided cycle configuration, as cycle parameters.
+pub(super) fn current_cycle_parameters<T: Config>() -> CycleParameters {
+    CycleParameters {
+        periods_per_cycle: T::CycleConfiguration::periods_per_cycle(),
+        eras_per_voting_subperiod: T::CycleConfiguration::eras_per_voting_subperiod(),
+        eras_per_build_and_earn_subperiod: T::CycleConfiguration::eras_per_build_and_earn_subperiod(
+        ),
+        blocks_per_era: T::CycleConfiguration::blocks_per_era(),
+    }
+}
@@ -41,8 +41,10 @@ use alloc::vec;
 pub use alloc::vec::Vec;
 use frame_support::{
     pallet_prelude::*,
+    storage::{with_storage_layer, with_transaction},
     traits::{
-        fungible::{Inspect as FunInspect, MutateFreeze as FunMutateFreeze},
+        fungible::{Inspect as FunInspect, Mutate as FunMutate, MutateFreeze as FunMutateFreeze},
+        tokens::{Fortitude, Precision, Preservation},
         SafeModeNotify, StorageVersion,
     },
     weights::Weight,
@@ -50,13 +52,16 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
     traits::{One, Saturating, UniqueSaturatedInto, Zero},
-    Perbill, Permill, SaturatedConversion,
+    Perbill, Permill, SaturatedConversion, TransactionOutcome,
 };
+use sp_std::collections::btree_set::BTreeSet;
 
 use astar_primitives::{
     dapp_staking::{
         AccountCheck, CycleConfiguration, DAppId, EraNumber, Observer as DAppStakingObserver,
-        PeriodNumber, Rank, RankedTier, SmartContractHandle, StakingRewardHandler, TierId,
+        PeriodNumber, Rank, RankedTier, SmartContractHandle, StakeAmountExport,
+        StakePreflightError, StakerContractExport, StakerStateExport, StakingConstants,
+        StakingRewardHandler, TierConfigView, TierId, UnlockingPeriodFn,
     },
     Balance, BlockNumber,
 };
@@ -94,7 +99,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(23);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -102,6 +107,7 @@ pub mod pallet {
 
     #[cfg(feature = "runtime-benchmarks")]
     pub trait BenchmarkHelper<SmartContract, AccountId> {
+        /// Returns a valid smart contract handle, unique for the specified `id`.
         fn get_smart_contract(id: u32) -> SmartContract;
 
         fn set_balance(account: &AccountId, balance: Balance);
@@ -120,11 +126,8 @@ pub mod pallet {
 
         /// Currency used for staking.
         /// Reference: <https://github.com/paritytech/substrate/pull/12951/>
-        type Currency: FunMutateFreeze<
-            Self::AccountId,
-            Id = Self::RuntimeFreezeReason,
-            Balance = Balance,
-        >;
+        type Currency: FunMutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason, Balance = Balance>
+            + FunMutate<Self::AccountId>;
 
         /// Describes smart contract in the context required by dApp staking.
         type SmartContract: Parameter
@@ -179,6 +182,10 @@ pub mod pallet {
         #[pallet::constant]
         type UnlockingPeriod: Get<EraNumber>;
 
+        /// Used to adjust the unlocking period based on the amount being unlocked.
+        /// Use `()` to apply the standard unlocking period to all amounts.
+        type UnlockingPeriodFn: UnlockingPeriodFn;
+
         /// Maximum amount of stake contract entries an account is allowed to have at once.
         #[pallet::constant]
         type MaxNumberOfStakedContracts: Get<u32>;
@@ -195,12 +202,132 @@ pub mod pallet {
         #[pallet::constant]
         type RankingEnabled: Get<bool>;
 
+        /// How the remainder of the tier reward division is handled.
+        #[pallet::constant]
+        type TierRewardRounding: Get<RewardRounding>;
+
+        /// Boost applied to the reward portion of the top tier during tier reward computation.
+        /// The boost is taken from the lower tiers, proportionally to their reward portions.
+        #[pallet::constant]
+        type TopTierBoost: Get<Permill>;
+
         /// The maximum number of 'safe move actions' allowed within a single period while
         /// retaining eligibility for bonus rewards. Exceeding this limit will result in the
         /// forfeiture of the bonus rewards for the affected stake.
         #[pallet::constant]
         type MaxBonusSafeMovesPerPeriod: Get<u8>;
 
+        /// Minimum number of eras a dApp must remain registered before it can be unregistered.
+        /// Root origin can bypass this restriction.
+        #[pallet::constant]
+        type MinRegistrationEras: Get<EraNumber>;
+
+        /// Number of consecutive periods without any stake after which a dApp can be unregistered by anyone.
+        /// `None` means that inactive dApps can't be unregistered permissionlessly.
+        #[pallet::constant]
+        type InactivityUnregisterPeriods: Get<Option<PeriodNumber>>;
+
+        /// Maximum number of eras for which staker rewards can be claimed in a single call made on behalf of another account.
+        /// Claims made by the account itself aren't limited by this value.
+        #[pallet::constant]
+        type KeeperClaimWindow: Get<EraNumber>;
+
+        /// Maximum total amount that can be staked on a single contract, for the next era.
+        /// `None` means there is no limit.
+        #[pallet::constant]
+        type MaxStakePerContract: Get<Option<Balance>>;
+
+        /// Maximum number of claim reminders checked at each era change.
+        /// If there are more reminders, the check continues from the same place in the next era.
+        #[pallet::constant]
+        type MaxClaimRemindersPerEra: Get<u32>;
+
+        /// Whether unlocking is allowed during the voting subperiod.
+        /// When `false`, locked funds remain committed until the build&earn subperiod starts.
+        #[pallet::constant]
+        type LockUnlockDuringVoting: Get<bool>;
+
+        /// Number of standard eras after maturity an unlocking chunk must remain unclaimed before anyone can reclaim it.
+        /// Even though it's expressed in 'eras', it's actually measured in number of blocks.
+        /// `None` or zero disables reclaiming of abandoned chunks.
+        #[pallet::constant]
+        type AbandonedChunkEras: Get<Option<EraNumber>>;
+
+        /// Account receiving the reclaimed abandoned unlocking chunks.
+        type AbandonedChunkBeneficiary: Get<Self::AccountId>;
+
+        /// Maximum number of eras for which dApp rewards can be claimed in a single batch call.
+        #[pallet::constant]
+        type MaxDAppClaimBatch: Get<u32>;
+
+        /// Maximum number of stake moves an account can make within a single period.
+        /// `None` means there is no limit.
+        #[pallet::constant]
+        type MaxMovesPerPeriod: Get<Option<u32>>;
+
+        /// Maximum total amount an account can still stake in the final era of a period.
+        /// Such stake only becomes effective in the next period, so it won't earn any rewards.
+        /// Doesn't apply to moving stake, which isn't allowed in the final era.
+        /// Zero means staking in the final era isn't allowed.
+        #[pallet::constant]
+        type FinalEraStakeGrace: Get<Balance>;
+
+        /// If set, amounts passed to `stake` are rounded down to a multiple of this increment.
+        /// `None` means the amount is used as is.
+        #[pallet::constant]
+        type StakeIncrement: Get<Option<Balance>>;
+
+        /// Maximum number of accounts which can stake at the same time.
+        /// Once reached, only accounts that are already staking can stake. `None` means no limit.
+        #[pallet::constant]
+        type MaxTotalStakers: Get<Option<u32>>;
+
+        /// Maximum number of dApp rewards which can be automatically claimed in a single block.
+        /// If more dApps opted into auto-claiming, they are processed over multiple blocks.
+        #[pallet::constant]
+        type MaxAutoClaimsPerBlock: Get<u32>;
+
+        /// Staker reward boost for accounts which keep their funds locked over a long time.
+        /// For each full step of eras with continuously locked funds, the staker reward is boosted
+        /// by the specified amount, up to 100%. The boost is paid on top of the staker reward pool.
+        /// `None` means no boost is applied.
+        #[pallet::constant]
+        type LoyaltyBoost: Get<Option<(EraNumber, Permill)>>;
+
+        /// Minimum free balance which must remain available to the account after locking,
+        /// so it can still pay the transaction fees to e.g. unlock or claim rewards later.
+        #[pallet::constant]
+        type PostLockMinFreeBalance: Get<Balance>;
+
+        /// Minimum number of eras which must pass between two subperiod forcings.
+        /// Zero means subperiod can be forced at any time.
+        #[pallet::constant]
+        type MinSubperiodForceInterval: Get<EraNumber>;
+
+        /// If set, recalculated tier thresholds only move towards their target values
+        /// by the specified fraction of the difference per era, instead of being applied immediately.
+        #[pallet::constant]
+        type ThresholdSmoothing: Get<Option<Permill>>;
+
+        /// Maximum fraction of the total issuance which can be locked in dApp staking.
+        /// `None` means no limit.
+        #[pallet::constant]
+        type MaxLockedIssuanceFraction: Get<Option<Permill>>;
+
+        /// Number of eras which must pass after locking before the newly locked funds can be staked.
+        /// Zero means locked funds can be staked right away.
+        #[pallet::constant]
+        type LockToStakeDelayEras: Get<EraNumber>;
+
+        /// What happens with the tier reward of dApps which opted out of dApp rewards.
+        #[pallet::constant]
+        type OptedOutRewardHandling: Get<OptOutRewardHandling>;
+
+        /// Maximum number of eras for which staker rewards are claimed in a single claim call.
+        /// Remaining eras are left to be claimed in the subsequent calls.
+        #[pallet::constant]
+        type MaxEraRewardsPerClaim: Get<u32>;
+
         /// Weight info for various calls & operations in the pallet.
         type WeightInfo: WeightInfo;
 
@@ -317,6 +444,130 @@ pub mod pallet {
         NewTierParameters {
             params: TierParameters<T::NumberOfTiers>,
         },
+        /// Account has claimed some stake rewards, which were paid out to the destination account.
+        RewardTo {
+            account: T::AccountId,
+            destination: T::AccountId,
+            era: EraNumber,
+            amount: Balance,
+        },
+        /// Account has staked on a smart contract, but the projected staker reward pool for the next era is empty.
+        /// Staking still succeeds, but it won't yield any staker rewards unless the pool is replenished.
+        StakingIntoEmptyPool {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+        },
+        /// Summary of all rewards (staker, bonus & dApp) paid out during the era which has just ended.
+        EraRewardsSummary { era: EraNumber, total_paid: Balance },
+        /// Destination account has approved receiving the staking position of the source account.
+        PositionTransferApproved {
+            source: T::AccountId,
+            dest: T::AccountId,
+        },
+        /// Entire staking position has been transferred from the source to the destination account.
+        PositionTransferred {
+            source: T::AccountId,
+            dest: T::AccountId,
+        },
+        /// Auto-compounding of dApp rewards has been enabled or disabled.
+        DAppAutoCompoundUpdated {
+            smart_contract: T::SmartContract,
+            enabled: bool,
+        },
+        /// Claimed dApp reward has been locked & staked on the dApp by the beneficiary.
+        DAppRewardCompounded {
+            beneficiary: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        },
+        /// Claimed dApp reward couldn't be compounded, and has remained liquid.
+        DAppRewardCompoundFailed {
+            beneficiary: T::AccountId,
+            smart_contract: T::SmartContract,
+            error: DispatchError,
+        },
+        /// Slots per tier override has been either set or cleared, and will be applicable from next era.
+        SlotsPerTierOverrideSet {
+            slots: Option<BoundedVec<u16, T::NumberOfTiers>>,
+        },
+        /// Part of the claimed staker rewards has been burned, on the staker's request.
+        StakerRewardBurned {
+            account: T::AccountId,
+            claimed: Balance,
+            burned: Balance,
+        },
+        /// dApp owner has set or cleared the best tier the dApp can be assigned to.
+        DAppTierCapSet {
+            smart_contract: T::SmartContract,
+            max_tier: Option<TierId>,
+        },
+        /// Account has set or cleared its claim reminder.
+        ClaimReminderSet {
+            account: T::AccountId,
+            every: Option<EraNumber>,
+        },
+        /// Claim reminder of the account is due.
+        ClaimReminderDue { account: T::AccountId },
+        /// Abandoned unlocking chunks of the account have been reclaimed.
+        AbandonedChunksReclaimed {
+            account: T::AccountId,
+            beneficiary: T::AccountId,
+            amount: Balance,
+        },
+        /// Reward pool split override has been either set or cleared, and will be applicable from next era.
+        RewardPoolSplitOverrideSet { split: Option<Perbill> },
+        /// Bonus status of the account's stake on the smart contract has been forcefully changed.
+        BonusStatusForced {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            old_status: BonusStatus,
+            new_status: BonusStatus,
+        },
+        /// Auto-relocking of matured unlocking chunks has been enabled or disabled.
+        AutoRelockUpdated {
+            account: T::AccountId,
+            enabled: bool,
+        },
+        /// Contract's stake for the next era crossed a tier threshold, gaining or losing the specified tier.
+        /// Tier slot capacity isn't taken into account.
+        ContractCrossedTierThreshold {
+            smart_contract: T::SmartContract,
+            tier_id: TierId,
+            crossed_up: bool,
+        },
+        /// Staker rewards have been claimed on behalf of the contract's stakers.
+        /// `cursor` is the last processed account, and should be used to continue; `None` means all accounts were processed.
+        ContractStakersClaimed {
+            smart_contract: T::SmartContract,
+            claimed: u32,
+            cursor: Option<T::AccountId>,
+        },
+        /// Cycle configuration change has been scheduled, to be applied at the start of the next period.
+        CycleConfigChangeScheduled { config: CycleParameters },
+        /// Scheduled cycle configuration change has been applied.
+        CycleConfigChangeApplied { config: CycleParameters },
+        /// dApp minimum stake amount override has been updated.
+        DAppMinStakeUpdated {
+            smart_contract: T::SmartContract,
+            min_stake: Option<Balance>,
+        },
+        /// Automatic claiming of dApp rewards has been enabled or disabled.
+        DAppAutoClaimUpdated {
+            smart_contract: T::SmartContract,
+            enabled: bool,
+        },
+        /// Stake delegate of the account has been set or cleared.
+        StakeDelegateSet {
+            owner: T::AccountId,
+            delegate: Option<T::AccountId>,
+        },
+        /// dApp owner has opted the dApp in or out of dApp rewards.
+        DAppRewardOptOutUpdated {
+            smart_contract: T::SmartContract,
+            opted_out: bool,
+        },
+        /// Maximum number of stakers override has been either set or cleared.
+        MaxTotalStakersOverrideSet { max_stakers: Option<u32> },
     }
 
     #[pallet::error]
@@ -397,6 +648,62 @@ pub mod pallet {
         InvalidTierParams,
         /// Same contract specified as source and destination.
         SameContracts,
+        /// dApp hasn't been registered for the minimum required number of eras, so it cannot be unregistered yet.
+        UnregisterTooSoon,
+        /// Reward destination account is not allowed to participate in dApp staking.
+        InvalidRewardDestination,
+        /// Reward destination account wouldn't be able to hold the minimum balance after the payout.
+        RewardDestinationBelowMinimumBalance,
+        /// dApp has been staked on too recently to be unregistered due to inactivity.
+        DAppNotInactive,
+        /// Account's existing lock has a different purpose than the one specified.
+        LockPurposeMismatch,
+        /// Stake would take the contract's total stake over the maximum allowed amount.
+        ContractStakeCapExceeded,
+        /// Destination account hasn't approved receiving the staking position.
+        PositionTransferNotApproved,
+        /// Destination account already has a staking position.
+        DestinationHasPosition,
+        /// Account has no staking position to transfer.
+        NoPositionToTransfer,
+        /// Smart contract handle is invalid, e.g. it points to the zero address.
+        InvalidSmartContract,
+        /// Fraction of the rewards to burn must be less than 100%.
+        InvalidBurnFraction,
+        /// Slots per tier override must specify the number of slots for each tier.
+        InvalidSlotsPerTierOverride,
+        /// Tier cap must refer to an existing tier.
+        InvalidTierCap,
+        /// Claim reminder interval must be greater than zero.
+        InvalidClaimReminderInterval,
+        /// Claim reminder can only be set by an account with locked funds.
+        ClaimReminderWithoutLock,
+        /// Unlocking isn't allowed during the voting subperiod.
+        UnlockForbiddenDuringVoting,
+        /// Reclaiming of abandoned unlocking chunks is disabled.
+        AbandonedChunkReclaimDisabled,
+        /// Account has no unlocking chunks which have been abandoned for long enough.
+        NoAbandonedChunks,
+        /// Account has reached the maximum number of stake moves for the ongoing period.
+        TooManyMovesThisPeriod,
+        /// Cycle configuration parameters must all be non-zero.
+        InvalidCycleConfiguration,
+        /// Maximum number of stakers has been reached, new accounts cannot stake.
+        StakerCapReached,
+        /// Caller isn't the stake delegate of the specified account.
+        NotStakeDelegate,
+        /// Locking would leave the account with less free balance than required.
+        InsufficientPostLockBalance,
+        /// Not enough eras have passed since the subperiod was last forced.
+        SubperiodForceTooSoon,
+        /// Locking would exceed the maximum fraction of the total issuance which can be locked.
+        GlobalLockCapReached,
+        /// Part of the locked funds was locked too recently to be staked, see `LockToStakeDelayEras`.
+        LockedFundsNotAged,
+        /// Unregistering inactive dApps isn't enabled.
+        InactivityUnregisterDisabled,
+        /// Cycle configuration change would alter the cycle length, which inflation relies on.
+        CycleLengthMismatch,
     }
 
     /// General information about dApp staking protocol state.
@@ -438,6 +745,18 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Accounts having a `StakerInfo` entry for each smart contract, allowing iteration over the contract's stakers.
+    #[pallet::storage]
+    pub type ContractStakers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::SmartContract,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
     /// Information about how much has been staked on a smart contract in some era or period.
     #[pallet::storage]
     pub type ContractStake<T: Config> = StorageMap<
@@ -489,6 +808,111 @@ pub mod pallet {
     #[pallet::storage]
     pub type HistoryCleanupMarker<T: Config> = StorageValue<_, CleanupMarker, ValueQuery>;
 
+    /// Last period in which the dApp was staked on, or in which it was registered if it hasn't been staked on since.
+    #[pallet::storage]
+    pub type LastStakedPeriod<T: Config> =
+        StorageMap<_, Twox64Concat, DAppId, PeriodNumber, OptionQuery>;
+
+    /// Total amount of rewards (staker, bonus & dApp) paid out during an era.
+    #[pallet::storage]
+    pub type TotalRewardsPaid<T: Config> =
+        StorageMap<_, Twox64Concat, EraNumber, Balance, ValueQuery>;
+
+    /// Total amount locked (including unlocking chunks) per lock purpose.
+    #[pallet::storage]
+    pub type LockedPerPurpose<T: Config> =
+        StorageMap<_, Blake2_128Concat, LockPurpose, Balance, ValueQuery>;
+
+    /// dApps whose claimed rewards are automatically locked & staked back on the dApp by the beneficiary.
+    #[pallet::storage]
+    pub type DAppAutoCompound<T: Config> = StorageMap<_, Twox64Concat, DAppId, bool, ValueQuery>;
+
+    /// Total amount of rewards claimed by each dApp since its registration.
+    #[pallet::storage]
+    pub type DAppLifetimeRewards<T: Config> =
+        StorageMap<_, Twox64Concat, DAppId, Balance, ValueQuery>;
+
+    /// Best tier a dApp can be assigned to, voluntarily set by the dApp owner.
+    #[pallet::storage]
+    pub type DAppTierCap<T: Config> = StorageMap<_, Twox64Concat, DAppId, TierId, OptionQuery>;
+
+    /// dApps which voluntarily opted out of dApp rewards.
+    #[pallet::storage]
+    pub type DAppRewardOptOut<T: Config> = StorageMap<_, Twox64Concat, DAppId, bool, ValueQuery>;
+
+    /// Approved staking position transfers, mapping the destination account to the source account.
+    #[pallet::storage]
+    pub type PositionTransferApprovals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// Number of slots per tier, used instead of the calculated values when the tier configuration is recalculated.
+    #[pallet::storage]
+    pub type SlotsPerTierOverride<T: Config> =
+        StorageValue<_, BoundedVec<u16, T::NumberOfTiers>, OptionQuery>;
+
+    /// Share of the combined reward pools allocated to stakers, used instead of the split provided by the reward handler.
+    #[pallet::storage]
+    pub type RewardPoolSplitOverride<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
+    /// Maximum number of accounts which can stake at the same time, used instead of `MaxTotalStakers`.
+    #[pallet::storage]
+    pub type MaxTotalStakersOverride<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Number of stake moves made by an account, together with the period in which they were made.
+    /// Only tracked while `MaxMovesPerPeriod` is set, the count resets once a new period starts.
+    #[pallet::storage]
+    pub type MovesInPeriod<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (PeriodNumber, u32), OptionQuery>;
+
+    /// Accounts whose matured unlocking chunks are automatically relocked, instead of becoming claimable.
+    #[pallet::storage]
+    pub type AutoRelock<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// Cycle configuration change, applied at the start of the next period.
+    #[pallet::storage]
+    pub type PendingCycleConfig<T: Config> = StorageValue<_, CycleParameters, OptionQuery>;
+
+    /// Cycle configuration used instead of the runtime provided `CycleConfiguration`, if set.
+    #[pallet::storage]
+    pub type ActiveCycleConfig<T: Config> = StorageValue<_, CycleParameters, OptionQuery>;
+
+    /// Number of accounts with at least one staking info entry.
+    #[pallet::storage]
+    pub type TotalStakers<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// dApps whose rewards are automatically claimed after each era, mapped to their smart contracts.
+    #[pallet::storage]
+    pub type DAppAutoClaim<T: Config> =
+        StorageMap<_, Twox64Concat, DAppId, T::SmartContract, OptionQuery>;
+
+    /// Progress of the automatic dApp reward claiming for the previous era.
+    #[pallet::storage]
+    pub type AutoClaimCursor<T: Config> = StorageValue<_, AutoClaimProgress, OptionQuery>;
+
+    /// Accounts allowed to manage stakes on behalf of other accounts, mapping the owner to the delegate.
+    #[pallet::storage]
+    pub type StakeDelegate<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// Era in which the subperiod was last forced.
+    #[pallet::storage]
+    pub type LastForcedSubperiodEra<T: Config> = StorageValue<_, EraNumber, OptionQuery>;
+
+    /// dApp stakes for the ongoing era, collected during `on_idle` to reduce the tier assignment weight at the end of the era.
+    #[pallet::storage]
+    pub type TierAssignmentSnapshot<T: Config> =
+        StorageValue<_, DAppStakesSnapshotFor<T>, OptionQuery>;
+
+    /// Opt-in claim reminders, holding the reminder interval & the era in which the next reminder is due.
+    #[pallet::storage]
+    pub type ClaimReminderEra<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ClaimReminder, OptionQuery>;
+
+    /// Last account whose claim reminder was checked, used to continue the check in the next era.
+    #[pallet::storage]
+    pub type ClaimReminderCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
     #[pallet::type_value]
     pub fn DefaultSafeguard<T: Config>() -> bool {
         // In production, safeguard is enabled by default.
@@ -630,25 +1054,38 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
             let now = now.saturated_into();
-            Self::era_and_period_handler(now, TierAssignment::Real)
+            // Auto-claiming goes first, so it never adds to the weight of the era transition block.
+            let mut consumed_weight = Self::dapp_auto_claim_step();
+            consumed_weight
+                .saturating_accrue(Self::era_and_period_handler(now, TierAssignment::Real));
+
+            consumed_weight
         }
 
         fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-            Self::expired_entry_cleanup(&remaining_weight)
+            let mut consumed_weight = Self::expired_entry_cleanup(&remaining_weight);
+            consumed_weight.saturating_accrue(Self::dapp_stakes_snapshot_step(
+                &remaining_weight.saturating_sub(consumed_weight),
+            ));
+
+            consumed_weight
         }
 
         fn integrity_test() {
             // dApp staking params
             // Sanity checks
             assert!(T::EraRewardSpanLength::get() > 0);
+            assert!(T::MaxEraRewardsPerClaim::get() > 0);
             assert!(T::RewardRetentionInPeriods::get() > 0);
             assert!(T::MaxNumberOfContracts::get() > 0);
             assert!(T::MaxUnlockingChunks::get() > 0);
             assert!(T::UnlockingPeriod::get() > 0);
             assert!(T::MaxNumberOfStakedContracts::get() > 0);
+            assert!(T::InactivityUnregisterPeriods::get() != Some(0));
 
             assert!(T::MinimumLockedAmount::get() > 0);
             assert!(T::MinimumStakeAmount::get() > 0);
+            assert!(T::KeeperClaimWindow::get() > 0);
             assert!(T::MinimumLockedAmount::get() >= T::MinimumStakeAmount::get());
 
             // Cycle config
@@ -723,6 +1160,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             T::ContractRegisterOrigin::ensure_origin(origin)?;
 
+            ensure!(smart_contract.is_valid(), Error::<T>::InvalidSmartContract);
             ensure!(
                 !IntegratedDApps::<T>::contains_key(&smart_contract),
                 Error::<T>::ContractAlreadyExists,
@@ -737,14 +1175,18 @@ pub mod pallet {
             // MAX value must never be assigned as a dApp Id since it serves as a sentinel value.
             ensure!(dapp_id < DAppId::MAX, Error::<T>::NewDAppIdUnavailable);
 
+            let protocol_state = ActiveProtocolState::<T>::get();
             IntegratedDApps::<T>::insert(
                 &smart_contract,
                 DAppInfo {
                     owner: owner.clone(),
                     id: dapp_id,
                     reward_beneficiary: None,
+                    registered_era: protocol_state.era,
+                    min_stake: None,
                 },
             );
+            LastStakedPeriod::<T>::insert(dapp_id, protocol_state.period_number());
 
             NextDAppId::<T>::put(dapp_id.saturating_add(1));
 
@@ -772,27 +1214,11 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let dev_account = ensure_signed(origin)?;
 
-            IntegratedDApps::<T>::try_mutate(
-                &smart_contract,
-                |maybe_dapp_info| -> DispatchResult {
-                    let dapp_info = maybe_dapp_info
-                        .as_mut()
-                        .ok_or(Error::<T>::ContractNotFound)?;
-
-                    ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
-
-                    dapp_info.reward_beneficiary = beneficiary.clone();
-
-                    Ok(())
-                },
-            )?;
-
-            Self::deposit_event(Event::<T>::DAppRewardDestinationUpdated {
+            Self::internal_set_dapp_reward_beneficiary(
+                Some(dev_account),
                 smart_contract,
                 beneficiary,
-            });
-
-            Ok(())
+            )
         }
 
         /// Used to change dApp owner.
@@ -841,6 +1267,7 @@ pub mod pallet {
         /// This doesn't remove the dApp completely from the system just yet, but it can no longer be used for staking.
         ///
         /// Can be called by dApp staking manager origin.
+        /// dApp must have been registered for at least `MinRegistrationEras` eras, unless the call is made by root.
         #[pallet::call_index(6)]
         #[pallet::weight(T::WeightInfo::unregister())]
         pub fn unregister(
@@ -848,19 +1275,25 @@ pub mod pallet {
             smart_contract: T::SmartContract,
         ) -> DispatchResult {
             Self::ensure_pallet_enabled()?;
-            T::ContractUnregisterOrigin::ensure_origin(origin)?;
+            let is_root = ensure_root(origin.clone()).is_ok();
+            if !is_root {
+                T::ContractUnregisterOrigin::ensure_origin(origin)?;
+            }
 
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
-            ContractStake::<T>::remove(&dapp_info.id);
-            IntegratedDApps::<T>::remove(&smart_contract);
-
             let current_era = ActiveProtocolState::<T>::get().era;
-            Self::deposit_event(Event::<T>::DAppUnregistered {
-                smart_contract,
-                era: current_era,
-            });
+            ensure!(
+                is_root
+                    || current_era
+                        >= dapp_info
+                            .registered_era
+                            .saturating_add(T::MinRegistrationEras::get()),
+                Error::<T>::UnregisterTooSoon
+            );
+
+            Self::remove_dapp(smart_contract, dapp_info.id, current_era);
 
             Ok(())
         }
@@ -871,6 +1304,7 @@ pub mod pallet {
         /// After adjustment, lock amount must be greater than zero and in total must be equal or greater than the minimum locked amount.
         ///
         /// Locked amount can immediately be used for staking.
+        /// New accounts are assigned the default lock purpose, existing accounts keep their current one.
         #[pallet::call_index(7)]
         #[pallet::weight(T::WeightInfo::lock_new_account().max(T::WeightInfo::lock_existing_account()))]
         pub fn lock(
@@ -880,48 +1314,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let mut ledger = Ledger::<T>::get(&account);
-
-            // Only do the check for new accounts.
-            // External logic should ensure that accounts which are already participating in dApp staking aren't
-            // allowed to participate elsewhere where they shouldn't.
-            let is_new_account = ledger.is_empty();
-            if is_new_account {
-                ensure!(
-                    T::AccountCheck::allowed_to_stake(&account),
-                    Error::<T>::AccountNotAvailableForDappStaking
-                );
-            }
-
-            // Calculate & check amount available for locking
-            let available_balance =
-                T::Currency::total_balance(&account).saturating_sub(ledger.total_locked_amount());
-            let amount_to_lock = available_balance.min(amount);
-            ensure!(!amount_to_lock.is_zero(), Error::<T>::ZeroAmount);
-
-            ledger.add_lock_amount(amount_to_lock);
-
-            ensure!(
-                ledger.active_locked_amount() >= T::MinimumLockedAmount::get(),
-                Error::<T>::LockedAmountBelowThreshold
-            );
-
-            Self::update_ledger(&account, ledger)?;
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.add_locked(amount_to_lock);
-            });
-
-            Self::deposit_event(Event::<T>::Locked {
-                account,
-                amount: amount_to_lock,
-            });
-
-            Ok(Some(if is_new_account {
-                T::WeightInfo::lock_new_account()
-            } else {
-                T::WeightInfo::lock_existing_account()
-            })
-            .into())
+            Self::internal_lock(account, amount, None)
         }
 
         /// Attempts to start the unlocking process for the specified amount.
@@ -929,6 +1322,8 @@ pub mod pallet {
         /// Only the amount that isn't actively used for staking can be unlocked.
         /// If the amount is greater than the available amount for unlocking, everything is unlocked.
         /// If the remaining locked amount would take the account below the minimum locked amount, everything is unlocked.
+        ///
+        /// Unlocking during the voting subperiod can be disabled via `LockUnlockDuringVoting`.
         #[pallet::call_index(8)]
         #[pallet::weight(T::WeightInfo::unlock())]
         pub fn unlock(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
@@ -936,6 +1331,10 @@ pub mod pallet {
             let account = ensure_signed(origin)?;
 
             let state = ActiveProtocolState::<T>::get();
+            ensure!(
+                T::LockUnlockDuringVoting::get() || state.subperiod() != Subperiod::Voting,
+                Error::<T>::UnlockForbiddenDuringVoting
+            );
             let mut ledger = Ledger::<T>::get(&account);
 
             let available_for_unlocking = ledger.unlockable_amount(state.period_info.number);
@@ -962,7 +1361,10 @@ pub mod pallet {
             ledger.subtract_lock_amount(amount_to_unlock);
 
             let current_block = frame_system::Pallet::<T>::block_number();
-            let unlock_block = current_block.saturating_add(Self::unlocking_period().into());
+            let unlock_block = current_block.saturating_add(
+                T::UnlockingPeriodFn::unlocking_period(amount_to_unlock, Self::unlocking_period())
+                    .into(),
+            );
             ledger
                 .add_unlocking_chunk(amount_to_unlock, unlock_block.saturated_into())
                 .map_err(|_| Error::<T>::TooManyUnlockingChunks)?;
@@ -982,6 +1384,7 @@ pub mod pallet {
         }
 
         /// Claims all of fully unlocked chunks, removing the lock from them.
+        /// If the account enabled auto-relocking, the matured chunks are relocked instead, when possible.
         #[pallet::call_index(9)]
         #[pallet::weight(T::WeightInfo::claim_unlocked(T::MaxNumberOfStakedContracts::get()))]
         pub fn claim_unlocked(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
@@ -1028,6 +1431,8 @@ pub mod pallet {
         /// and same for `Build&Earn` subperiod.
         ///
         /// Staked amount is only eligible for rewards from the next era onwards.
+        ///
+        /// If `StakeIncrement` is set, the amount is rounded down to a multiple of it first.
         #[pallet::call_index(11)]
         #[pallet::weight(T::WeightInfo::stake())]
         pub fn stake(
@@ -1038,43 +1443,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            // User is only eligible for the bonus reward if their first time stake is in the `Voting` subperiod.
-            //
-            // `StakeAmount` is prepared based on the current subperiod.
-            // If the user is staking for the first time in the `Voting` subperiod, they are eligible for the bonus reward, and the max number of bonus moves is set.
-            // If the user is staking for the first time in the `Build&Earn` subperiod, they are not eligible for the bonus reward, and the bonus moves are set to 0.
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let (stake_amount, bonus_status) = match protocol_state.subperiod() {
-                Subperiod::Voting => (
-                    StakeAmount {
-                        voting: amount,
-                        build_and_earn: 0,
-                        era: protocol_state.era,
-                        period: protocol_state.period_number(),
-                    },
-                    *BonusStatusWrapperFor::<T>::default(),
-                ),
-                Subperiod::BuildAndEarn => (
-                    StakeAmount {
-                        voting: 0,
-                        build_and_earn: amount,
-                        era: protocol_state.era,
-                        period: protocol_state.period_number(),
-                    },
-                    0,
-                ),
-            };
-
-            // The `inner_stake` function takes a `StakeAmount` struct allowing modification of both `voting` and `build_and_earn` amounts at the same time.
-            Self::inner_stake(&account, &smart_contract, stake_amount, bonus_status)?;
-
-            Self::deposit_event(Event::<T>::Stake {
-                account,
-                smart_contract,
-                amount,
-            });
-
-            Ok(())
+            Self::internal_stake(account, smart_contract, amount)
         }
 
         /// Unstake the specified amount from a smart contract.
@@ -1096,22 +1465,14 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let (unstake_amount, _) = Self::inner_unstake(&account, &smart_contract, amount)?;
-
-            Self::deposit_event(Event::<T>::Unstake {
-                account,
-                smart_contract,
-                amount: unstake_amount.total(),
-            });
-
-            Ok(())
+            Self::internal_unstake(account, smart_contract, amount)
         }
 
         /// Claims some staker rewards, if user has any.
         /// In the case of a successful call, at least one era will be claimed, with the possibility of multiple claims happening.
         #[pallet::call_index(13)]
         #[pallet::weight({
-            let max_span_length = T::EraRewardSpanLength::get();
+            let max_span_length = T::EraRewardSpanLength::get().min(T::MaxEraRewardsPerClaim::get());
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
         })]
@@ -1119,7 +1480,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            Self::internal_claim_staker_rewards_for(account)
+            Self::internal_claim_staker_rewards_for(account, None, None, Permill::zero())
         }
 
         /// Used to claim bonus reward for a smart contract, if eligible.
@@ -1136,13 +1497,18 @@ pub mod pallet {
         }
 
         /// Used to claim dApp reward for the specified era.
+        ///
+        /// If auto-compounding is enabled for the dApp, the reward is locked & staked on the same contract by the beneficiary.
+        /// In case that isn't possible, the reward remains liquid.
         #[pallet::call_index(15)]
-        #[pallet::weight(T::WeightInfo::claim_dapp_reward())]
+        #[pallet::weight(T::WeightInfo::claim_dapp_reward()
+            .saturating_add(T::WeightInfo::lock_new_account())
+            .saturating_add(T::WeightInfo::stake()))]
         pub fn claim_dapp_reward(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] era: EraNumber,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
 
             // To keep in line with legacy behavior, dApp rewards can be claimed by anyone.
@@ -1150,46 +1516,21 @@ pub mod pallet {
 
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
-
-            // Make sure provided era has ended
             let protocol_state = ActiveProtocolState::<T>::get();
-            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
-
-            // 'Consume' dApp reward for the specified era, if possible.
-            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
-            ensure!(
-                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            let (amount, ranked_tier) =
-                dapp_tiers
-                    .try_claim(dapp_info.id)
-                    .map_err(|error| match error {
-                        DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
-                        _ => Error::<T>::InternalClaimDAppError,
-                    })?;
-
-            let (tier_id, rank) = ranked_tier.deconstruct();
-
-            // Get reward destination, and deposit the reward.
-            let beneficiary = dapp_info.reward_beneficiary();
-            T::StakingRewardHandler::payout_reward(&beneficiary, amount)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            // Write back updated struct to prevent double reward claims
-            DAppTiers::<T>::insert(&era, dapp_tiers);
 
-            Self::deposit_event(Event::<T>::DAppReward {
-                beneficiary: beneficiary.clone(),
-                smart_contract,
-                tier_id,
-                rank,
+            let (beneficiary, amount) = Self::internal_claim_dapp_reward(
+                &dapp_info,
+                &smart_contract,
                 era,
-                amount,
-            });
+                &protocol_state,
+            )?;
 
-            Ok(())
+            if DAppAutoCompound::<T>::get(dapp_info.id) {
+                Self::auto_compound_dapp_reward(beneficiary, smart_contract, amount);
+                Ok(().into())
+            } else {
+                Ok(Some(T::WeightInfo::claim_dapp_reward()).into())
+            }
         }
 
         /// Used to unstake funds from a contract that was unregistered after an account staked on it.
@@ -1261,10 +1602,12 @@ pub mod pallet {
             // Remove all expired entries.
             for smart_contract in to_be_deleted {
                 StakerInfo::<T>::remove(&account, &smart_contract);
+                ContractStakers::<T>::remove(&smart_contract, &account);
             }
 
             // Remove expired stake entries from the ledger.
             let mut ledger = Ledger::<T>::get(&account);
+            Self::note_staked_contracts_change(ledger.contract_stake_count, remaining);
             ledger.contract_stake_count = remaining;
             ledger.maybe_cleanup_expired(threshold_period); // Not necessary but we do it for the sake of consistency
             Self::update_ledger(&account, ledger)?;
@@ -1286,6 +1629,8 @@ pub mod pallet {
         /// Used for testing purposes, when we want to force an era change, or a subperiod change.
         /// Not intended to be used in production, except in case of unforeseen circumstances.
         ///
+        /// Subperiod can only be forced once `MinSubperiodForceInterval` eras have passed since it was last forced.
+        ///
         /// Can only be called by the root origin.
         #[pallet::call_index(18)]
         #[pallet::weight(T::WeightInfo::force())]
@@ -1295,6 +1640,18 @@ pub mod pallet {
 
             ensure!(!Safeguard::<T>::get(), Error::<T>::ForceNotAllowed);
 
+            if forcing_type == ForcingType::Subperiod {
+                let current_era = ActiveProtocolState::<T>::get().era;
+                if let Some(last_forced_era) = LastForcedSubperiodEra::<T>::get() {
+                    ensure!(
+                        current_era.saturating_sub(last_forced_era)
+                            >= T::MinSubperiodForceInterval::get(),
+                        Error::<T>::SubperiodForceTooSoon
+                    );
+                }
+                LastForcedSubperiodEra::<T>::put(current_era);
+            }
+
             // Ensure a 'change' happens on the next block
             ActiveProtocolState::<T>::mutate(|state| {
                 let current_block = frame_system::Pallet::<T>::block_number();
@@ -1321,9 +1678,11 @@ pub mod pallet {
 
         /// Claims some staker rewards for the specified account, if they have any.
         /// In the case of a successful call, at least one era will be claimed, with the possibility of multiple claims happening.
+        ///
+        /// If the caller isn't the specified account, at most `KeeperClaimWindow` eras are claimed.
         #[pallet::call_index(19)]
         #[pallet::weight({
-            let max_span_length = T::EraRewardSpanLength::get();
+            let max_span_length = T::EraRewardSpanLength::get().min(T::MaxEraRewardsPerClaim::get());
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
         })]
@@ -1332,9 +1691,10 @@ pub mod pallet {
             account: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            ensure_signed(origin)?;
+            let caller = ensure_signed(origin)?;
 
-            Self::internal_claim_staker_rewards_for(account)
+            let max_claim_eras = (caller != account).then(T::KeeperClaimWindow::get);
+            Self::internal_claim_staker_rewards_for(account, None, max_claim_eras, Permill::zero())
         }
 
         /// Used to claim bonus reward for a smart contract on behalf of the specified account, if eligible.
@@ -1353,6 +1713,8 @@ pub mod pallet {
 
         /// Transfers stake between two smart contracts, ensuring bonus status preservation if eligible.
         /// Emits a `StakeMoved` event.
+        ///
+        /// If `MaxMovesPerPeriod` is set, the number of moves per account is limited within a single period.
         #[pallet::call_index(21)]
         #[pallet::weight(T::WeightInfo::move_stake_unregistered_source().max(T::WeightInfo::move_stake_from_registered_source()))]
         pub fn move_stake(
@@ -1364,45 +1726,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            ensure!(
-                !source_contract.eq(&destination_contract),
-                Error::<T>::SameContracts
-            );
-
-            ensure!(
-                IntegratedDApps::<T>::contains_key(&destination_contract),
-                Error::<T>::ContractNotFound
-            );
-
-            let maybe_source_dapp_info = IntegratedDApps::<T>::get(&source_contract);
-            let is_source_unregistered = maybe_source_dapp_info.is_none();
-
-            let (mut move_amount, bonus_status) = if is_source_unregistered {
-                Self::inner_unstake_from_unregistered(&account, &source_contract)?
-            } else {
-                Self::inner_unstake(&account, &source_contract, amount)?
-            };
-
-            // When bonus is forfeited, voting stake must be merged into b&e stake
-            if bonus_status == 0 && move_amount.voting > 0 {
-                move_amount.convert_bonus_into_regular_stake();
-            }
-
-            Self::inner_stake(&account, &destination_contract, move_amount, bonus_status)?;
-
-            Self::deposit_event(Event::<T>::StakeMoved {
-                account,
-                source_contract,
-                destination_contract,
-                amount: move_amount.total(),
-            });
-
-            Ok(Some(if is_source_unregistered {
-                T::WeightInfo::move_stake_unregistered_source()
-            } else {
-                T::WeightInfo::move_stake_from_registered_source()
-            })
-            .into())
+            Self::internal_move_stake(account, source_contract, destination_contract, amount)
         }
 
         /// Used to set static tier parameters, which are used to calculate tier configuration.
@@ -1426,874 +1750,3424 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Inner `unstake` functionality for an **active** smart contract.
-        /// If successful returns the `StakeAmount` that was unstaked, and the updated bonus status.
+        /// Claims some staker rewards, if user has any, and pays them out to the `destination` account.
+        /// In the case of a successful call, at least one era will be claimed, with the possibility of multiple claims happening.
         ///
-        /// - Ensures the contract is still registered.
-        /// - Updates staker info, ledger, and contract stake info.
-        /// - Returns the unstaked amount and updated bonus status.
-        pub fn inner_unstake(
-            account: &T::AccountId,
-            smart_contract: &T::SmartContract,
-            amount: Balance,
-        ) -> Result<(StakeAmount, BonusStatus), DispatchError> {
-            ensure!(amount > 0, Error::<T>::ZeroAmount);
-            let dapp_info =
-                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
-
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
+        /// Destination account must be allowed to participate in dApp staking, and must be able to hold
+        /// the minimum balance after the payout.
+        #[pallet::call_index(23)]
+        #[pallet::weight({
+            let max_span_length = T::EraRewardSpanLength::get().min(T::MaxEraRewardsPerClaim::get());
+            T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+        })]
+        pub fn claim_staker_rewards_to(
+            origin: OriginFor<T>,
+            destination: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-            let mut ledger = Ledger::<T>::get(&account);
+            ensure!(
+                T::AccountCheck::allowed_to_stake(&destination),
+                Error::<T>::InvalidRewardDestination
+            );
 
-            // 1.
-            // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
-            let (new_staking_info, amount, stake_amount_iter, updated_bonus_status) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    Some(mut staking_info) => {
-                        ensure!(
-                            staking_info.period_number() == protocol_state.period_number(),
-                            Error::<T>::UnstakeFromPastPeriod
-                        );
-                        ensure!(
-                            staking_info.total_staked_amount() >= amount,
-                            Error::<T>::UnstakeAmountTooLarge
-                        );
+            Self::internal_claim_staker_rewards_for(
+                account,
+                Some(destination),
+                None,
+                Permill::zero(),
+            )
+        }
 
-                        // If unstaking would take the total staked amount below the minimum required value,
-                        // unstake everything.
-                        let amount = if staking_info.total_staked_amount().saturating_sub(amount)
-                            < T::MinimumStakeAmount::get()
-                        {
-                            staking_info.total_staked_amount()
-                        } else {
-                            amount
-                        };
+        /// Unregister a dApp which hasn't been staked on for at least `InactivityUnregisterPeriods` consecutive periods.
+        ///
+        /// Can be called by anyone. Ongoing period isn't counted, since stake can still be added during it.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::unregister_inactive())]
+        pub fn unregister_inactive(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            ensure_signed(origin)?;
+            let inactivity_periods = T::InactivityUnregisterPeriods::get()
+                .ok_or(Error::<T>::InactivityUnregisterDisabled)?;
 
-                        let (stake_amount_iter, updated_bonus_status) =
-                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
-                        (
-                            staking_info,
-                            amount,
-                            stake_amount_iter,
-                            updated_bonus_status,
-                        )
-                    }
-                    None => {
-                        return Err(Error::<T>::NoStakingInfo.into());
-                    }
-                };
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_period = protocol_state.period_number();
+            let last_staked_period =
+                LastStakedPeriod::<T>::get(dapp_info.id).unwrap_or(current_period);
+            ensure!(
+                current_period > last_staked_period.saturating_add(inactivity_periods),
+                Error::<T>::DAppNotInactive
+            );
 
-            // 2.
-            // Reduce stake amount
-            ledger
-                .unstake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    // This is a defensive check, which should never happen since we calculate the correct value above.
-                    AccountLedgerError::UnstakeAmountLargerThanStake => {
-                        Error::<T>::UnstakeAmountTooLarge
-                    }
-                    _ => Error::<T>::InternalUnstakeError,
-                })?;
+            Self::remove_dapp(smart_contract, dapp_info.id, protocol_state.era);
 
-            // 3.
-            // Update `ContractStake` storage with the reduced stake amount on the specified contract.
-            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
-            contract_stake_info.unstake(
-                &stake_amount_iter,
-                protocol_state.period_info,
-                current_era,
-            );
+            Ok(())
+        }
 
-            // 4.
-            // Update total staked amount for the next era.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.unstake_amount(stake_amount_iter.clone());
-            });
+        /// Locks additional funds into dApp staking, tagged with the specified purpose.
+        ///
+        /// Behaves the same as `lock`, but the purpose of an account's lock can only be chosen when it's created.
+        /// Locking more funds into an existing ledger requires the same purpose to be used.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::lock_new_account().max(T::WeightInfo::lock_existing_account()))]
+        pub fn lock_tagged(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: Balance,
+            purpose: LockPurpose,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-            // 5.
-            // Update remaining storage entries
-            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+            Self::internal_lock(account, amount, Some(purpose))
+        }
 
-            if new_staking_info.is_empty() {
-                ledger.contract_stake_count.saturating_dec();
-                StakerInfo::<T>::remove(&account, &smart_contract);
-            } else {
-                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+        /// Approve the transfer of the `source` account's staking position to the `dest` account.
+        ///
+        /// Must be called either by the `dest` account itself, or by the manager origin.
+        /// Any previous approval for the `dest` account is overwritten.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::approve_position_transfer())]
+        pub fn approve_position_transfer(
+            origin: OriginFor<T>,
+            source: T::AccountId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            if T::ManagerOrigin::ensure_origin(origin.clone()).is_err() {
+                let caller = ensure_signed(origin)?;
+                ensure!(caller == dest, DispatchError::BadOrigin);
             }
 
-            Self::update_ledger(&account, ledger)?;
-
-            // Return the `StakeAmount` that has max total value.
-            let mut unstake_amount = stake_amount_iter
-                .iter()
-                .max_by(|a, b| a.total().cmp(&b.total()))
-                // At least one value exists, otherwise we wouldn't be here.
-                .ok_or(Error::<T>::InternalUnstakeError)?
-                .clone();
+            PositionTransferApprovals::<T>::insert(&dest, &source);
 
-            // Ensure we use the current era instead of potentially next era
-            unstake_amount.era = current_era;
+            Self::deposit_event(Event::<T>::PositionTransferApproved { source, dest });
 
-            Ok((unstake_amount, updated_bonus_status))
+            Ok(())
         }
 
-        /// Handles unstaking from an **unregistered** smart contract.
+        /// Transfer the caller's entire staking position (locked, unlocking & staked amounts, and all staker info entries)
+        /// to the `dest` account, together with the underlying locked funds.
         ///
-        /// - Ensures the contract is no longer active.
-        /// - Updates staker info and ledger.
-        /// - Returns the unstaked amount and preserves the original bonus status.
-        pub fn inner_unstake_from_unregistered(
-            account: &T::AccountId,
-            smart_contract: &T::SmartContract,
-        ) -> Result<(StakeAmount, BonusStatus), DispatchError> {
+        /// The `dest` account must have approved the transfer, must be allowed to stake, and must not have an existing position.
+        /// Contract stake & era info totals remain unchanged, only the owner of the position changes.
+        /// Moves made in the ongoing period & the auto-relock setting are carried over to `dest`,
+        /// while the caller's stake delegate & claim reminder are cleared.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::transfer_position(T::MaxNumberOfStakedContracts::get()))]
+        pub fn transfer_position(
+            origin: OriginFor<T>,
+            dest: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let source = ensure_signed(origin)?;
+
             ensure!(
-                !IntegratedDApps::<T>::contains_key(&smart_contract),
-                Error::<T>::ContractStillActive
+                PositionTransferApprovals::<T>::get(&dest).as_ref() == Some(&source),
+                Error::<T>::PositionTransferNotApproved
+            );
+            ensure!(
+                T::AccountCheck::allowed_to_stake(&dest),
+                Error::<T>::AccountNotAvailableForDappStaking
+            );
+            ensure!(
+                !Ledger::<T>::contains_key(&dest),
+                Error::<T>::DestinationHasPosition
             );
 
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
-
-            // Extract total staked amount on the specified unregistered contract
-            let (amount, unstake_amount_iter, preserved_bonus_status) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    Some(mut staking_info) => {
-                        ensure!(
-                            staking_info.period_number() == protocol_state.period_number(),
-                            Error::<T>::UnstakeFromPastPeriod
-                        );
-
-                        let preserved_bonus_status = staking_info.bonus_status;
-                        let amount = staking_info.staked.total();
-
-                        let (unstake_amount_iter, _) =
-                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
-
-                        (amount, unstake_amount_iter, preserved_bonus_status)
-                    }
-                    None => {
-                        return Err(Error::<T>::NoStakingInfo.into());
-                    }
-                };
+            let ledger = Ledger::<T>::get(&source);
+            ensure!(!ledger.is_empty(), Error::<T>::NoPositionToTransfer);
 
-            // Reduce stake amount in ledger
-            let mut ledger = Ledger::<T>::get(&account);
-            ledger
-                .unstake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    // These are all defensive checks, which should never fail since we already checked them above.
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    _ => Error::<T>::InternalUnstakeError,
-                })?;
-            ledger.contract_stake_count.saturating_dec();
+            // Move the locked funds, so the new position remains fully backed.
+            T::Currency::thaw(&FreezeReason::DAppStaking.into(), &source)?;
+            T::Currency::transfer(
+                &source,
+                &dest,
+                ledger.total_locked_amount(),
+                Preservation::Expendable,
+            )?;
 
-            // Update total staked amount for the next era.
-            // This means 'fake' stake total amount has been kept until now, even though contract was unregistered.
-            // Although strange, it's been requested to keep it like this from the team.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.unstake_amount(unstake_amount_iter.clone());
-            });
+            let mut moved_entries: u32 = 0;
+            for (smart_contract, staking_info) in StakerInfo::<T>::drain_prefix(&source) {
+                StakerInfo::<T>::insert(&dest, &smart_contract, staking_info);
+                ContractStakers::<T>::remove(&smart_contract, &source);
+                ContractStakers::<T>::insert(&smart_contract, &dest, ());
+                moved_entries.saturating_inc();
+            }
 
-            // Update remaining storage entries
-            Self::update_ledger(&account, ledger)?;
-            StakerInfo::<T>::remove(&account, &smart_contract);
+            // Per-account state follows the position, so e.g. the move limit can't be reset by transferring it.
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+            if let Some((period, moves)) = MovesInPeriod::<T>::take(&source) {
+                if period == current_period {
+                    let dest_moves = match MovesInPeriod::<T>::get(&dest) {
+                        Some((period, moves)) if period == current_period => moves,
+                        _ => 0,
+                    };
+                    MovesInPeriod::<T>::insert(
+                        &dest,
+                        (current_period, moves.saturating_add(dest_moves)),
+                    );
+                }
+            }
+            if AutoRelock::<T>::take(&source) {
+                AutoRelock::<T>::insert(&dest, true);
+            }
+            StakeDelegate::<T>::remove(&source);
+            ClaimReminderEra::<T>::remove(&source);
 
-            // Return the `StakeAmount` that has max total value.
-            let mut unstake_amount = unstake_amount_iter
-                .iter()
-                .max_by(|a, b| a.total().cmp(&b.total()))
-                // At least one value exists, otherwise we wouldn't be here.
-                .ok_or(Error::<T>::InternalUnstakeError)?
-                .clone();
+            Ledger::<T>::remove(&source);
+            Self::update_ledger(&dest, ledger)?;
+            PositionTransferApprovals::<T>::remove(&dest);
 
-            // Ensure we use the current era instead of potentially next era
-            unstake_amount.era = current_era;
+            Self::deposit_event(Event::<T>::PositionTransferred { source, dest });
 
-            Ok((unstake_amount, preserved_bonus_status))
+            Ok(Some(T::WeightInfo::transfer_position(moved_entries)).into())
         }
 
-        /// Inner `stake` functionality.
+        /// Enable or disable auto-compounding of the dApp rewards.
         ///
-        /// Specifies the amount in the form of the `StakeAmount` struct, allowing simultaneous update of both `voting` and `build_and_earn` amounts.
-        /// The `bonus_status` is used to determine if the staker is still eligible for the bonus reward. This is useful for the `move` extrinsic.
-        pub fn inner_stake(
-            account: &T::AccountId,
-            smart_contract: &T::SmartContract,
-            amount: StakeAmount,
-            bonus_status: BonusStatus,
-        ) -> Result<(), DispatchError> {
-            ensure!(amount.total() > 0, Error::<T>::ZeroAmount);
+        /// Caller has to be dApp owner.
+        /// When enabled, claimed dApp rewards are locked & staked on the same contract by the reward beneficiary,
+        /// instead of being paid out liquid.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_dapp_auto_compound())]
+        pub fn set_dapp_auto_compound(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
 
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
-            let period_number = protocol_state.period_info.number;
-            ensure!(
-                !protocol_state
-                    .period_info
-                    .is_next_period(current_era.saturating_add(1)),
-                Error::<T>::PeriodEndsInNextEra
-            );
-
-            let mut ledger = Ledger::<T>::get(&account);
-
-            // In case old stake rewards are unclaimed & have expired, clean them up.
-            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
-            let _ignore = ledger.maybe_cleanup_expired(threshold_period);
+            if enabled {
+                DAppAutoCompound::<T>::insert(dapp_info.id, true);
+            } else {
+                DAppAutoCompound::<T>::remove(dapp_info.id);
+            }
 
-            // 1.
-            // Increase stake amount for the next era & current period in staker's ledger
-            ledger
-                .add_stake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    AccountLedgerError::UnavailableStakeFunds => Error::<T>::UnavailableStakeFunds,
-                    // Defensive check, should never happen
-                    _ => Error::<T>::InternalStakeError,
-                })?;
+            Self::deposit_event(Event::<T>::DAppAutoCompoundUpdated {
+                smart_contract,
+                enabled,
+            });
 
-            // 2.
-            // Update `StakerInfo` storage with the new stake amount on the specified contract.
-            //
-            // There are three distinct scenarios:
-            // 1. Existing entry matches the current period number - just update it.
-            // 2. Existing entry is expired/orphaned - replace it.
-            // 3. Entry doesn't exist or it's for an older period - create a new one.
-            //
-            // This is ok since we only use this storage entry to keep track of how much each staker
-            // has staked on each contract in the current period. We only ever need the latest information.
-            // This is because `AccountLedger` is the one keeping information about how much was staked when.
-            let (mut new_staking_info, is_new_entry, replacing_old_entry) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    // Entry with matching period exists
-                    Some(staking_info)
-                        if staking_info.period_number() == protocol_state.period_number() =>
-                    {
-                        (staking_info, false, false)
-                    }
-                    // Entry exists but period doesn't match. Bonus reward might still be claimable.
-                    Some(staking_info)
-                        if staking_info.period_number() >= threshold_period
-                            && staking_info.is_bonus_eligible() =>
-                    {
-                        return Err(Error::<T>::UnclaimedRewards.into());
-                    }
-                    // Entry exists but is expired/orphaned - we're replacing it, not adding new
-                    Some(_old_entry) => {
-                        // Remove the old orphaned entry explicitly
-                        StakerInfo::<T>::remove(&account, &smart_contract);
-                        (
-                            SingularStakingInfo::new(protocol_state.period_number(), bonus_status),
-                            true, // is_new_entry (for storage write)
-                            true, // replacing_old_entry (don't increment counter)
-                        )
-                    }
-                    // No entry exists at all - truly new
-                    None => (
-                        SingularStakingInfo::new(
-                            protocol_state.period_number(),
-                            // Important to account for the remaining bonus moves since it's possible to retain the bonus
-                            // after moving the stake to another contract.
-                            bonus_status,
-                        ),
-                        true,
-                        false,
-                    ),
-                };
+            Ok(())
+        }
 
-            new_staking_info.stake(amount, current_era, bonus_status);
+        /// Claims some staker rewards, same as `claim_staker_rewards`, and burns `burn_fraction` of the claimed amount.
+        ///
+        /// The remaining part of the rewards is paid out to the staker.
+        /// Burning all of the rewards isn't allowed.
+        #[pallet::call_index(29)]
+        #[pallet::weight({
+            let max_span_length = T::EraRewardSpanLength::get().min(T::MaxEraRewardsPerClaim::get());
+            T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+        })]
+        pub fn claim_staker_rewards_with_burn(
+            origin: OriginFor<T>,
+            burn_fraction: Permill,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
             ensure!(
-                new_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
-                Error::<T>::InsufficientStakeAmount
+                burn_fraction < Permill::one(),
+                Error::<T>::InvalidBurnFraction
             );
 
-            // Only increment if we're truly adding a new contract, not replacing an orphaned entry
-            if is_new_entry && !replacing_old_entry {
-                ledger.contract_stake_count.saturating_inc();
-                ensure!(
-                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
-                    Error::<T>::TooManyStakedContracts
-                );
-            }
+            Self::internal_claim_staker_rewards_for(account, None, None, burn_fraction)
+        }
 
-            // 3.
-            // Update `ContractStake` storage with the new stake amount on the specified contract.
-            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
-            contract_stake_info.stake(amount, current_era, period_number);
+        /// Sets or clears the number of slots per tier, used instead of the calculated values.
+        ///
+        /// Takes effect from the next era, when the tier configuration is recalculated,
+        /// and remains in effect until it's cleared.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::set_slots_per_tier_override())]
+        pub fn set_slots_per_tier_override(
+            origin: OriginFor<T>,
+            slots: Option<BoundedVec<u16, T::NumberOfTiers>>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
 
-            // 4.
-            // Update total staked amount for the next era.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.add_stake_amount(amount);
-            });
+            match &slots {
+                Some(slots_per_tier) => {
+                    ensure!(
+                        slots_per_tier.len() == T::NumberOfTiers::get() as usize,
+                        Error::<T>::InvalidSlotsPerTierOverride
+                    );
+                    SlotsPerTierOverride::<T>::put(slots_per_tier);
+                }
+                None => SlotsPerTierOverride::<T>::kill(),
+            }
 
-            // 5.
-            // Update remaining storage entries
-            Self::update_ledger(&account, ledger)?;
-            StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
-            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+            Self::deposit_event(Event::<T>::SlotsPerTierOverrideSet { slots });
 
             Ok(())
         }
 
-        /// `true` if the account is a staker, `false` otherwise.
-        pub fn is_staker(account: &T::AccountId) -> bool {
-            Ledger::<T>::contains_key(account)
-        }
+        /// Sets or clears the best tier the dApp can be assigned to.
+        ///
+        /// Caller has to be dApp owner.
+        /// A capped dApp is never assigned to a tier better than `max_tier`, even if its stake qualifies for it,
+        /// leaving the better tier slot to other dApps. Takes effect from the next tier assignment.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::set_tier_cap())]
+        pub fn set_tier_cap(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            max_tier: Option<TierId>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
-        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
-        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
-            if ActiveProtocolState::<T>::get().maintenance {
-                Err(Error::<T>::Disabled)
-            } else {
-                Ok(())
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+
+            match max_tier {
+                Some(tier) => {
+                    ensure!(
+                        u32::from(tier) < T::NumberOfTiers::get(),
+                        Error::<T>::InvalidTierCap
+                    );
+                    DAppTierCap::<T>::insert(dapp_info.id, tier);
+                }
+                None => DAppTierCap::<T>::remove(dapp_info.id),
             }
+
+            Self::deposit_event(Event::<T>::DAppTierCapSet {
+                smart_contract,
+                max_tier,
+            });
+
+            Ok(())
         }
 
-        /// Update the account ledger, and dApp staking balance freeze.
-        ///
-        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        /// Sets or clears the claim reminder of the caller.
         ///
-        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
-        /// runtime definition must ensure it supports necessary freezes.
-        pub(crate) fn update_ledger(
-            account: &T::AccountId,
-            ledger: AccountLedgerFor<T>,
-        ) -> Result<(), DispatchError> {
-            if ledger.is_empty() {
-                Ledger::<T>::remove(&account);
-                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
-            } else {
-                T::Currency::set_freeze(
-                    &FreezeReason::DAppStaking.into(),
-                    account,
-                    ledger.total_locked_amount(),
-                )?;
-                Ledger::<T>::insert(account, ledger);
+        /// When set, [`Event::ClaimReminderDue`] is emitted for the caller every `every` eras, starting
+        /// from the current era, giving indexers a clean trigger for claim notifications.
+        /// Reminders are checked in bounded batches at era change, so a reminder might be delayed if many are set.
+        /// Caller must have locked funds, and the reminder is removed once that's no longer the case.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::set_claim_reminder())]
+        pub fn set_claim_reminder(
+            origin: OriginFor<T>,
+            every: Option<EraNumber>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            match every {
+                Some(interval) => {
+                    ensure!(
+                        !interval.is_zero(),
+                        Error::<T>::InvalidClaimReminderInterval
+                    );
+                    ensure!(
+                        Ledger::<T>::contains_key(&account),
+                        Error::<T>::ClaimReminderWithoutLock
+                    );
+
+                    let current_era = ActiveProtocolState::<T>::get().era;
+                    ClaimReminderEra::<T>::insert(
+                        &account,
+                        ClaimReminder {
+                            every: interval,
+                            next_era: current_era.saturating_add(interval),
+                        },
+                    );
+                }
+                None => ClaimReminderEra::<T>::remove(&account),
             }
 
+            Self::deposit_event(Event::<T>::ClaimReminderSet { account, every });
+
             Ok(())
         }
 
-        /// Returns the number of blocks per voting period.
-        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era()
-                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
-        }
+        /// Reclaims the unlocking chunks of `who` which have remained unclaimed for at least `AbandonedChunkEras`
+        /// standard eras after they matured, transferring them to the `AbandonedChunkBeneficiary` account.
+        ///
+        /// Can be called by anyone, but only if enabled via `AbandonedChunkEras`.
+        /// Chunks which are still unlocking, or have matured only recently, are never affected.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::reclaim_abandoned(T::MaxNumberOfStakedContracts::get()))]
+        pub fn reclaim_abandoned(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            ensure_signed(origin)?;
 
-        /// Calculates the `EraRewardSpan` index for the specified era.
-        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
-            era.saturating_sub(era % T::EraRewardSpanLength::get())
-        }
+            let abandoned_eras = T::AbandonedChunkEras::get()
+                .filter(|eras| !eras.is_zero())
+                .ok_or(Error::<T>::AbandonedChunkReclaimDisabled)?;
+            let abandoned_blocks = ActiveCycleConfiguration::<T>::blocks_per_era()
+                .saturating_mul(abandoned_eras.into());
 
-        /// Return the oldest period for which rewards can be claimed.
-        /// All rewards before that period are considered to be expired.
-        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
-            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
+            Self::internal_reclaim_abandoned(who, abandoned_blocks)
         }
 
-        /// Unlocking period expressed in the number of blocks.
-        pub fn unlocking_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
-        }
+        /// Used to claim dApp rewards for multiple eras of the same contract.
+        ///
+        /// Eras which cannot be claimed, e.g. because they were already claimed or have expired, are skipped.
+        /// [`Event::DAppReward`] is emitted for each successfully claimed era, and the call fails only if nothing was claimed.
+        /// If auto-compounding is enabled for the dApp, the total claimed reward is locked & staked at once.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::claim_dapp_reward()
+            .saturating_mul(eras.len() as u64)
+            .saturating_add(T::WeightInfo::lock_new_account())
+            .saturating_add(T::WeightInfo::stake()))]
+        pub fn claim_dapp_reward_batch(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            eras: BoundedVec<EraNumber, T::MaxDAppClaimBatch>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
 
-        /// Returns the dApp tier assignment for the current era, based on the current stake amounts.
-        pub fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
+            // Same as for the single era claim, dApp rewards can be claimed by anyone.
+            let _ = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
             let protocol_state = ActiveProtocolState::<T>::get();
 
-            let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
-                protocol_state.era,
-                protocol_state.period_number(),
-                Balance::zero(),
-            );
+            let mut total_amount = Balance::zero();
+            let mut claimed_eras: u32 = 0;
+            for era in eras.iter() {
+                // Each era is claimed in its own storage layer, so a failed claim leaves no trace.
+                let result = with_storage_layer(|| {
+                    Self::internal_claim_dapp_reward(
+                        &dapp_info,
+                        &smart_contract,
+                        *era,
+                        &protocol_state,
+                    )
+                });
 
-            dapp_tiers.dapps.into_inner()
+                if let Ok((_, amount)) = result {
+                    total_amount.saturating_accrue(amount);
+                    claimed_eras.saturating_inc();
+                }
+            }
+            ensure!(claimed_eras > 0, Error::<T>::NoClaimableRewards);
+
+            let claim_weight =
+                T::WeightInfo::claim_dapp_reward().saturating_mul(claimed_eras.into());
+            if DAppAutoCompound::<T>::get(dapp_info.id) {
+                let beneficiary = dapp_info.reward_beneficiary();
+                Self::auto_compound_dapp_reward(beneficiary, smart_contract, total_amount);
+                Ok(Some(
+                    claim_weight
+                        .saturating_add(T::WeightInfo::lock_new_account())
+                        .saturating_add(T::WeightInfo::stake()),
+                )
+                .into())
+            } else {
+                Ok(Some(claim_weight).into())
+            }
         }
 
-        /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
-        ///
-        /// ### Algorithm
-        ///
-        /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
-        ///    This information is used to calculate 'score' per dApp, which is used to determine the tier.
-        ///
-        /// 2. Sort the entries by the score, in descending order - the top score dApp comes first.
-        ///
-        /// 3. Assign dApps to tiers based on stake thresholds, calculating ratio-based ranks within each tier.
+        /// Sets or clears the share of the combined staker & dApp reward pools allocated to stakers.
         ///
-        /// 4. Calculate reward components per tier using the **rank multiplier model**:
+        /// The remainder goes to the dApp reward pool, while the combined amount remains as provided by the reward handler.
+        /// Takes effect from the next era reward calculation, and remains in effect until it's cleared.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::set_reward_split())]
+        pub fn set_reward_split(origin: OriginFor<T>, split: Option<Perbill>) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            RewardPoolSplitOverride::<T>::set(split);
+
+            Self::deposit_event(Event::<T>::RewardPoolSplitOverrideSet { split });
+
+            Ok(())
+        }
+
+        /// Forcefully sets the bonus status of the account's stake on the smart contract.
         ///
-        ///    ```text
-        ///    Step 1: Calculate weights
-        ///        increment = (multiplier - 100%) ÷ MAX_RANK
-        ///        dApp_weight = 100% + rank × increment
+        /// Intended as a repair tool in case the bonus status ends up inconsistent.
+        /// The status is clamped to `MaxBonusSafeMovesPerPeriod + 1`, the value assigned to a fresh eligible stake.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::force_set_bonus_status())]
+        pub fn force_set_bonus_status(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            status: BonusStatus,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let new_status = status.min(*BonusStatusWrapperFor::<T>::default());
+            let old_status = StakerInfo::<T>::try_mutate(
+                &account,
+                &smart_contract,
+                |maybe_staking_info| -> Result<BonusStatus, DispatchError> {
+                    let staking_info = maybe_staking_info
+                        .as_mut()
+                        .ok_or(Error::<T>::NoStakingInfo)?;
+                    let old_status = staking_info.bonus_status;
+                    staking_info.bonus_status = new_status;
+                    Ok(old_status)
+                },
+            )?;
+
+            Self::deposit_event(Event::<T>::BonusStatusForced {
+                account,
+                smart_contract,
+                old_status,
+                new_status,
+            });
+
+            Ok(())
+        }
+
+        /// Enables or disables auto-relocking of matured unlocking chunks for the caller.
         ///
-        ///    Step 2: Find reward rate (with overflow cap)
-        ///        target_weight = max_slots × avg_weight       (calibrated for avg rank = 5)
-        ///        actual_weight = filled_slots × 100% + ranks_sum × increment
-        ///        reward_per_% = tier_allocation ÷ max(actual_weight, target_weight)
+        /// When enabled, matured unlocking chunks are moved back to the active lock on the next `lock` or `claim_unlocked`,
+        /// instead of being released to the free balance. Chunks are only relocked if the resulting active lock
+        /// satisfies the minimum locked amount, otherwise they can be claimed as usual.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::set_auto_relock())]
+        pub fn set_auto_relock(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            if enabled {
+                AutoRelock::<T>::insert(&account, true);
+            } else {
+                AutoRelock::<T>::remove(&account);
+            }
+
+            Self::deposit_event(Event::<T>::AutoRelockUpdated { account, enabled });
+
+            Ok(())
+        }
+
+        /// Claims staker rewards on behalf of the stakers of the specified smart contract.
         ///
-        ///    Step 3: Pre-compute claim values
-        ///        tier_reward = 100% × reward_per_%
-        ///        rank_reward = increment × reward_per_%
-        ///    ```
-        ///    (Sort the entries by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
+        /// Caller has to be dApp owner, and pays for the transaction, while the rewards still go to the stakers.
+        /// At most `max_accounts` stakers of the contract are processed, starting after `start_after`, if specified.
+        /// Same as with `claim_staker_rewards_for`, at most `KeeperClaimWindow` eras are claimed per staker.
+        /// Stakers which have nothing to claim are skipped.
         ///
-        /// The returned object contains information about each dApp that made it into a tier.
-        /// Alongside tier assignment info, number of read DB contract stake entries is returned.
-        pub(crate) fn get_dapp_tier_assignment_and_rewards(
-            era: EraNumber,
-            period: PeriodNumber,
-            dapp_reward_pool: Balance,
-        ) -> (DAppTierRewardsFor<T>, DAppId) {
-            let mut dapp_stakes = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
+        /// The last processed account is deposited in the `ContractStakersClaimed` event, as a cursor for the next call.
+        #[pallet::call_index(38)]
+        #[pallet::weight(Pallet::<T>::claim_for_contract_stakers_weight(*max_accounts))]
+        pub fn claim_for_contract_stakers(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            max_accounts: u32,
+            start_after: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
-            // 1.
-            // Iterate over all staked dApps.
-            // This is bounded by max amount of dApps we allow to be registered.
-            let mut counter = 0;
-            for (dapp_id, stake_amount) in ContractStake::<T>::iter() {
-                counter.saturating_inc();
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
 
-                // Skip dApps which don't have ANY amount staked
-                if let Some(stake_amount) = stake_amount.get(era, period) {
-                    if !stake_amount.total().is_zero() {
-                        dapp_stakes.push((dapp_id, stake_amount.total()));
-                    }
+            let mut cursor = start_after.clone();
+            let mut accounts = match start_after {
+                Some(account) => ContractStakers::<T>::iter_key_prefix_from(
+                    &smart_contract,
+                    ContractStakers::<T>::hashed_key_for(&smart_contract, account),
+                ),
+                None => ContractStakers::<T>::iter_key_prefix(&smart_contract),
+            };
+
+            let mut processed = 0_u32;
+            let mut claimed = 0_u32;
+            while processed < max_accounts {
+                let Some(account) = accounts.next() else {
+                    cursor = None;
+                    break;
+                };
+                processed.saturating_inc();
+
+                let result = with_storage_layer(|| {
+                    Self::internal_claim_staker_rewards_for(
+                        account.clone(),
+                        None,
+                        Some(T::KeeperClaimWindow::get()),
+                        Permill::zero(),
+                    )
+                });
+                if result.is_ok() {
+                    claimed.saturating_inc();
                 }
+                cursor = Some(account);
             }
 
-            // 2.
-            // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
-            dapp_stakes.sort_unstable_by(|(_, amount_1), (_, amount_2)| amount_2.cmp(amount_1));
+            Self::deposit_event(Event::<T>::ContractStakersClaimed {
+                smart_contract,
+                claimed,
+                cursor,
+            });
 
-            let tier_config = TierConfig::<T>::get();
-            let tier_params = StaticTierParams::<T>::get();
+            Ok(Some(Self::claim_for_contract_stakers_weight(processed)).into())
+        }
 
-            // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
-            // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
-            //
-            // In the current implementation, the dApp with the lower dApp Id has the advantage.
-            // There is no guarantee this will persist in the future, so it's best for dApps to do their
-            // best to avoid getting themselves into such situations.
+        /// Used to force set the reward beneficiary account for a dApp, bypassing the owner check.
+        ///
+        /// Intended as a recovery tool, in case the dApp owner is unable to correct a mis-set beneficiary.
+        /// Can only be called by manager origin.
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::set_dapp_reward_beneficiary())]
+        pub fn force_set_dapp_reward_beneficiary(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            beneficiary: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
 
-            // 3.
-            // Iterate over configured tier and potential dApps.
-            // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
-            // and tier capacity hasn't been filled yet.
-            let mut dapp_tiers = BTreeMap::new();
-            let mut tier_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
-            let mut rank_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
+            Self::internal_set_dapp_reward_beneficiary(None, smart_contract, beneficiary)
+        }
 
-            let mut upper_bound = Balance::zero();
+        /// Used to set the minimum stake amount override for a dApp.
+        ///
+        /// Caller has to be dApp owner.
+        /// Staking on the dApp requires the total stake to be at least the greater of the override and the global minimum.
+        /// If set to `None`, only the global minimum applies. Existing stakes aren't affected.
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::set_dapp_min_stake())]
+        pub fn set_dapp_min_stake(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            min_stake: Option<Balance>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
-            for (tier_id, (tier_capacity, lower_bound)) in tier_config
-                .slots_per_tier
-                .iter()
-                .zip(tier_config.tier_thresholds.iter())
-                .enumerate()
-            {
-                let mut tier_slots = BTreeMap::new();
+            IntegratedDApps::<T>::try_mutate(
+                &smart_contract,
+                |maybe_dapp_info| -> DispatchResult {
+                    let dapp_info = maybe_dapp_info
+                        .as_mut()
+                        .ok_or(Error::<T>::ContractNotFound)?;
 
-                // Iterate over dApps until one of two conditions has been met:
-                // 1. Tier has no more capacity
-                // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
-                for (dapp_id, staked_amount) in dapp_stakes
-                    .iter()
-                    .skip(dapp_tiers.len())
-                    .take_while(|(_, amount)| amount.ge(lower_bound))
-                    .take(*tier_capacity as usize)
-                {
-                    let rank = if T::RankingEnabled::get() {
-                        RankedTier::find_rank(*lower_bound, upper_bound, *staked_amount)
-                    } else {
-                        0
-                    };
-                    tier_slots.insert(*dapp_id, RankedTier::new_saturated(tier_id as u8, rank));
-                }
+                    ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
 
-                // 4. Compute tier rewards using rank multiplier
-                let filled_slots = tier_slots.len() as u32;
-                // sum of all ranks for this tier
-                let ranks_sum = tier_slots
-                    .iter()
-                    .fold(0u32, |accum, (_, x)| accum.saturating_add(x.rank().into()));
+                    dapp_info.min_stake = min_stake;
 
-                let multiplier_bips = tier_params
-                    .tier_rank_multipliers
-                    .get(tier_id)
-                    .copied()
-                    .unwrap_or(10_000);
+                    Ok(())
+                },
+            )?;
 
-                let tier_allocation = tier_config
-                    .reward_portion
-                    .get(tier_id)
-                    .copied()
-                    .unwrap_or(Permill::zero())
-                    * dapp_reward_pool;
+            Self::deposit_event(Event::<T>::DAppMinStakeUpdated {
+                smart_contract,
+                min_stake,
+            });
 
-                let (tier_reward, rank_reward) = Self::compute_tier_rewards(
-                    tier_allocation,
-                    *tier_capacity,
-                    filled_slots,
-                    ranks_sum,
-                    multiplier_bips,
-                );
+            Ok(())
+        }
 
-                tier_rewards.push(tier_reward);
-                rank_rewards.push(rank_reward);
-                dapp_tiers.append(&mut tier_slots);
-                upper_bound = *lower_bound; // current threshold becomes upper bound for next tier
+        /// Enable or disable automatic claiming of the dApp rewards.
+        ///
+        /// Caller has to be dApp owner.
+        /// When enabled, the dApp reward for the previous era is claimed on-chain after each era change,
+        /// with at most `MaxAutoClaimsPerBlock` dApps processed per block.
+        /// Rewards which weren't auto-claimed before the next era change can still be claimed manually.
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::set_dapp_auto_claim())]
+        pub fn set_dapp_auto_claim(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+
+            if enabled {
+                DAppAutoClaim::<T>::insert(dapp_info.id, smart_contract.clone());
+            } else {
+                DAppAutoClaim::<T>::remove(dapp_info.id);
             }
 
-            // 5.
-            // Prepare and return tier & rewards info.
-            // In case rewards creation fails, we just write the default value. This should never happen though.
-            (
-                DAppTierRewards::<T::MaxNumberOfContracts, T::NumberOfTiers>::new(
-                    dapp_tiers,
-                    tier_rewards,
-                    period,
-                    rank_rewards,
-                )
-                .unwrap_or_default(),
-                counter,
-            )
-        }
+            Self::deposit_event(Event::<T>::DAppAutoClaimUpdated {
+                smart_contract,
+                enabled,
+            });
 
-        /// Used to handle era & period transitions.
-        pub(crate) fn era_and_period_handler(
-            now: BlockNumber,
-            tier_assignment: TierAssignment,
-        ) -> Weight {
-            let mut protocol_state = ActiveProtocolState::<T>::get();
+            Ok(())
+        }
 
-            // `ActiveProtocolState` is whitelisted, so we need to account for its read.
-            let mut consumed_weight = T::DbWeight::get().reads(1);
+        /// Set or clear the account which is allowed to manage the caller's stakes.
+        ///
+        /// The delegate can stake, unstake & move stake on behalf of the caller via the `*_delegated` calls.
+        /// Funds always remain in custody of the caller, and rewards are paid out to the caller.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::set_stake_delegate())]
+        pub fn set_stake_delegate(
+            origin: OriginFor<T>,
+            delegate: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let owner = ensure_signed(origin)?;
 
-            // We should not modify pallet storage while in maintenance mode.
-            // This is a safety measure, since maintenance mode is expected to be
-            // enabled in case some misbehavior or corrupted storage is detected.
-            if protocol_state.maintenance {
-                return consumed_weight;
+            match &delegate {
+                Some(delegate) => StakeDelegate::<T>::insert(&owner, delegate),
+                None => StakeDelegate::<T>::remove(&owner),
             }
 
-            // Inform observers about the upcoming new era, if it's the case.
-            if protocol_state.next_era_start == now.saturating_add(1) {
-                consumed_weight
-                    .saturating_accrue(Self::notify_block_before_new_era(&protocol_state));
-            }
+            Self::deposit_event(Event::<T>::StakeDelegateSet { owner, delegate });
 
-            // Nothing to do if it's not new era
-            if !protocol_state.is_new_era(now) {
-                return consumed_weight;
-            }
+            Ok(())
+        }
 
-            // At this point it's clear that an era change will happen
-            let mut era_info = CurrentEraInfo::<T>::get();
+        /// Stake the specified amount on a smart contract, on behalf of the `owner` account.
+        ///
+        /// Caller must be the stake delegate of the `owner`. Behaves the same as `stake`, using the `owner`'s locked funds.
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::stake().saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn stake_delegated(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let delegate = ensure_signed(origin)?;
+            Self::ensure_stake_delegate(&owner, &delegate)?;
 
-            let current_era = protocol_state.era;
-            let next_era = current_era.saturating_add(1);
-            let (maybe_period_event, era_reward) = match protocol_state.subperiod() {
-                // Voting subperiod only lasts for one 'prolonged' era
-                Subperiod::Voting => {
-                    // For the sake of consistency, we put zero reward into storage. There are no rewards for the voting subperiod.
-                    let era_reward = EraReward {
-                        staker_reward_pool: Balance::zero(),
-                        staked: era_info.total_staked_amount(),
-                        dapp_reward_pool: Balance::zero(),
-                    };
+            Self::internal_stake(owner, smart_contract, amount)
+        }
 
-                    let next_subperiod_start_era = next_era
-                        .saturating_add(T::CycleConfiguration::eras_per_build_and_earn_subperiod());
-                    let build_and_earn_start_block =
-                        now.saturating_add(T::CycleConfiguration::blocks_per_era());
-                    protocol_state.advance_to_next_subperiod(
-                        next_subperiod_start_era,
-                        build_and_earn_start_block,
-                    );
+        /// Unstake the specified amount from a smart contract, on behalf of the `owner` account.
+        ///
+        /// Caller must be the stake delegate of the `owner`. Behaves the same as `unstake`.
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::unstake().saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn unstake_delegated(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let delegate = ensure_signed(origin)?;
+            Self::ensure_stake_delegate(&owner, &delegate)?;
 
-                    era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+            Self::internal_unstake(owner, smart_contract, amount)
+        }
 
-                    consumed_weight
-                        .saturating_accrue(T::WeightInfo::on_initialize_voting_to_build_and_earn());
+        /// Transfers stake between two smart contracts, on behalf of the `owner` account.
+        ///
+        /// Caller must be the stake delegate of the `owner`. Behaves the same as `move_stake`.
+        #[pallet::call_index(47)]
+        #[pallet::weight(
+            T::WeightInfo::move_stake_unregistered_source()
+                .max(T::WeightInfo::move_stake_from_registered_source())
+                .saturating_add(T::DbWeight::get().reads(1))
+        )]
+        pub fn move_stake_delegated(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let delegate = ensure_signed(origin)?;
+            Self::ensure_stake_delegate(&owner, &delegate)?;
 
-                    (
-                        Some(Event::<T>::NewSubperiod {
-                            subperiod: protocol_state.subperiod(),
-                            number: protocol_state.period_number(),
-                        }),
-                        era_reward,
-                    )
-                }
-                Subperiod::BuildAndEarn => {
-                    let staked = era_info.total_staked_amount();
-                    let (staker_reward_pool, dapp_reward_pool) =
-                        T::StakingRewardHandler::staker_and_dapp_reward_pools(staked);
-                    let era_reward = EraReward {
-                        staker_reward_pool,
-                        staked,
-                        dapp_reward_pool,
-                    };
+            Self::internal_move_stake(owner, source_contract, destination_contract, amount)
+        }
 
-                    // Distribute dapps into tiers, write it into storage
-                    //
-                    // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
-                    // This must never be used in production code, obviously.
-                    let (dapp_tier_rewards, counter) = match tier_assignment {
-                        TierAssignment::Real => Self::get_dapp_tier_assignment_and_rewards(
-                            current_era,
-                            protocol_state.period_number(),
-                            dapp_reward_pool,
-                        ),
-                        #[cfg(feature = "runtime-benchmarks")]
-                        TierAssignment::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
-                    };
-                    DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
+        /// Unstake the entire staked amount from a smart contract.
+        ///
+        /// Behaves the same as `unstake` called with the full amount staked on the contract.
+        #[pallet::call_index(48)]
+        #[pallet::weight(T::WeightInfo::unstake())]
+        pub fn unstake_all(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-                    consumed_weight
-                        .saturating_accrue(T::WeightInfo::dapp_tier_assignment(counter.into()));
+            let amount = StakerInfo::<T>::get(&account, &smart_contract)
+                .ok_or(Error::<T>::NoStakingInfo)?
+                .total_staked_amount();
 
-                    // Switch to `Voting` period if conditions are met.
-                    if protocol_state.period_info.is_next_period(next_era) {
-                        // Store info about period end
-                        let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
-                        PeriodEnd::<T>::insert(
-                            &protocol_state.period_number(),
-                            PeriodEndInfo {
-                                bonus_reward_pool,
-                                total_vp_stake: era_info.staked_amount(Subperiod::Voting),
-                                final_era: current_era,
-                            },
-                        );
+            Self::internal_unstake(account, smart_contract, amount)
+        }
 
-                        // For the sake of consistency we treat the whole `Voting` period as a single era.
-                        // This means no special handling is required for this period, it only lasts potentially longer than a single standard era.
-                        let next_subperiod_start_era = next_era.saturating_add(1);
-                        let voting_period_length = Self::blocks_per_voting_period();
-                        let next_era_start_block = now.saturating_add(voting_period_length);
+        /// Opt the dApp in or out of dApp rewards.
+        ///
+        /// Caller has to be dApp owner.
+        /// An opted-out dApp isn't rewarded for its tier, leaving the reward to others, as described by
+        /// [`Config::OptedOutRewardHandling`]. Stakers are still rewarded for staking on it.
+        /// Takes effect from the next tier assignment.
+        #[pallet::call_index(49)]
+        #[pallet::weight(T::WeightInfo::set_dapp_reward_opt_out())]
+        pub fn set_dapp_reward_opt_out(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            opted_out: bool,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
-                        protocol_state.advance_to_next_subperiod(
-                            next_subperiod_start_era,
-                            next_era_start_block,
-                        );
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
 
-                        era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+            if opted_out {
+                DAppRewardOptOut::<T>::insert(dapp_info.id, true);
+            } else {
+                DAppRewardOptOut::<T>::remove(dapp_info.id);
+            }
 
-                        // Update historical cleanup marker.
-                        // Must be called with the new period number.
-                        Self::update_cleanup_marker(protocol_state.period_number());
+            Self::deposit_event(Event::<T>::DAppRewardOptOutUpdated {
+                smart_contract,
+                opted_out,
+            });
 
-                        consumed_weight.saturating_accrue(
-                            T::WeightInfo::on_initialize_build_and_earn_to_voting(),
-                        );
+            Ok(())
+        }
 
-                        (
-                            Some(Event::<T>::NewSubperiod {
-                                subperiod: protocol_state.subperiod(),
-                                number: protocol_state.period_number(),
-                            }),
-                            era_reward,
-                        )
-                    } else {
-                        let next_era_start_block =
-                            now.saturating_add(T::CycleConfiguration::blocks_per_era());
-                        protocol_state.next_era_start = next_era_start_block;
+        /// Schedule a cycle configuration change, to be applied at the start of the next period.
+        ///
+        /// Era & subperiod lengths are only changed on the period boundary, to avoid disrupting the ongoing period.
+        /// The cycle length, both in eras & blocks, and the number of build&earn eras in it must remain the same,
+        /// since inflation is calculated based on the runtime provided `CycleConfiguration`.
+        /// Any previously scheduled change is overwritten. Can only be called by manager origin.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::schedule_cycle_config_change())]
+        pub fn schedule_cycle_config_change(
+            origin: OriginFor<T>,
+            new_config: CycleParameters,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
 
-                        era_info.migrate_to_next_era(None);
+            ensure!(new_config.is_valid(), Error::<T>::InvalidCycleConfiguration);
+            ensure!(
+                new_config.has_same_cycle_as::<T::CycleConfiguration>(),
+                Error::<T>::CycleLengthMismatch
+            );
 
-                        consumed_weight.saturating_accrue(
-                            T::WeightInfo::on_initialize_build_and_earn_to_build_and_earn(),
-                        );
+            PendingCycleConfig::<T>::put(new_config);
 
-                        (None, era_reward)
+            Self::deposit_event(Event::<T>::CycleConfigChangeScheduled { config: new_config });
+
+            Ok(())
+        }
+
+        /// Sets or clears the maximum number of accounts which can stake at the same time.
+        ///
+        /// While set, it's used instead of `MaxTotalStakers`, allowing the cap to be adjusted during a controlled rollout.
+        /// Accounts which are already staking aren't affected if the cap is lowered below the current number of stakers.
+        #[pallet::call_index(50)]
+        #[pallet::weight(T::WeightInfo::set_reward_split())]
+        pub fn set_max_total_stakers(
+            origin: OriginFor<T>,
+            max_stakers: Option<u32>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            MaxTotalStakersOverride::<T>::set(max_stakers);
+
+            Self::deposit_event(Event::<T>::MaxTotalStakersOverrideSet { max_stakers });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Sets the reward beneficiary account for a dApp.
+        ///
+        /// If `owner` is provided, it must match the dApp owner.
+        fn internal_set_dapp_reward_beneficiary(
+            owner: Option<T::AccountId>,
+            smart_contract: T::SmartContract,
+            beneficiary: Option<T::AccountId>,
+        ) -> DispatchResult {
+            IntegratedDApps::<T>::try_mutate(
+                &smart_contract,
+                |maybe_dapp_info| -> DispatchResult {
+                    let dapp_info = maybe_dapp_info
+                        .as_mut()
+                        .ok_or(Error::<T>::ContractNotFound)?;
+
+                    if let Some(owner) = owner {
+                        ensure!(dapp_info.owner == owner, Error::<T>::OriginNotOwner);
                     }
+
+                    dapp_info.reward_beneficiary = beneficiary.clone();
+
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::<T>::DAppRewardDestinationUpdated {
+                smart_contract,
+                beneficiary,
+            });
+
+            Ok(())
+        }
+
+        /// Weight of `claim_for_contract_stakers` for the specified number of processed accounts.
+        ///
+        /// Each account is charged as a full staker reward claim, plus reading its contract stakers index key.
+        fn claim_for_contract_stakers_weight(accounts: u32) -> Weight {
+            let max_span_length =
+                T::EraRewardSpanLength::get().min(T::MaxEraRewardsPerClaim::get());
+            let claim_weight = T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_past_period(
+                    max_span_length,
+                ))
+                .saturating_add(T::DbWeight::get().reads(1));
+
+            T::DbWeight::get()
+                .reads(1)
+                .saturating_add(claim_weight.saturating_mul(accounts.into()))
+        }
+
+        /// Updates the total number of stakers, based on the account's number of staked contracts before & after a change.
+        fn note_staked_contracts_change(before: u32, after: u32) {
+            match (before.is_zero(), after.is_zero()) {
+                (true, false) => TotalStakers::<T>::mutate(|stakers| stakers.saturating_inc()),
+                (false, true) => TotalStakers::<T>::mutate(|stakers| stakers.saturating_dec()),
+                _ => (),
+            }
+        }
+
+        /// Rounds the amount down to a multiple of `StakeIncrement`, if it's set.
+        fn round_to_stake_increment(amount: Balance) -> Balance {
+            match T::StakeIncrement::get() {
+                Some(increment) if !increment.is_zero() => {
+                    amount.saturating_sub(amount % increment)
                 }
-            };
+                _ => amount,
+            }
+        }
+
+        /// Loyalty boost applicable to the staker reward for the specified era,
+        /// based on the number of eras the account has continuously had locked funds.
+        pub(crate) fn loyalty_boost(locked_since: Option<EraNumber>, era: EraNumber) -> Permill {
+            match (T::LoyaltyBoost::get(), locked_since) {
+                (Some((eras_per_step, boost_per_step)), Some(locked_since))
+                    if !eras_per_step.is_zero() =>
+                {
+                    let steps = era.saturating_sub(locked_since) / eras_per_step;
+                    Permill::from_parts(boost_per_step.deconstruct().saturating_mul(steps))
+                }
+                _ => Permill::zero(),
+            }
+        }
+
+        /// Prepares the `StakeAmount` & bonus status for a new stake of `amount` in the current subperiod.
+        fn new_stake_amount(amount: Balance) -> (StakeAmount, BonusStatus) {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            match protocol_state.subperiod() {
+                Subperiod::Voting => (
+                    StakeAmount {
+                        voting: amount,
+                        build_and_earn: 0,
+                        era: protocol_state.era,
+                        period: protocol_state.period_number(),
+                    },
+                    *BonusStatusWrapperFor::<T>::default(),
+                ),
+                Subperiod::BuildAndEarn => (
+                    StakeAmount {
+                        voting: 0,
+                        build_and_earn: amount,
+                        era: protocol_state.era,
+                        period: protocol_state.period_number(),
+                    },
+                    0,
+                ),
+            }
+        }
+
+        /// Locks & stakes the claimed dApp reward on the same contract, on behalf of the beneficiary.
+        ///
+        /// Either both lock & stake succeed, or no changes are made and the reward remains liquid,
+        /// e.g. if the beneficiary has unclaimed staker rewards, or the minimum lock amount isn't reached.
+        fn auto_compound_dapp_reward(
+            beneficiary: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) {
+            let result = with_storage_layer(|| -> DispatchResult {
+                Self::internal_lock(beneficiary.clone(), amount, None).map_err(|e| e.error)?;
+                let (stake_amount, bonus_status) = Self::new_stake_amount(amount);
+                Self::inner_stake(&beneficiary, &smart_contract, stake_amount, bonus_status)
+            });
+
+            match result {
+                Ok(()) => Self::deposit_event(Event::<T>::DAppRewardCompounded {
+                    beneficiary,
+                    smart_contract,
+                    amount,
+                }),
+                Err(error) => Self::deposit_event(Event::<T>::DAppRewardCompoundFailed {
+                    beneficiary,
+                    smart_contract,
+                    error,
+                }),
+            }
+        }
+
+        /// Removes the dApp from dApp staking, making it ineligible for future rewards.
+        fn remove_dapp(smart_contract: T::SmartContract, dapp_id: DAppId, era: EraNumber) {
+            ContractStake::<T>::remove(&dapp_id);
+            Self::update_dapp_stakes_snapshot(dapp_id, &ContractStakeAmount::default());
+            LastStakedPeriod::<T>::remove(&dapp_id);
+            DAppAutoCompound::<T>::remove(&dapp_id);
+            DAppAutoClaim::<T>::remove(&dapp_id);
+            DAppTierCap::<T>::remove(&dapp_id);
+            DAppRewardOptOut::<T>::remove(&dapp_id);
+            DAppLifetimeRewards::<T>::remove(&dapp_id);
+            IntegratedDApps::<T>::remove(&smart_contract);
+
+            Self::deposit_event(Event::<T>::DAppUnregistered {
+                smart_contract,
+                era,
+            });
+        }
+
+        /// Inner `unstake` functionality for an **active** smart contract.
+        /// If successful returns the `StakeAmount` that was unstaked, and the updated bonus status.
+        ///
+        /// - Ensures the contract is still registered.
+        /// - Updates staker info, ledger, and contract stake info.
+        /// - Returns the unstaked amount and updated bonus status.
+        pub fn inner_unstake(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            amount: Balance,
+        ) -> Result<(StakeAmount, BonusStatus), DispatchError> {
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // 1.
+            // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
+            let (new_staking_info, amount, stake_amount_iter, updated_bonus_status) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(mut staking_info) => {
+                        ensure!(
+                            staking_info.period_number() == protocol_state.period_number(),
+                            Error::<T>::UnstakeFromPastPeriod
+                        );
+                        ensure!(
+                            staking_info.total_staked_amount() >= amount,
+                            Error::<T>::UnstakeAmountTooLarge
+                        );
+
+                        // If unstaking would take the total staked amount below the minimum required value,
+                        // unstake everything.
+                        let amount = if staking_info.total_staked_amount().saturating_sub(amount)
+                            < T::MinimumStakeAmount::get()
+                        {
+                            staking_info.total_staked_amount()
+                        } else {
+                            amount
+                        };
+
+                        let (stake_amount_iter, updated_bonus_status) =
+                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+
+                        (
+                            staking_info,
+                            amount,
+                            stake_amount_iter,
+                            updated_bonus_status,
+                        )
+                    }
+                    None => {
+                        return Err(Error::<T>::NoStakingInfo.into());
+                    }
+                };
+
+            // 2.
+            // Reduce stake amount
+            ledger
+                .unstake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    // This is a defensive check, which should never happen since we calculate the correct value above.
+                    AccountLedgerError::UnstakeAmountLargerThanStake => {
+                        Error::<T>::UnstakeAmountTooLarge
+                    }
+                    _ => Error::<T>::InternalUnstakeError,
+                })?;
+
+            // 3.
+            // Update `ContractStake` storage with the reduced stake amount on the specified contract.
+            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
+            let stake_before =
+                contract_stake_info.total_staked_amount(protocol_state.period_number());
+            contract_stake_info.unstake(
+                &stake_amount_iter,
+                protocol_state.period_info,
+                current_era,
+            );
+            Self::notify_tier_threshold_crossing(
+                smart_contract,
+                stake_before,
+                contract_stake_info.total_staked_amount(protocol_state.period_number()),
+            );
+
+            // 4.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unstake_amount(stake_amount_iter.clone());
+            });
+
+            // 5.
+            // Update remaining storage entries
+            Self::update_dapp_stakes_snapshot(dapp_info.id, &contract_stake_info);
+            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+
+            if new_staking_info.is_empty() {
+                Self::note_staked_contracts_change(
+                    ledger.contract_stake_count,
+                    ledger.contract_stake_count.saturating_sub(1),
+                );
+                ledger.contract_stake_count.saturating_dec();
+                StakerInfo::<T>::remove(&account, &smart_contract);
+                ContractStakers::<T>::remove(&smart_contract, &account);
+            } else {
+                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+            }
+
+            Self::update_ledger(&account, ledger)?;
+
+            // Return the `StakeAmount` that has max total value.
+            let mut unstake_amount = stake_amount_iter
+                .iter()
+                .max_by(|a, b| a.total().cmp(&b.total()))
+                // At least one value exists, otherwise we wouldn't be here.
+                .ok_or(Error::<T>::InternalUnstakeError)?
+                .clone();
+
+            // Ensure we use the current era instead of potentially next era
+            unstake_amount.era = current_era;
+
+            Ok((unstake_amount, updated_bonus_status))
+        }
+
+        /// Handles unstaking from an **unregistered** smart contract.
+        ///
+        /// - Ensures the contract is no longer active.
+        /// - Updates staker info and ledger.
+        /// - Returns the unstaked amount and preserves the original bonus status.
+        pub fn inner_unstake_from_unregistered(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> Result<(StakeAmount, BonusStatus), DispatchError> {
+            ensure!(
+                !IntegratedDApps::<T>::contains_key(&smart_contract),
+                Error::<T>::ContractStillActive
+            );
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+
+            // Extract total staked amount on the specified unregistered contract
+            let (amount, unstake_amount_iter, preserved_bonus_status) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(mut staking_info) => {
+                        ensure!(
+                            staking_info.period_number() == protocol_state.period_number(),
+                            Error::<T>::UnstakeFromPastPeriod
+                        );
+
+                        let preserved_bonus_status = staking_info.bonus_status;
+                        let amount = staking_info.staked.total();
+
+                        let (unstake_amount_iter, _) =
+                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+
+                        (amount, unstake_amount_iter, preserved_bonus_status)
+                    }
+                    None => {
+                        return Err(Error::<T>::NoStakingInfo.into());
+                    }
+                };
+
+            // Reduce stake amount in ledger
+            let mut ledger = Ledger::<T>::get(&account);
+            ledger
+                .unstake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    // These are all defensive checks, which should never fail since we already checked them above.
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    _ => Error::<T>::InternalUnstakeError,
+                })?;
+            Self::note_staked_contracts_change(
+                ledger.contract_stake_count,
+                ledger.contract_stake_count.saturating_sub(1),
+            );
+            ledger.contract_stake_count.saturating_dec();
+
+            // Update total staked amount for the next era.
+            // This means 'fake' stake total amount has been kept until now, even though contract was unregistered.
+            // Although strange, it's been requested to keep it like this from the team.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unstake_amount(unstake_amount_iter.clone());
+            });
+
+            // Update remaining storage entries
+            Self::update_ledger(&account, ledger)?;
+            StakerInfo::<T>::remove(&account, &smart_contract);
+            ContractStakers::<T>::remove(&smart_contract, &account);
+
+            // Return the `StakeAmount` that has max total value.
+            let mut unstake_amount = unstake_amount_iter
+                .iter()
+                .max_by(|a, b| a.total().cmp(&b.total()))
+                // At least one value exists, otherwise we wouldn't be here.
+                .ok_or(Error::<T>::InternalUnstakeError)?
+                .clone();
+
+            // Ensure we use the current era instead of potentially next era
+            unstake_amount.era = current_era;
+
+            Ok((unstake_amount, preserved_bonus_status))
+        }
+
+        /// Inner `stake` functionality.
+        ///
+        /// Specifies the amount in the form of the `StakeAmount` struct, allowing simultaneous update of both `voting` and `build_and_earn` amounts.
+        /// The `bonus_status` is used to determine if the staker is still eligible for the bonus reward. This is useful for the `move` extrinsic.
+        pub fn inner_stake(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            amount: StakeAmount,
+            bonus_status: BonusStatus,
+        ) -> Result<(), DispatchError> {
+            ensure!(amount.total() > 0, Error::<T>::ZeroAmount);
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            let period_number = protocol_state.period_info.number;
+            let is_final_era = protocol_state
+                .period_info
+                .is_next_period(current_era.saturating_add(1));
+            ensure!(
+                !is_final_era || !T::FinalEraStakeGrace::get().is_zero(),
+                Error::<T>::PeriodEndsInNextEra
+            );
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // In case old stake rewards are unclaimed & have expired, clean them up.
+            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
+            let _ignore = ledger.maybe_cleanup_expired(threshold_period);
+
+            // 1.
+            // Increase stake amount for the next era & current period in staker's ledger
+            ledger
+                .add_stake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    AccountLedgerError::UnavailableStakeFunds => Error::<T>::UnavailableStakeFunds,
+                    // Defensive check, should never happen
+                    _ => Error::<T>::InternalStakeError,
+                })?;
+
+            // Stake made in the final era only becomes effective in the next period.
+            // The grace applies to the total amount staked by the account during the final era,
+            // so it cannot be bypassed by splitting the stake into multiple calls.
+            if is_final_era {
+                let final_era_stake = ledger
+                    .staked_amount(period_number)
+                    .saturating_sub(ledger.staked_amount_for_era(current_era, period_number));
+                ensure!(
+                    final_era_stake <= T::FinalEraStakeGrace::get(),
+                    Error::<T>::PeriodEndsInNextEra
+                );
+            }
+
+            // 2.
+            // Update `StakerInfo` storage with the new stake amount on the specified contract.
+            //
+            // There are three distinct scenarios:
+            // 1. Existing entry matches the current period number - just update it.
+            // 2. Existing entry is expired/orphaned - replace it.
+            // 3. Entry doesn't exist or it's for an older period - create a new one.
+            //
+            // This is ok since we only use this storage entry to keep track of how much each staker
+            // has staked on each contract in the current period. We only ever need the latest information.
+            // This is because `AccountLedger` is the one keeping information about how much was staked when.
+            let (mut new_staking_info, is_new_entry, replacing_old_entry) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    // Entry with matching period exists
+                    Some(staking_info)
+                        if staking_info.period_number() == protocol_state.period_number() =>
+                    {
+                        (staking_info, false, false)
+                    }
+                    // Entry exists but period doesn't match. Bonus reward might still be claimable.
+                    Some(staking_info)
+                        if staking_info.period_number() >= threshold_period
+                            && staking_info.is_bonus_eligible() =>
+                    {
+                        return Err(Error::<T>::UnclaimedRewards.into());
+                    }
+                    // Entry exists but is expired/orphaned - we're replacing it, not adding new
+                    Some(_old_entry) => {
+                        // Remove the old orphaned entry explicitly
+                        StakerInfo::<T>::remove(&account, &smart_contract);
+                        (
+                            SingularStakingInfo::new(protocol_state.period_number(), bonus_status),
+                            true, // is_new_entry (for storage write)
+                            true, // replacing_old_entry (don't increment counter)
+                        )
+                    }
+                    // No entry exists at all - truly new
+                    None => (
+                        SingularStakingInfo::new(
+                            protocol_state.period_number(),
+                            // Important to account for the remaining bonus moves since it's possible to retain the bonus
+                            // after moving the stake to another contract.
+                            bonus_status,
+                        ),
+                        true,
+                        false,
+                    ),
+                };
+
+            new_staking_info.stake(amount, current_era, bonus_status);
+            ensure!(
+                new_staking_info.total_staked_amount()
+                    >= dapp_info.min_stake_amount(T::MinimumStakeAmount::get()),
+                Error::<T>::InsufficientStakeAmount
+            );
+
+            // Only increment if we're truly adding a new contract, not replacing an orphaned entry
+            if is_new_entry && !replacing_old_entry {
+                if ledger.contract_stake_count.is_zero() {
+                    if let Some(max_stakers) = Self::max_total_stakers() {
+                        ensure!(
+                            TotalStakers::<T>::get() < max_stakers,
+                            Error::<T>::StakerCapReached
+                        );
+                    }
+                }
+                Self::note_staked_contracts_change(
+                    ledger.contract_stake_count,
+                    ledger.contract_stake_count.saturating_add(1),
+                );
+                ledger.contract_stake_count.saturating_inc();
+                ensure!(
+                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
+                    Error::<T>::TooManyStakedContracts
+                );
+            }
+
+            // 3.
+            // Update `ContractStake` storage with the new stake amount on the specified contract.
+            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
+            let stake_before = contract_stake_info.total_staked_amount(period_number);
+            contract_stake_info.stake(amount, current_era, period_number);
+            if let Some(max_stake) = T::MaxStakePerContract::get() {
+                ensure!(
+                    contract_stake_info.total_staked_amount(period_number) <= max_stake,
+                    Error::<T>::ContractStakeCapExceeded
+                );
+            }
+            Self::notify_tier_threshold_crossing(
+                smart_contract,
+                stake_before,
+                contract_stake_info.total_staked_amount(period_number),
+            );
+
+            // 4.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_stake_amount(amount);
+            });
+
+            // 5.
+            // Update remaining storage entries
+            Self::update_ledger(&account, ledger)?;
+            StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+            if is_new_entry {
+                ContractStakers::<T>::insert(&smart_contract, &account, ());
+            }
+            Self::update_dapp_stakes_snapshot(dapp_info.id, &contract_stake_info);
+            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+            LastStakedPeriod::<T>::insert(&dapp_info.id, period_number);
+
+            Ok(())
+        }
+
+        /// `true` if the account is a staker, `false` otherwise.
+        pub fn is_staker(account: &T::AccountId) -> bool {
+            Ledger::<T>::contains_key(account)
+        }
+
+        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
+        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
+            if ActiveProtocolState::<T>::get().maintenance {
+                Err(Error::<T>::Disabled)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Update the account ledger, and dApp staking balance freeze.
+        ///
+        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        ///
+        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
+        /// runtime definition must ensure it supports necessary freezes.
+        pub(crate) fn update_ledger(
+            account: &T::AccountId,
+            mut ledger: AccountLedgerFor<T>,
+        ) -> Result<(), DispatchError> {
+            ledger.refresh_locked_since(ActiveProtocolState::<T>::get().era);
+
+            if ledger.is_empty() {
+                Ledger::<T>::remove(&account);
+                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
+            } else {
+                T::Currency::set_freeze(
+                    &FreezeReason::DAppStaking.into(),
+                    account,
+                    ledger.total_locked_amount(),
+                )?;
+                Ledger::<T>::insert(account, ledger);
+            }
+
+            Ok(())
+        }
+
+        /// Returns the staker & dApp reward pools for an era, as provided by the reward handler.
+        ///
+        /// If set, `RewardPoolSplitOverride` redistributes the combined amount between the two pools.
+        pub(crate) fn staker_and_dapp_reward_pools(
+            total_value_staked: Balance,
+        ) -> (Balance, Balance) {
+            let (staker_reward_pool, dapp_reward_pool) =
+                T::StakingRewardHandler::staker_and_dapp_reward_pools(total_value_staked);
+
+            match RewardPoolSplitOverride::<T>::get() {
+                Some(split) => {
+                    let total_reward_pool = staker_reward_pool.saturating_add(dapp_reward_pool);
+                    let staker_reward_pool = split * total_reward_pool;
+                    (
+                        staker_reward_pool,
+                        total_reward_pool.saturating_sub(staker_reward_pool),
+                    )
+                }
+                None => (staker_reward_pool, dapp_reward_pool),
+            }
+        }
+
+        /// Adds the accumulated reward pool top-up to the staker & dApp reward pools, proportionally to their size.
+        ///
+        /// The top-up is taken from the reward handler, so this must only be called once per era.
+        fn with_reward_pool_top_up(
+            (staker_reward_pool, dapp_reward_pool): (Balance, Balance),
+        ) -> (Balance, Balance) {
+            let top_up = T::StakingRewardHandler::take_reward_pool_top_up();
+            if top_up.is_zero() {
+                return (staker_reward_pool, dapp_reward_pool);
+            }
+
+            let total_reward_pool = staker_reward_pool.saturating_add(dapp_reward_pool);
+            let staker_top_up = if total_reward_pool.is_zero() {
+                top_up
+            } else {
+                Perbill::from_rational(staker_reward_pool, total_reward_pool) * top_up
+            };
+
+            (
+                staker_reward_pool.saturating_add(staker_top_up),
+                dapp_reward_pool.saturating_add(top_up.saturating_sub(staker_top_up)),
+            )
+        }
+
+        /// Returns the number of blocks per voting period.
+        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
+            ActiveCycleConfiguration::<T>::blocks_per_era()
+                .saturating_mul(ActiveCycleConfiguration::<T>::eras_per_voting_subperiod().into())
+        }
+
+        /// Calculates the `EraRewardSpan` index for the specified era.
+        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
+            era.saturating_sub(era % T::EraRewardSpanLength::get())
+        }
+
+        /// Maximum number of accounts which can stake at the same time, `None` if there's no limit.
+        pub(crate) fn max_total_stakers() -> Option<u32> {
+            MaxTotalStakersOverride::<T>::get().or_else(T::MaxTotalStakers::get)
+        }
+
+        /// Return the oldest period for which rewards can be claimed.
+        /// All rewards before that period are considered to be expired.
+        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
+            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
+        }
+
+        /// Standard unlocking period expressed in the number of blocks.
+        ///
+        /// The actual unlocking period of a chunk may be adjusted by `T::UnlockingPeriodFn`, based on the unlocked amount.
+        pub fn unlocking_period() -> BlockNumber {
+            ActiveCycleConfiguration::<T>::blocks_per_era()
+                .saturating_mul(T::UnlockingPeriod::get().into())
+        }
+
+        /// Staking limits & constants relevant for lock, stake & unlock operations.
+        pub fn staking_constants() -> StakingConstants {
+            StakingConstants {
+                minimum_locked_amount: T::MinimumLockedAmount::get(),
+                minimum_stake_amount: T::MinimumStakeAmount::get(),
+                max_number_of_staked_contracts: T::MaxNumberOfStakedContracts::get(),
+                max_unlocking_chunks: T::MaxUnlockingChunks::get(),
+                unlocking_period: Self::unlocking_period(),
+            }
+        }
+
+        /// Tier configuration currently in effect.
+        ///
+        /// Read directly from the `TierConfig` storage, which is recalculated at the start of each period,
+        /// so the values always match the ones used for the dApp tier assignment.
+        pub fn tier_config() -> TierConfigView {
+            let tier_config = TierConfig::<T>::get();
+            TierConfigView {
+                slots_per_tier: tier_config.slots_per_tier().to_vec(),
+                tier_thresholds: tier_config.tier_thresholds().to_vec(),
+                reward_portion: tier_config.reward_portion().to_vec(),
+                total_number_of_slots: tier_config.total_number_of_slots(),
+            }
+        }
+
+        /// Number of slots still available in each tier, based on the dApp tier assignment for the current era.
+        ///
+        /// Uses the same assignment logic as the one executed at the end of an era, so a tier whose slots are all
+        /// taken by higher staked dApps is reported as full.
+        /// During the voting subperiod no tier assignment is done, so all values are zero.
+        pub fn available_tier_slots() -> BoundedVec<u16, T::NumberOfTiers> {
+            let mut available_slots = TierConfig::<T>::get().slots_per_tier;
+
+            if ActiveProtocolState::<T>::get().subperiod() == Subperiod::Voting {
+                available_slots.iter_mut().for_each(|slots| *slots = 0);
+                return available_slots;
+            }
+
+            for (_, ranked_tier) in Self::get_dapp_tier_assignment() {
+                if let Some(slots) = available_slots.get_mut(ranked_tier.tier() as usize) {
+                    *slots = slots.saturating_sub(1);
+                }
+            }
+
+            available_slots
+        }
+
+        /// Minimum stake a dApp currently needs in order to be assigned to the specified tier.
+        ///
+        /// If the tier still has open slots, this is the tier threshold. Otherwise, the dApp needs to outstake
+        /// the lowest staked dApp in the tier, based on the dApp tier assignment for the current era.
+        /// During the voting subperiod no tier assignment is done, so the tier threshold is returned.
+        ///
+        /// Returns `None` if the tier doesn't exist, or has no slots.
+        pub fn stake_needed_for_tier(tier_id: TierId) -> Option<Balance> {
+            let tier_config = TierConfig::<T>::get();
+            let threshold = *tier_config.tier_thresholds().get(tier_id as usize)?;
+            let slots = *tier_config.slots_per_tier().get(tier_id as usize)?;
+            if slots.is_zero() {
+                return None;
+            }
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            if protocol_state.subperiod() == Subperiod::Voting {
+                return Some(threshold);
+            }
+
+            let tier_stakes: Vec<Balance> = Self::get_dapp_tier_assignment()
+                .into_iter()
+                .filter(|(_, ranked_tier)| ranked_tier.tier() == tier_id)
+                .map(|(dapp_id, _)| {
+                    Self::era_stake_total(
+                        &ContractStake::<T>::get(dapp_id),
+                        protocol_state.era,
+                        protocol_state.period_number(),
+                    )
+                })
+                .collect();
+
+            if tier_stakes.len() < slots as usize {
+                return Some(threshold);
+            }
+
+            // Tier is full, the lowest staked dApp in it must be outstaked.
+            let marginal_stake = tier_stakes
+                .into_iter()
+                .min()
+                .unwrap_or_default()
+                .saturating_add(1);
+            Some(threshold.max(marginal_stake))
+        }
+
+        /// Returns the stake of the specified smart contract for the ongoing era, together with its projected tier.
+        ///
+        /// The tier is taken from the dApp tier assignment for the current era, so tier slot capacity
+        /// and the ranking of the other dApps is taken into account.
+        ///
+        /// Returns `None` if the contract isn't registered, or it doesn't make it into any tier.
+        pub fn effective_staking_power(
+            smart_contract: &T::SmartContract,
+        ) -> Option<(Balance, TierId)> {
+            let dapp_info = IntegratedDApps::<T>::get(smart_contract)?;
+            let tier_id = Self::get_dapp_tier_assignment().get(&dapp_info.id)?.tier();
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let stake = Self::era_stake_total(
+                &ContractStake::<T>::get(dapp_info.id),
+                protocol_state.era,
+                protocol_state.period_number(),
+            );
+
+            Some((stake, tier_id))
+        }
+
+        /// Returns the age of the account's stake on the specified smart contract, as `(since, eras_staked)`.
+        ///
+        /// `since` is the era from which the latest stake update is effective, so staking more or unstaking resets it.
+        /// `eras_staked` is the number of eras since then, up to the ongoing era. It's zero if the stake
+        /// only becomes effective in the next era.
+        ///
+        /// Returns `None` if there is no staking info for the account & contract pair.
+        pub fn stake_age(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> Option<(EraNumber, u32)> {
+            let staking_info = StakerInfo::<T>::get(account, smart_contract)?;
+            let since = staking_info.era();
+            let current_era = ActiveProtocolState::<T>::get().era;
+
+            Some((since, current_era.saturating_sub(since)))
+        }
+
+        /// Reconciles the funds held by the pallet, as `(ledgers_total, era_info_total, matches)`.
+        ///
+        /// - `ledgers_total` is the sum of active locked & unlocking amounts of all accounts in `Ledger`.
+        /// - `era_info_total` is the sum of total locked & unlocking amounts in `CurrentEraInfo`.
+        ///
+        /// **NOTE:** iterates over all `Ledger` entries, so it's very expensive and must never be called on-chain.
+        pub fn reconcile_held_funds() -> (Balance, Balance, bool) {
+            let ledgers_total = Ledger::<T>::iter_values()
+                .fold(Balance::zero(), |total, ledger| {
+                    total.saturating_add(ledger.total_locked_amount())
+                });
+
+            let era_info = CurrentEraInfo::<T>::get();
+            let era_info_total = era_info.total_locked.saturating_add(era_info.unlocking);
+
+            (
+                ledgers_total,
+                era_info_total,
+                ledgers_total == era_info_total,
+            )
+        }
+
+        /// Number of eras remaining until the next subperiod starts, including the ongoing era.
+        pub fn eras_until_subperiod_change() -> EraNumber {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            protocol_state
+                .next_subperiod_start_era()
+                .saturating_sub(protocol_state.era)
+        }
+
+        /// Returns the range of eras covered by the stored `EraRewards` spans, as `(oldest_era, newest_era)`.
+        ///
+        /// Only the oldest & newest spans are read. Returns `(0, 0)` if no era rewards are stored.
+        pub fn retained_era_window() -> (EraNumber, EraNumber) {
+            let last_finished_era = ActiveProtocolState::<T>::get().era.saturating_sub(1);
+            let newest_span =
+                match EraRewards::<T>::get(Self::era_reward_span_index(last_finished_era)) {
+                    Some(span) => span,
+                    None => return (0, 0),
+                };
+
+            // Oldest span is the next one to be cleaned up.
+            let oldest_era =
+                EraRewards::<T>::get(HistoryCleanupMarker::<T>::get().era_reward_index)
+                    .map_or(newest_span.first_era(), |span| span.first_era());
+
+            (oldest_era, newest_span.last_era())
+        }
+
+        /// dApp tier assignment & rewards for the specified era.
+        ///
+        /// Only available for finished build&earn eras whose entry hasn't been cleaned up yet,
+        /// `None` otherwise (e.g. for voting subperiod eras).
+        pub fn dapp_tier_rewards(era: EraNumber) -> Option<DAppTierRewardsView> {
+            DAppTiers::<T>::get(era).map(|tier_rewards| DAppTierRewardsView {
+                dapps: tier_rewards.dapps.into_inner(),
+                rewards: tier_rewards.rewards.into_inner(),
+                period: tier_rewards.period,
+                rank_rewards: tier_rewards.rank_rewards.into_inner(),
+                remainders: tier_rewards.remainders.into_inner(),
+            })
+        }
+
+        /// Eras for which the specified smart contract has a claimable dApp reward, in ascending order.
+        ///
+        /// Follows the `claim_dapp_reward` eligibility rules: the era must have ended, its reward must not have expired,
+        /// and the dApp must still have an unclaimed tier assignment in it. Empty if the contract isn't registered.
+        pub fn claimable_dapp_rewards(smart_contract: &T::SmartContract) -> Vec<EraNumber> {
+            let dapp_id = match IntegratedDApps::<T>::get(smart_contract) {
+                Some(dapp_info) => dapp_info.id,
+                None => return Vec::new(),
+            };
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let oldest_claimable_period =
+                Self::oldest_claimable_period(protocol_state.period_number());
+
+            // Claimed rewards are removed from the tier assignment, and expired entries are eventually cleaned up,
+            // so the number of stored entries is bounded by the reward retention.
+            let mut eras: Vec<EraNumber> = DAppTiers::<T>::iter()
+                .filter(|(era, dapp_tiers)| {
+                    *era < protocol_state.era
+                        && dapp_tiers.period >= oldest_claimable_period
+                        && dapp_tiers.dapps.contains_key(&dapp_id)
+                })
+                .map(|(era, _)| era)
+                .collect();
+            eras.sort();
+            eras
+        }
+
+        /// Reward pools for the ongoing era, as `(staker_reward_pool, bonus_reward_pool, dapp_reward_pool)`.
+        ///
+        /// Staker & dApp reward pools are based on the current era stake, and are the same as used when the era ends,
+        /// except for any reward pool top-up accumulated until then. Both are zero during the voting subperiod, since it has no rewards.
+        /// Bonus reward pool is for the ongoing period.
+        pub fn current_reward_pools() -> (Balance, Balance, Balance) {
+            let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
+
+            let (staker_reward_pool, dapp_reward_pool) =
+                match ActiveProtocolState::<T>::get().subperiod() {
+                    Subperiod::Voting => (Balance::zero(), Balance::zero()),
+                    Subperiod::BuildAndEarn => Self::staker_and_dapp_reward_pools(
+                        CurrentEraInfo::<T>::get().total_staked_amount(),
+                    ),
+                };
+
+            (staker_reward_pool, bonus_reward_pool, dapp_reward_pool)
+        }
+
+        /// Smart contracts owned by the specified account.
+        ///
+        /// `IntegratedDApps` is keyed by smart contract, so all of the registered dApps are iterated.
+        /// This is bounded by `MaxNumberOfContracts`, and is only intended for off-chain queries.
+        pub fn dapps_by_owner(owner: &T::AccountId) -> Vec<T::SmartContract> {
+            IntegratedDApps::<T>::iter()
+                .filter(|(_, dapp_info)| dapp_info.owner == *owner)
+                .map(|(smart_contract, _)| smart_contract)
+                .collect()
+        }
+
+        /// Resolves each of the given smart contracts to its dApp Id, or `None` if it isn't registered.
+        ///
+        /// Output is aligned with the input. At most `MaxNumberOfContracts` contracts are resolved,
+        /// any contracts beyond that are ignored.
+        pub fn dapp_ids(contracts: Vec<T::SmartContract>) -> Vec<Option<DAppId>> {
+            contracts
+                .iter()
+                .take(T::MaxNumberOfContracts::get() as usize)
+                .map(|smart_contract| {
+                    IntegratedDApps::<T>::get(smart_contract).map(|dapp_info| dapp_info.id)
+                })
+                .collect()
+        }
+
+        /// Smart contracts on which the account has rewards that will expire unless claimed, together with the expiry period.
+        ///
+        /// Only entries from past periods are considered, if they're eligible for the bonus reward or if the account's
+        /// staker rewards for that period are still unclaimed. Rewards staked in period `p` remain claimable while
+        /// `p >= current_period - RewardRetentionInPeriods`, so the returned expiry period is the first one in which
+        /// they can no longer be claimed. Already expired entries are omitted.
+        pub fn stake_expiry_warning(
+            account: &T::AccountId,
+        ) -> Vec<(T::SmartContract, PeriodNumber)> {
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+            let threshold_period = Self::oldest_claimable_period(current_period);
+            let unclaimed_period = Ledger::<T>::get(account).staked_period();
+
+            StakerInfo::<T>::iter_prefix(account)
+                .filter_map(|(smart_contract, staking_info)| {
+                    let period = staking_info.period_number();
+                    let expiring = period < current_period
+                        && period >= threshold_period
+                        && (staking_info.is_bonus_eligible() || unclaimed_period == Some(period));
+
+                    expiring.then(|| {
+                        let expires_at = period
+                            .saturating_add(T::RewardRetentionInPeriods::get())
+                            .saturating_add(1);
+                        (smart_contract, expires_at)
+                    })
+                })
+                .collect()
+        }
+
+        /// Checks whether `account` could stake `amount` on the specified smart contract right now.
+        ///
+        /// All of the `stake` checks are executed, but no changes are made to the state.
+        /// In case staking isn't possible, the blocking condition is returned.
+        pub fn can_stake(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            amount: Balance,
+        ) -> Result<(), StakePreflightError> {
+            let result = Self::ensure_pallet_enabled()
+                .map_err(Into::into)
+                .and_then(|_| {
+                    with_transaction(|| {
+                        let amount = Self::round_to_stake_increment(amount);
+                        let (stake_amount, bonus_status) = Self::new_stake_amount(amount);
+                        TransactionOutcome::Rollback(
+                            Self::ensure_locked_funds_aged(account, amount).and_then(|_| {
+                                Self::inner_stake(
+                                    account,
+                                    smart_contract,
+                                    stake_amount,
+                                    bonus_status,
+                                )
+                            }),
+                        )
+                    })
+                });
+
+            result.map_err(|error| {
+                let known_errors: [(DispatchError, StakePreflightError); 11] = [
+                    (Error::<T>::Disabled.into(), StakePreflightError::Disabled),
+                    (
+                        Error::<T>::ZeroAmount.into(),
+                        StakePreflightError::ZeroAmount,
+                    ),
+                    (
+                        Error::<T>::ContractNotFound.into(),
+                        StakePreflightError::ContractNotFound,
+                    ),
+                    (
+                        Error::<T>::PeriodEndsInNextEra.into(),
+                        StakePreflightError::PeriodEndsInNextEra,
+                    ),
+                    (
+                        Error::<T>::UnclaimedRewards.into(),
+                        StakePreflightError::UnclaimedRewards,
+                    ),
+                    (
+                        Error::<T>::UnavailableStakeFunds.into(),
+                        StakePreflightError::UnavailableStakeFunds,
+                    ),
+                    (
+                        Error::<T>::InsufficientStakeAmount.into(),
+                        StakePreflightError::InsufficientStakeAmount,
+                    ),
+                    (
+                        Error::<T>::TooManyStakedContracts.into(),
+                        StakePreflightError::TooManyStakedContracts,
+                    ),
+                    (
+                        Error::<T>::ContractStakeCapExceeded.into(),
+                        StakePreflightError::ContractStakeCapExceeded,
+                    ),
+                    (
+                        Error::<T>::LockedFundsNotAged.into(),
+                        StakePreflightError::LockedFundsNotAged,
+                    ),
+                    (
+                        Error::<T>::StakerCapReached.into(),
+                        StakePreflightError::StakerCapReached,
+                    ),
+                ];
+
+                known_errors
+                    .into_iter()
+                    .find(|(pallet_error, _)| *pallet_error == error)
+                    .map_or(StakePreflightError::Other, |(_, preflight_error)| {
+                        preflight_error
+                    })
+            })
+        }
+
+        /// Total amount of funds in the unlocking process, across all accounts.
+        pub fn total_unlocking() -> Balance {
+            CurrentEraInfo::<T>::get().unlocking
+        }
+
+        /// Total amount of rewards claimed by the dApp since its registration.
+        ///
+        /// Returns zero if the contract isn't registered.
+        pub fn dapp_lifetime_rewards(smart_contract: &T::SmartContract) -> Balance {
+            IntegratedDApps::<T>::get(smart_contract)
+                .map(|dapp_info| DAppLifetimeRewards::<T>::get(dapp_info.id))
+                .unwrap_or_default()
+        }
+
+        /// Returns the expired history cleanup status, as `(oldest_kept_era, current_era, pending_entries)`.
+        ///
+        /// - `oldest_kept_era` is the oldest era for which `EraRewards` or `DAppTiers` entries haven't been cleaned up yet.
+        /// - `current_era` is the ongoing era.
+        /// - `pending_entries` is the estimated number of expired `EraRewards` & `DAppTiers` entries still awaiting cleanup.
+        ///   Since only one entry of each kind is cleaned up per block, it's also a rough estimate of the number of blocks needed.
+        pub fn history_cleanup_status() -> (EraNumber, EraNumber, u32) {
+            let marker = HistoryCleanupMarker::<T>::get();
+            let current_era = ActiveProtocolState::<T>::get().era;
+
+            let pending_era_rewards = marker
+                .oldest_valid_era
+                .saturating_sub(marker.era_reward_index)
+                .div_ceil(T::EraRewardSpanLength::get());
+            let pending_dapp_tiers = marker
+                .oldest_valid_era
+                .saturating_sub(marker.dapp_tiers_index);
+
+            (
+                marker.era_reward_index.min(marker.dapp_tiers_index),
+                current_era,
+                pending_era_rewards.saturating_add(pending_dapp_tiers),
+            )
+        }
+
+        /// Returns the dApp tier assignment for the current era, based on the current stake amounts.
+        pub fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
+                protocol_state.era,
+                protocol_state.period_number(),
+                Balance::zero(),
+            );
+
+            dapp_tiers.dapps.into_inner()
+        }
+
+        /// Returns the live standing of the specified smart contract, as `(current_stake, next_era_stake, projected_tier)`.
+        ///
+        /// - `current_stake` is the amount staked on the contract for the ongoing era, which will be used for the tier assignment at the end of the era.
+        /// - `next_era_stake` is the amount staked on the contract for the next era, in the active period.
+        /// - `projected_tier` is the best tier whose current threshold is satisfied by `current_stake`; tier slot capacity is not taken into account.
+        ///
+        /// Returns `None` if the contract isn't registered.
+        pub fn dapp_current_standing(
+            smart_contract: &T::SmartContract,
+        ) -> Option<(Balance, Balance, Option<TierId>)> {
+            let dapp_info = IntegratedDApps::<T>::get(smart_contract)?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let contract_stake = ContractStake::<T>::get(dapp_info.id);
+
+            let current_stake = Self::era_stake_total(
+                &contract_stake,
+                protocol_state.era,
+                protocol_state.period_number(),
+            );
+            let next_era_stake = contract_stake.total_staked_amount(protocol_state.period_number());
+
+            let projected_tier = if current_stake.is_zero() {
+                None
+            } else {
+                TierConfig::<T>::get()
+                    .tier_thresholds()
+                    .iter()
+                    .position(|threshold| current_stake >= *threshold)
+                    .map(|tier_id| tier_id as TierId)
+            };
+
+            Some((current_stake, next_era_stake, projected_tier))
+        }
+
+        /// Returns the first and the last era (both inclusive) of the specified period.
+        ///
+        /// For the ongoing period, the last era is projected from the current subperiod & cycle configuration,
+        /// and may change if the period end is forced.
+        /// Returns `None` for future periods, or if information about the previous period's end is no longer available.
+        pub fn period_era_range(period: PeriodNumber) -> Option<(EraNumber, EraNumber)> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_period = protocol_state.period_number();
+            if period.is_zero() || period > current_period {
+                return None;
+            }
+
+            let first_era = match PeriodEnd::<T>::get(period - 1) {
+                Some(period_end) => period_end.final_era.saturating_add(1),
+                // The very first period starts with the first era.
+                None if period == 1 => 1,
+                None => return None,
+            };
+
+            let last_era = if period == current_period {
+                let next_subperiod_start_era = protocol_state.next_subperiod_start_era();
+                match protocol_state.subperiod() {
+                    Subperiod::Voting => next_subperiod_start_era
+                        .saturating_add(
+                            ActiveCycleConfiguration::<T>::eras_per_build_and_earn_subperiod(),
+                        )
+                        .saturating_sub(1),
+                    Subperiod::BuildAndEarn => next_subperiod_start_era.saturating_sub(1),
+                }
+            } else {
+                PeriodEnd::<T>::get(period)?.final_era
+            };
+
+            Some((first_era, last_era))
+        }
+
+        /// Returns the raw bonus status of the account's stake on the specified smart contract.
+        ///
+        /// `0` means the bonus reward has been forfeited, otherwise the staker is eligible for it,
+        /// with `bonus_status - 1` safe moves remaining.
+        /// Returns `None` if the account has no staking info for the contract.
+        pub fn bonus_status(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> Option<BonusStatus> {
+            StakerInfo::<T>::get(account, smart_contract)
+                .map(|staking_info| staking_info.bonus_status)
+        }
+
+        /// Returns the eras of the currently staked period for which the account has already claimed staker rewards.
+        ///
+        /// Ledger doesn't track claims per era, only a cursor pointing to the earliest unclaimed era.
+        /// Therefore, the result is always a contiguous range, starting with the first build&earn era of the staked period
+        /// and ending right before the cursor. Eras before the account's first stake in the period are included as well.
+        ///
+        /// Returns an empty vector if the account has no stake, or if information about the staked period is no longer available.
+        pub fn claimed_eras(account: &T::AccountId) -> Vec<EraNumber> {
+            let ledger = Ledger::<T>::get(account);
+            let (Some(staked_period), Some(cursor)) =
+                (ledger.staked_period(), ledger.earliest_staked_era())
+            else {
+                return Vec::new();
+            };
+
+            match Self::period_era_range(staked_period) {
+                // The first era of the period is the voting subperiod era, which never yields staker rewards.
+                Some((first_era, _)) => (first_era.saturating_add(1)..cursor).collect(),
+                None => Vec::new(),
+            }
+        }
+
+        /// Exports the complete staking state of the account: ledger, and all staking info entries.
+        ///
+        /// Entries are listed in storage iteration order, which is deterministic for the same state.
+        pub fn export_staker_state(account: &T::AccountId) -> StakerStateExport<T::SmartContract> {
+            let export_amount = |amount: &StakeAmount| StakeAmountExport {
+                voting: amount.voting,
+                build_and_earn: amount.build_and_earn,
+                era: amount.era,
+                period: amount.period,
+            };
+
+            let ledger = Ledger::<T>::get(account);
+            let stakes = StakerInfo::<T>::iter_prefix(account)
+                .map(|(smart_contract, staking_info)| StakerContractExport {
+                    smart_contract,
+                    previous_staked: export_amount(&staking_info.previous_staked),
+                    staked: export_amount(&staking_info.staked),
+                    bonus_status: staking_info.bonus_status,
+                })
+                .collect();
+
+            StakerStateExport {
+                locked: ledger.locked,
+                unlocking: ledger
+                    .unlocking
+                    .iter()
+                    .map(|chunk| (chunk.amount, chunk.unlock_block))
+                    .collect(),
+                staked: export_amount(&ledger.staked),
+                staked_future: ledger.staked_future.as_ref().map(export_amount),
+                contract_stake_count: ledger.contract_stake_count,
+                purpose: ledger.purpose,
+                stakes,
+            }
+        }
+
+        /// Estimates the annual percentage return for staking `stake` on the specified smart contract.
+        ///
+        /// This is only an approximation, based on the following assumptions:
+        /// - the contract's stake in the active period, increased by `stake`, is compared against the
+        ///   current tier thresholds to determine whether the contract would make it into any tier;
+        ///   tier slot capacity is not taken into account,
+        /// - total value staked for the next era, increased by `stake`, determines the staker reward pool,
+        ///   and both remain unchanged for the entire cycle,
+        /// - one cycle lasts a year, and staker rewards are only earned during build&earn eras,
+        /// - bonus rewards are not included.
+        ///
+        /// Returned value is saturated at 100%.
+        /// Returns `None` if `stake` is zero, contract isn't registered, or the resulting stake wouldn't satisfy any tier threshold.
+        pub fn estimate_apr(smart_contract: &T::SmartContract, stake: Balance) -> Option<Perbill> {
+            if stake.is_zero() {
+                return None;
+            }
+
+            let dapp_info = IntegratedDApps::<T>::get(smart_contract)?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let contract_stake = ContractStake::<T>::get(dapp_info.id)
+                .total_staked_amount(protocol_state.period_number())
+                .saturating_add(stake);
+            if !TierConfig::<T>::get()
+                .tier_thresholds()
+                .iter()
+                .any(|threshold| contract_stake >= *threshold)
+            {
+                return None;
+            }
+
+            let total_staked = CurrentEraInfo::<T>::get()
+                .total_staked_amount_next_era()
+                .saturating_add(stake);
+            let (staker_reward_pool, _) = Self::staker_and_dapp_reward_pools(total_staked);
+
+            let era_reward = Perbill::from_rational(stake, total_staked) * staker_reward_pool;
+            let annual_reward = era_reward.saturating_mul(
+                ActiveCycleConfiguration::<T>::build_and_earn_eras_per_cycle().into(),
+            );
+
+            Some(Perbill::from_rational(annual_reward, stake))
+        }
+
+        /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
+        ///
+        /// ### Algorithm
+        ///
+        /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
+        ///    This information is used to calculate 'score' per dApp, which is used to determine the tier.
+        ///
+        /// 2. Sort the entries by the score, in descending order - the top score dApp comes first.
+        ///
+        /// 3. Assign dApps to tiers based on stake thresholds, calculating ratio-based ranks within each tier.
+        ///
+        /// 4. Calculate reward components per tier using the **rank multiplier model**:
+        ///
+        ///    ```text
+        ///    Step 1: Calculate weights
+        ///        increment = (multiplier - 100%) ÷ MAX_RANK
+        ///        dApp_weight = 100% + rank × increment
+        ///
+        ///    Step 2: Find reward rate (with overflow cap)
+        ///        target_weight = max_slots × avg_weight       (calibrated for avg rank = 5)
+        ///        actual_weight = filled_slots × 100% + ranks_sum × increment
+        ///        reward_per_% = tier_allocation ÷ max(actual_weight, target_weight)
+        ///
+        ///    Step 3: Pre-compute claim values
+        ///        tier_reward = 100% × reward_per_%
+        ///        rank_reward = increment × reward_per_%
+        ///    ```
+        ///    If `TierRewardRounding` is `DistributeRemainder`, the amount lost to integer division
+        ///    is assigned to the top dApp of the tier.
+        ///    dApps which opted out of dApp rewards are handled according to `OptedOutRewardHandling`,
+        ///    either not occupying any tier slot, or occupying one without being rewarded for it.
+        ///    (Sort the entries by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
+        ///
+        /// The returned object contains information about each dApp that made it into a tier.
+        /// Alongside tier assignment info, number of read DB contract stake entries is returned.
+        pub(crate) fn get_dapp_tier_assignment_and_rewards(
+            era: EraNumber,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> (DAppTierRewardsFor<T>, DAppId) {
+            let mut dapp_stakes = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
+
+            // 1.
+            // Iterate over all staked dApps.
+            // This is bounded by max amount of dApps we allow to be registered.
+            let mut counter = 0;
+            for (dapp_id, stake_amount) in ContractStake::<T>::iter() {
+                counter.saturating_inc();
+
+                // Skip dApps which don't have ANY amount staked
+                let amount = Self::era_stake_total(&stake_amount, era, period);
+                if !amount.is_zero() {
+                    dapp_stakes.push((dapp_id, amount));
+                }
+            }
+
+            (
+                Self::compute_dapp_tier_assignment_and_rewards(
+                    dapp_stakes,
+                    period,
+                    dapp_reward_pool,
+                ),
+                counter,
+            )
+        }
+
+        /// Executes steps 2. to 5. of the tier assignment algorithm described in
+        /// [`Self::get_dapp_tier_assignment_and_rewards`], using the provided non-zero dApp stakes.
+        pub(crate) fn compute_dapp_tier_assignment_and_rewards(
+            mut dapp_stakes: Vec<(DAppId, Balance)>,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> DAppTierRewardsFor<T> {
+            // 2.
+            // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
+            // Equal amounts are ordered by dApp Id, so the result doesn't depend on the order in which stakes were collected.
+            dapp_stakes.sort_unstable_by(|(id_1, amount_1), (id_2, amount_2)| {
+                amount_2.cmp(amount_1).then_with(|| id_1.cmp(id_2))
+            });
+
+            let tier_config = TierConfig::<T>::get();
+            let tier_params = StaticTierParams::<T>::get();
+
+            // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
+            // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
+            //
+            // In the current implementation, the dApp with the lower dApp Id has the advantage.
+            // There is no guarantee this will persist in the future, so it's best for dApps to do their
+            // best to avoid getting themselves into such situations.
+
+            // dApps which voluntarily capped the best tier they can be assigned to.
+            let tier_caps: BTreeMap<DAppId, TierId> = dapp_stakes
+                .iter()
+                .filter_map(|(dapp_id, _)| {
+                    DAppTierCap::<T>::get(dapp_id).map(|cap| (*dapp_id, cap))
+                })
+                .collect();
+
+            // dApps which opted out of dApp rewards.
+            let opted_out: BTreeSet<DAppId> = dapp_stakes
+                .iter()
+                .map(|(dapp_id, _)| *dapp_id)
+                .filter(|dapp_id| DAppRewardOptOut::<T>::get(dapp_id))
+                .collect();
+            if T::OptedOutRewardHandling::get() == OptOutRewardHandling::Redistribute {
+                dapp_stakes.retain(|(dapp_id, _)| !opted_out.contains(dapp_id));
+            }
+
+            // 3.
+            // Iterate over configured tier and potential dApps.
+            // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
+            // and tier capacity hasn't been filled yet. Capped dApps are skipped until their cap tier is reached.
+            let mut dapp_tiers = BTreeMap::new();
+            let mut tier_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
+            let mut rank_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
+            let mut remainders = BTreeMap::new();
+
+            let mut upper_bound = Balance::zero();
+
+            let reward_portion = Self::boosted_reward_portion(
+                &tier_config.reward_portion,
+                &tier_config.slots_per_tier,
+            );
+
+            for (tier_id, (tier_capacity, lower_bound)) in tier_config
+                .slots_per_tier
+                .iter()
+                .zip(tier_config.tier_thresholds.iter())
+                .enumerate()
+            {
+                let mut tier_slots = BTreeMap::new();
+                let mut top_dapp_id = None;
+
+                // Iterate over unassigned dApps until one of two conditions has been met:
+                // 1. Tier has no more capacity
+                // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
+                for (dapp_id, staked_amount) in dapp_stakes
+                    .iter()
+                    .filter(|(dapp_id, _)| {
+                        !dapp_tiers.contains_key(dapp_id)
+                            && tier_caps
+                                .get(dapp_id)
+                                .is_none_or(|cap| usize::from(*cap) <= tier_id)
+                    })
+                    .take_while(|(_, amount)| amount.ge(lower_bound))
+                    .take(*tier_capacity as usize)
+                {
+                    if !opted_out.contains(dapp_id) {
+                        top_dapp_id.get_or_insert(*dapp_id);
+                    }
+                    let rank = if T::RankingEnabled::get() {
+                        RankedTier::find_rank(*lower_bound, upper_bound, *staked_amount)
+                    } else {
+                        0
+                    };
+                    tier_slots.insert(*dapp_id, RankedTier::new_saturated(tier_id as u8, rank));
+                }
+
+                // 4. Compute tier rewards using rank multiplier
+                let filled_slots = tier_slots.len() as u32;
+                // sum of all ranks for this tier
+                let ranks_sum = tier_slots
+                    .iter()
+                    .fold(0u32, |accum, (_, x)| accum.saturating_add(x.rank().into()));
+
+                let multiplier_bips = tier_params
+                    .tier_rank_multipliers
+                    .get(tier_id)
+                    .copied()
+                    .unwrap_or(10_000);
+
+                let tier_allocation = reward_portion
+                    .get(tier_id)
+                    .copied()
+                    .unwrap_or(Permill::zero())
+                    * dapp_reward_pool;
+
+                let (tier_reward, rank_reward, remainder) =
+                    Self::compute_tier_rewards_with_remainder(
+                        tier_allocation,
+                        *tier_capacity,
+                        filled_slots,
+                        ranks_sum,
+                        multiplier_bips,
+                    );
+
+                // dApps are sorted by stake, so the first one in the tier is the top one.
+                if T::TierRewardRounding::get() == RewardRounding::DistributeRemainder
+                    && !remainder.is_zero()
+                {
+                    if let Some(top_dapp_id) = top_dapp_id {
+                        remainders.insert(top_dapp_id, remainder);
+                    }
+                }
+
+                tier_rewards.push(tier_reward);
+                rank_rewards.push(rank_reward);
+                dapp_tiers.append(&mut tier_slots);
+                upper_bound = *lower_bound; // current threshold becomes upper bound for next tier
+            }
+
+            // Opted-out dApps which occupied a tier slot aren't rewarded for it.
+            dapp_tiers.retain(|dapp_id, _| !opted_out.contains(dapp_id));
+
+            // 5.
+            // Prepare and return tier & rewards info.
+            // In case rewards creation fails, we just write the default value. This should never happen though.
+            DAppTierRewards::<T::MaxNumberOfContracts, T::NumberOfTiers>::new(
+                dapp_tiers,
+                tier_rewards,
+                period,
+                rank_rewards,
+                remainders,
+            )
+            .unwrap_or_default()
+        }
+
+        /// Returns the tier reward portions with the top tier boosted by [`Config::TopTierBoost`].
+        ///
+        /// The top tier is the best tier which has any slots, since tiers without slots can't be entered
+        /// and their reward would be lost. The boost is deducted from the tiers below it, proportionally
+        /// to their reward portions, so the sum of all portions remains the same. In case the boost exceeds
+        /// the sum of the lower tier portions, all of it is moved to the top tier.
+        pub(crate) fn boosted_reward_portion(
+            reward_portion: &[Permill],
+            slots_per_tier: &[u16],
+        ) -> Vec<Permill> {
+            let mut boosted = reward_portion.to_vec();
+            let Some(top_tier_index) = slots_per_tier.iter().position(|slots| *slots > 0) else {
+                return boosted;
+            };
+            let Some((top_tier, lower_tiers)) = boosted
+                .get_mut(top_tier_index..)
+                .and_then(|tiers| tiers.split_first_mut())
+            else {
+                return boosted;
+            };
+
+            let lower_tiers_sum = lower_tiers.iter().fold(0_u32, |acc, portion| {
+                acc.saturating_add(portion.deconstruct())
+            });
+            let boost = T::TopTierBoost::get().deconstruct().min(lower_tiers_sum);
+            if boost.is_zero() {
+                return boosted;
+            }
+
+            let mut deducted = 0_u32;
+            for portion in lower_tiers.iter_mut() {
+                let deduction =
+                    Perbill::from_rational(portion.deconstruct(), lower_tiers_sum) * boost;
+                *portion = Permill::from_parts(portion.deconstruct().saturating_sub(deduction));
+                deducted.saturating_accrue(deduction);
+            }
+            *top_tier = Permill::from_parts(top_tier.deconstruct().saturating_add(deducted));
+
+            // Boost only moves the reward between tiers, the total must remain unchanged.
+            let sum = |portions: &[Permill]| {
+                portions.iter().fold(0_u32, |acc, portion| {
+                    acc.saturating_add(portion.deconstruct())
+                })
+            };
+            if sum(&boosted) != sum(reward_portion) {
+                log::error!(
+                    target: LOG_TARGET,
+                    "Boosted reward portions don't sum up to the original ones, ignoring the boost."
+                );
+                return reward_portion.to_vec();
+            }
+
+            boosted
+        }
+
+        /// Total amount staked on a dApp in the specified era & period, zero if nothing is staked.
+        fn era_stake_total(
+            contract_stake: &ContractStakeAmount,
+            era: EraNumber,
+            period: PeriodNumber,
+        ) -> Balance {
+            contract_stake
+                .get(era, period)
+                .map_or(Balance::zero(), |stake_amount| stake_amount.total())
+        }
+
+        /// Collects non-zero dApp stakes for the ongoing era into the [`DAppStakesSnapshot`],
+        /// reading as many `ContractStake` entries as the provided weight allows.
+        ///
+        /// Once the snapshot is complete, the tier assignment at the end of the era doesn't need to
+        /// iterate over the `ContractStake` map, which reduces the weight of the era transition block.
+        ///
+        /// Returns consumed weight.
+        pub(crate) fn dapp_stakes_snapshot_step(remaining_weight: &Weight) -> Weight {
+            // Need to be able to process at least one entry
+            if remaining_weight.any_lt(T::WeightInfo::dapp_stakes_snapshot(1)) {
+                return Weight::zero();
+            }
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            if protocol_state.maintenance || protocol_state.subperiod() != Subperiod::BuildAndEarn {
+                return T::DbWeight::get().reads(1);
+            }
+            let (era, period) = (protocol_state.era, protocol_state.period_number());
+
+            let mut snapshot = match TierAssignmentSnapshot::<T>::get() {
+                Some(snapshot) if snapshot.era == era => snapshot,
+                _ => DAppStakesSnapshotFor::<T>::new(era),
+            };
+            if snapshot.complete {
+                return T::WeightInfo::dapp_stakes_snapshot(0);
+            }
+
+            let mut contract_stake_iter = match snapshot.last_processed {
+                Some(dapp_id) => {
+                    ContractStake::<T>::iter_from(ContractStake::<T>::hashed_key_for(dapp_id))
+                }
+                None => ContractStake::<T>::iter(),
+            };
+
+            let mut processed: u32 = 0;
+            while T::WeightInfo::dapp_stakes_snapshot(processed.saturating_add(1))
+                .all_lte(*remaining_weight)
+            {
+                let Some((dapp_id, contract_stake)) = contract_stake_iter.next() else {
+                    snapshot.complete = true;
+                    break;
+                };
+                processed.saturating_inc();
+
+                snapshot.last_processed = Some(dapp_id);
+                let amount = Self::era_stake_total(&contract_stake, era, period);
+                if snapshot.set(dapp_id, amount).is_err() {
+                    // Should never happen since the number of dApps is bounded.
+                    // The tier assignment will fall back to reading the `ContractStake` map.
+                    TierAssignmentSnapshot::<T>::kill();
+                    return T::WeightInfo::dapp_stakes_snapshot(processed);
+                }
+            }
+
+            TierAssignmentSnapshot::<T>::put(snapshot);
+
+            T::WeightInfo::dapp_stakes_snapshot(processed)
+        }
+
+        /// Automatically claims the previous era's dApp rewards for the dApps which opted in.
+        ///
+        /// At most `MaxAutoClaimsPerBlock` dApps are processed per block, continuing from the last processed one,
+        /// so all of them are eventually covered. Rewards which were already claimed, or which aren't available, are skipped.
+        /// In case the era changes before all of the dApps are processed, the remaining rewards are left for manual claiming.
+        ///
+        /// Returns consumed weight.
+        pub(crate) fn dapp_auto_claim_step() -> Weight {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let max_claims = T::MaxAutoClaimsPerBlock::get();
+            if protocol_state.maintenance || max_claims.is_zero() || protocol_state.era.is_zero() {
+                return T::DbWeight::get().reads(1);
+            }
+            let era = protocol_state.era.saturating_sub(1);
+
+            let mut progress = match AutoClaimCursor::<T>::get() {
+                Some(progress) if progress.era == era => progress,
+                _ => AutoClaimProgress::new(era),
+            };
+            if progress.complete {
+                return T::DbWeight::get().reads(2);
+            }
+
+            // Nothing to claim for eras without a tier assignment, e.g. voting subperiod eras.
+            if !DAppTiers::<T>::contains_key(era) {
+                progress.complete = true;
+                AutoClaimCursor::<T>::put(progress);
+                return T::DbWeight::get().reads_writes(3, 1);
+            }
+
+            let mut auto_claim_iter = match progress.last_processed {
+                Some(dapp_id) => {
+                    DAppAutoClaim::<T>::iter_from(DAppAutoClaim::<T>::hashed_key_for(dapp_id))
+                }
+                None => DAppAutoClaim::<T>::iter(),
+            };
+
+            let mut processed = 0_u32;
+            while processed < max_claims {
+                let Some((dapp_id, smart_contract)) = auto_claim_iter.next() else {
+                    progress.complete = true;
+                    break;
+                };
+                processed.saturating_inc();
+                progress.last_processed = Some(dapp_id);
+
+                let Some(dapp_info) = IntegratedDApps::<T>::get(&smart_contract) else {
+                    continue;
+                };
+                let result = with_storage_layer(|| {
+                    Self::internal_claim_dapp_reward(
+                        &dapp_info,
+                        &smart_contract,
+                        era,
+                        &protocol_state,
+                    )
+                });
+                if let Ok((beneficiary, amount)) = result {
+                    if DAppAutoCompound::<T>::get(dapp_id) {
+                        Self::auto_compound_dapp_reward(beneficiary, smart_contract, amount);
+                    }
+                }
+            }
+
+            AutoClaimCursor::<T>::put(progress);
+
+            T::DbWeight::get()
+                .reads_writes(3, 1)
+                .saturating_add(Self::dapp_auto_claim_weight(processed))
+        }
+
+        /// Weight of automatically claiming the dApp rewards for the specified number of processed dApps.
+        ///
+        /// Each dApp is charged as a full `claim_dapp_reward` call with auto-compounding, plus reading its `DAppAutoClaim` entry.
+        fn dapp_auto_claim_weight(dapps: u32) -> Weight {
+            T::WeightInfo::claim_dapp_reward()
+                .saturating_add(T::WeightInfo::lock_new_account())
+                .saturating_add(T::WeightInfo::stake())
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_mul(dapps.into())
+        }
+
+        /// Deposits `ContractCrossedTierThreshold` if the contract's next era stake moved across a tier threshold.
+        ///
+        /// Only the best tier whose threshold is satisfied is compared, so a single event is deposited even
+        /// if multiple thresholds were crossed. The amount of work is bounded by the number of tiers.
+        fn notify_tier_threshold_crossing(
+            smart_contract: &T::SmartContract,
+            stake_before: Balance,
+            stake_after: Balance,
+        ) {
+            if stake_before == stake_after {
+                return;
+            }
+
+            let tier_config = TierConfig::<T>::get();
+            let best_tier = |stake: Balance| {
+                tier_config
+                    .tier_thresholds()
+                    .iter()
+                    .position(|threshold| stake >= *threshold)
+                    .map(|tier_id| tier_id as TierId)
+            };
+
+            // Lower tier Id is a better tier, `None` means no threshold is satisfied.
+            let (tier_id, crossed_up) = match (best_tier(stake_before), best_tier(stake_after)) {
+                (Some(before), Some(after)) if after > before => (before, false),
+                (Some(before), None) => (before, false),
+                (before, Some(after)) if before != Some(after) => (after, true),
+                _ => return,
+            };
+
+            Self::deposit_event(Event::<T>::ContractCrossedTierThreshold {
+                smart_contract: smart_contract.clone(),
+                tier_id,
+                crossed_up,
+            });
+        }
+
+        /// Keeps the [`DAppStakesSnapshot`] consistent with an updated `ContractStake` entry.
+        ///
+        /// Entries which haven't been processed yet are ignored, since they will be read later on.
+        fn update_dapp_stakes_snapshot(dapp_id: DAppId, contract_stake: &ContractStakeAmount) {
+            TierAssignmentSnapshot::<T>::mutate_exists(|maybe_snapshot| {
+                let Some(snapshot) = maybe_snapshot else {
+                    return;
+                };
+
+                let is_processed = snapshot.complete
+                    || snapshot.last_processed.map_or(false, |last_processed| {
+                        ContractStake::<T>::hashed_key_for(dapp_id)
+                            <= ContractStake::<T>::hashed_key_for(last_processed)
+                    });
+                if !is_processed {
+                    return;
+                }
+
+                let protocol_state = ActiveProtocolState::<T>::get();
+                if snapshot.era != protocol_state.era {
+                    // Outdated snapshot, it can never be used.
+                    *maybe_snapshot = None;
+                    return;
+                }
+
+                let amount = Self::era_stake_total(
+                    contract_stake,
+                    snapshot.era,
+                    protocol_state.period_number(),
+                );
+                if snapshot.set(dapp_id, amount).is_err() {
+                    *maybe_snapshot = None;
+                }
+            });
+        }
+
+        /// Used to handle era & period transitions.
+        pub(crate) fn era_and_period_handler(
+            now: BlockNumber,
+            tier_assignment: TierAssignment,
+        ) -> Weight {
+            let mut protocol_state = ActiveProtocolState::<T>::get();
+
+            // `ActiveProtocolState` is whitelisted, so we need to account for its read.
+            let mut consumed_weight = T::DbWeight::get().reads(1);
+
+            // We should not modify pallet storage while in maintenance mode.
+            // This is a safety measure, since maintenance mode is expected to be
+            // enabled in case some misbehavior or corrupted storage is detected.
+            if protocol_state.maintenance {
+                return consumed_weight;
+            }
+
+            // Inform observers about the upcoming new era, if it's the case.
+            if protocol_state.next_era_start == now.saturating_add(1) {
+                consumed_weight
+                    .saturating_accrue(Self::notify_block_before_new_era(&protocol_state));
+            }
+
+            // Nothing to do if it's not new era
+            if !protocol_state.is_new_era(now) {
+                return consumed_weight;
+            }
+
+            // At this point it's clear that an era change will happen
+            let mut era_info = CurrentEraInfo::<T>::get();
+
+            let current_era = protocol_state.era;
+            let next_era = current_era.saturating_add(1);
+            let (maybe_period_event, era_reward) = match protocol_state.subperiod() {
+                // Voting subperiod only lasts for one 'prolonged' era
+                Subperiod::Voting => {
+                    // For the sake of consistency, we put zero reward into storage. There are no rewards for the voting subperiod.
+                    let era_reward = EraReward {
+                        staker_reward_pool: Balance::zero(),
+                        staked: era_info.total_staked_amount(),
+                        dapp_reward_pool: Balance::zero(),
+                    };
+
+                    let next_subperiod_start_era = next_era.saturating_add(
+                        ActiveCycleConfiguration::<T>::eras_per_build_and_earn_subperiod(),
+                    );
+                    let build_and_earn_start_block =
+                        now.saturating_add(ActiveCycleConfiguration::<T>::blocks_per_era());
+                    protocol_state.advance_to_next_subperiod(
+                        next_subperiod_start_era,
+                        build_and_earn_start_block,
+                    );
+
+                    era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+
+                    consumed_weight
+                        .saturating_accrue(T::WeightInfo::on_initialize_voting_to_build_and_earn());
+
+                    (
+                        Some(Event::<T>::NewSubperiod {
+                            subperiod: protocol_state.subperiod(),
+                            number: protocol_state.period_number(),
+                        }),
+                        era_reward,
+                    )
+                }
+                Subperiod::BuildAndEarn => {
+                    let staked = era_info.total_staked_amount();
+                    let (staker_reward_pool, dapp_reward_pool) =
+                        Self::with_reward_pool_top_up(Self::staker_and_dapp_reward_pools(staked));
+                    let era_reward = EraReward {
+                        staker_reward_pool,
+                        staked,
+                        dapp_reward_pool,
+                    };
+
+                    // Distribute dapps into tiers, write it into storage
+                    //
+                    // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
+                    // This must never be used in production code, obviously.
+                    //
+                    // If dApp stakes were fully collected during `on_idle`, there's no need to iterate over all contract stake entries.
+                    let (dapp_tier_rewards, tier_assignment_weight) = match tier_assignment {
+                        TierAssignment::Real => match TierAssignmentSnapshot::<T>::take() {
+                            Some(snapshot) if snapshot.era == current_era && snapshot.complete => {
+                                let stakes_len = snapshot.stakes.len() as u32;
+                                (
+                                    Self::compute_dapp_tier_assignment_and_rewards(
+                                        snapshot.stakes.into_inner(),
+                                        protocol_state.period_number(),
+                                        dapp_reward_pool,
+                                    ),
+                                    T::WeightInfo::dapp_tier_assignment_from_snapshot(stakes_len),
+                                )
+                            }
+                            _ => {
+                                let (dapp_tier_rewards, counter) =
+                                    Self::get_dapp_tier_assignment_and_rewards(
+                                        current_era,
+                                        protocol_state.period_number(),
+                                        dapp_reward_pool,
+                                    );
+                                (
+                                    dapp_tier_rewards,
+                                    T::WeightInfo::dapp_tier_assignment(counter.into()),
+                                )
+                            }
+                        },
+                        #[cfg(feature = "runtime-benchmarks")]
+                        TierAssignment::Dummy => (
+                            DAppTierRewardsFor::<T>::default(),
+                            T::WeightInfo::dapp_tier_assignment(0),
+                        ),
+                    };
+                    DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
+
+                    consumed_weight.saturating_accrue(tier_assignment_weight);
+
+                    // Switch to `Voting` period if conditions are met.
+                    if protocol_state.period_info.is_next_period(next_era) {
+                        // Store info about period end
+                        let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
+                        PeriodEnd::<T>::insert(
+                            &protocol_state.period_number(),
+                            PeriodEndInfo {
+                                bonus_reward_pool,
+                                total_vp_stake: era_info.staked_amount(Subperiod::Voting),
+                                final_era: current_era,
+                            },
+                        );
+
+                        // Apply the scheduled cycle configuration change, if any, so the new period uses it from the start.
+                        if let Some(config) = PendingCycleConfig::<T>::take() {
+                            ActiveCycleConfig::<T>::put(config);
+                            Self::deposit_event(Event::<T>::CycleConfigChangeApplied { config });
+                        }
+
+                        // For the sake of consistency we treat the whole `Voting` period as a single era.
+                        // This means no special handling is required for this period, it only lasts potentially longer than a single standard era.
+                        let next_subperiod_start_era = next_era.saturating_add(1);
+                        let voting_period_length = Self::blocks_per_voting_period();
+                        let next_era_start_block = now.saturating_add(voting_period_length);
+
+                        protocol_state.advance_to_next_subperiod(
+                            next_subperiod_start_era,
+                            next_era_start_block,
+                        );
+
+                        era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+
+                        // Update historical cleanup marker.
+                        // Must be called with the new period number.
+                        Self::update_cleanup_marker(protocol_state.period_number());
+
+                        consumed_weight.saturating_accrue(
+                            T::WeightInfo::on_initialize_build_and_earn_to_voting(),
+                        );
+
+                        (
+                            Some(Event::<T>::NewSubperiod {
+                                subperiod: protocol_state.subperiod(),
+                                number: protocol_state.period_number(),
+                            }),
+                            era_reward,
+                        )
+                    } else {
+                        let next_era_start_block =
+                            now.saturating_add(ActiveCycleConfiguration::<T>::blocks_per_era());
+                        protocol_state.next_era_start = next_era_start_block;
+
+                        era_info.migrate_to_next_era(None);
+
+                        consumed_weight.saturating_accrue(
+                            T::WeightInfo::on_initialize_build_and_earn_to_build_and_earn(),
+                        );
+
+                        (None, era_reward)
+                    }
+                }
+            };
+
+            // Update storage items
+            protocol_state.era = next_era;
+            ActiveProtocolState::<T>::put(protocol_state);
+
+            CurrentEraInfo::<T>::put(era_info);
+
+            let era_span_index = Self::era_reward_span_index(current_era);
+            let mut span = EraRewards::<T>::get(&era_span_index).unwrap_or(EraRewardSpan::new());
+            if let Err(_) = span.push(current_era, era_reward) {
+                // This must never happen but we log the error just in case.
+                log::error!(
+                    target: LOG_TARGET,
+                    "Failed to push era {} into the era reward span.",
+                    current_era
+                );
+            }
+            EraRewards::<T>::insert(&era_span_index, span);
+
+            // Re-calculate tier configuration for the upcoming new era
+            let tier_params = StaticTierParams::<T>::get();
+            let total_issuance = T::Currency::total_issuance();
+
+            let tier_config = TierConfig::<T>::get();
+            let mut new_tier_config = tier_config.calculate_new(&tier_params, total_issuance);
+            if let Some(slots_per_tier) = SlotsPerTierOverride::<T>::get() {
+                new_tier_config.slots_per_tier = slots_per_tier;
+            }
+            if let Some(smoothing) = T::ThresholdSmoothing::get() {
+                new_tier_config.smooth_tier_thresholds(&tier_config, smoothing);
+            }
+
+            // Validate new tier configuration
+            if new_tier_config.is_valid() {
+                TierConfig::<T>::put(new_tier_config);
+            } else {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "New tier configuration is invalid for era {}, preserving old one.",
+                    next_era
+                );
+            }
+
+            consumed_weight.saturating_accrue(Self::process_claim_reminders(next_era));
+
+            Self::deposit_event(Event::<T>::EraRewardsSummary {
+                era: current_era,
+                total_paid: TotalRewardsPaid::<T>::get(current_era),
+            });
+            Self::deposit_event(Event::<T>::NewEra { era: next_era });
+            if let Some(period_event) = maybe_period_event {
+                Self::deposit_event(period_event);
+            }
+
+            consumed_weight
+        }
+
+        /// Checks the next batch of claim reminders, emitting `ClaimReminderDue` for the ones due in `era`
+        /// and advancing their next due era. Reminders of accounts without locked funds are removed.
+        ///
+        /// At most `MaxClaimRemindersPerEra` reminders are checked, continuing from where the previous check stopped.
+        ///
+        /// Returns consumed weight.
+        pub(crate) fn process_claim_reminders(era: EraNumber) -> Weight {
+            let limit = T::MaxClaimRemindersPerEra::get() as usize;
+            let reminders: Vec<_> = match ClaimReminderCursor::<T>::get() {
+                Some(account) => ClaimReminderEra::<T>::iter_from(
+                    ClaimReminderEra::<T>::hashed_key_for(&account),
+                )
+                .take(limit)
+                .collect(),
+                None => ClaimReminderEra::<T>::iter().take(limit).collect(),
+            };
+
+            // If the end of the map has been reached, the next check starts from the beginning.
+            let cursor = if reminders.len() < limit {
+                None
+            } else {
+                reminders.last().map(|(account, _)| account.clone())
+            };
+            ClaimReminderCursor::<T>::set(cursor);
+
+            let checked = reminders.len() as u32;
+            for (account, mut reminder) in reminders {
+                if !Ledger::<T>::contains_key(&account) {
+                    ClaimReminderEra::<T>::remove(&account);
+                } else if reminder.next_era <= era {
+                    reminder.next_era = era.saturating_add(reminder.every);
+                    ClaimReminderEra::<T>::insert(&account, reminder);
+                    Self::deposit_event(Event::<T>::ClaimReminderDue { account });
+                }
+            }
+
+            T::WeightInfo::process_claim_reminders(checked)
+        }
+
+        /// Adds the paid out reward amount to the total for the ongoing era.
+        fn record_reward_payout(amount: Balance) {
+            let current_era = ActiveProtocolState::<T>::get().era;
+            TotalRewardsPaid::<T>::mutate(current_era, |total_paid| {
+                total_paid.saturating_accrue(amount)
+            });
+        }
+
+        /// Used to notify observers about the upcoming new era in the next block.
+        fn notify_block_before_new_era(protocol_state: &ProtocolState) -> Weight {
+            let next_era = protocol_state.era.saturating_add(1);
+            T::Observers::block_before_new_era(next_era)
+        }
+
+        /// Updates the cleanup marker with the new oldest valid era if possible.
+        ///
+        /// It's possible that the call will be a no-op since we haven't advanced enough periods yet.
+        fn update_cleanup_marker(new_period_number: PeriodNumber) {
+            // 1. Find out the latest expired period; rewards can no longer be claimed for it or any older period.
+            let latest_expired_period = match new_period_number
+                .checked_sub(T::RewardRetentionInPeriods::get().saturating_add(1))
+            {
+                Some(period) if !period.is_zero() => period,
+                // Haven't advanced enough periods to have any expired entries.
+                _ => return,
+            };
+
+            // 2. Find the oldest valid era for which rewards can still be claimed.
+            //    Technically, this will be `Voting` subperiod era but it doesn't matter.
+            //
+            //    Also, remove the expired `PeriodEnd` entry since it's no longer needed.
+            let oldest_valid_era = match PeriodEnd::<T>::take(latest_expired_period) {
+                Some(period_end_info) => period_end_info.final_era.saturating_add(1),
+                None => {
+                    // Should never happen but nothing we can do if it does.
+                    log::error!(
+                        target: LOG_TARGET,
+                        "No `PeriodEnd` entry for the expired period: {}",
+                        latest_expired_period
+                    );
+                    return;
+                }
+            };
+
+            // 3. Update the cleanup marker with the new oldest valid era.
+            HistoryCleanupMarker::<T>::mutate(|marker| {
+                marker.oldest_valid_era = oldest_valid_era;
+            });
+        }
+
+        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
+        ///
+        /// Returns consumed weight.
+        fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
+            // Need to be able to process one full pass
+            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
+                return Weight::zero();
+            }
+
+            // Get the cleanup marker and ensure we have pending cleanups.
+            let mut cleanup_marker = HistoryCleanupMarker::<T>::get();
+            if !cleanup_marker.has_pending_cleanups() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // 1. Attempt to cleanup one expired `EraRewards` entry.
+            if cleanup_marker.era_reward_index < cleanup_marker.oldest_valid_era {
+                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
+                    // If oldest valid era comes AFTER this span, it's safe to delete it.
+                    if era_reward.last_era() < cleanup_marker.oldest_valid_era {
+                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
+                        cleanup_marker
+                            .era_reward_index
+                            .saturating_accrue(T::EraRewardSpanLength::get());
+                    }
+                } else {
+                    // Can happen if the entry is part of history before dApp staking v3
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Era rewards span for era {} is missing, but cleanup marker is set.",
+                        cleanup_marker.era_reward_index
+                    );
+                    cleanup_marker
+                        .era_reward_index
+                        .saturating_accrue(T::EraRewardSpanLength::get());
+                }
+            }
+
+            // 2. Attempt to cleanup one expired `DAppTiers` entry.
+            if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
+                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
+                cleanup_marker.dapp_tiers_index.saturating_inc();
+            }
+
+            // Store the updated cleanup marker
+            HistoryCleanupMarker::<T>::put(cleanup_marker);
+
+            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
+            // we opt for the simpler solution where only 1 entry per block is cleaned up.
+            // It can be changed though.
+
+            // It could end up being less than this weight, but this won't occur often enough to be important.
+            T::WeightInfo::on_idle_cleanup()
+        }
+
+        /// Ensure that `delegate` is allowed to manage the stakes of the `owner` account.
+        fn ensure_stake_delegate(owner: &T::AccountId, delegate: &T::AccountId) -> DispatchResult {
+            ensure!(
+                StakeDelegate::<T>::get(owner).as_ref() == Some(delegate),
+                Error::<T>::NotStakeDelegate
+            );
+            Ok(())
+        }
+
+        /// Ensure that staking `amount` doesn't use funds locked too recently, see `LockToStakeDelayEras`.
+        ///
+        /// Only applies to new stakes, moving already staked funds or compounding rewards isn't restricted.
+        fn ensure_locked_funds_aged(account: &T::AccountId, amount: Balance) -> DispatchResult {
+            let delay = T::LockToStakeDelayEras::get();
+            if delay.is_zero() {
+                return Ok(());
+            }
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let ledger = Ledger::<T>::get(account);
+            let fresh_amount = ledger.fresh_locked_amount(protocol_state.era, delay);
+            ensure!(
+                fresh_amount.is_zero()
+                    || amount
+                        <= ledger
+                            .stakeable_amount(protocol_state.period_number())
+                            .saturating_sub(fresh_amount),
+                Error::<T>::LockedFundsNotAged
+            );
+
+            Ok(())
+        }
+
+        /// Internal function that executes the `stake` logic for the specified account.
+        fn internal_stake(
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) -> DispatchResult {
+            let amount = Self::round_to_stake_increment(amount);
+            Self::ensure_locked_funds_aged(&account, amount)?;
+
+            // User is only eligible for the bonus reward if their first time stake is in the `Voting` subperiod.
+            //
+            // `StakeAmount` is prepared based on the current subperiod.
+            // If the user is staking for the first time in the `Voting` subperiod, they are eligible for the bonus reward, and the max number of bonus moves is set.
+            // If the user is staking for the first time in the `Build&Earn` subperiod, they are not eligible for the bonus reward, and the bonus moves are set to 0.
+            let (stake_amount, bonus_status) = Self::new_stake_amount(amount);
+
+            // The `inner_stake` function takes a `StakeAmount` struct allowing modification of both `voting` and `build_and_earn` amounts at the same time.
+            Self::inner_stake(&account, &smart_contract, stake_amount, bonus_status)?;
+
+            // Warn the staker in case their stake won't yield any rewards.
+            let (staker_reward_pool, _) = Self::staker_and_dapp_reward_pools(
+                CurrentEraInfo::<T>::get().total_staked_amount_next_era(),
+            );
+            if staker_reward_pool.is_zero() {
+                Self::deposit_event(Event::<T>::StakingIntoEmptyPool {
+                    account: account.clone(),
+                    smart_contract: smart_contract.clone(),
+                });
+            }
+
+            Self::deposit_event(Event::<T>::Stake {
+                account,
+                smart_contract,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Internal function that executes the `unstake` logic for the specified account.
+        fn internal_unstake(
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) -> DispatchResult {
+            let (unstake_amount, _) = Self::inner_unstake(&account, &smart_contract, amount)?;
+
+            Self::deposit_event(Event::<T>::Unstake {
+                account,
+                smart_contract,
+                amount: unstake_amount.total(),
+            });
+
+            Ok(())
+        }
+
+        /// Internal function that executes the `move_stake` logic for the specified account.
+        fn internal_move_stake(
+            account: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                !source_contract.eq(&destination_contract),
+                Error::<T>::SameContracts
+            );
+
+            // The final era stake grace only applies to new stake, moving stake in the final era isn't allowed.
+            let protocol_state = ActiveProtocolState::<T>::get();
+            ensure!(
+                !protocol_state
+                    .period_info
+                    .is_next_period(protocol_state.era.saturating_add(1)),
+                Error::<T>::PeriodEndsInNextEra
+            );
+
+            if let Some(max_moves) = T::MaxMovesPerPeriod::get() {
+                let current_period = protocol_state.period_number();
+                let moves = match MovesInPeriod::<T>::get(&account) {
+                    Some((period, moves)) if period == current_period => moves,
+                    _ => 0,
+                };
+                ensure!(moves < max_moves, Error::<T>::TooManyMovesThisPeriod);
+                MovesInPeriod::<T>::insert(&account, (current_period, moves.saturating_add(1)));
+            }
+
+            ensure!(
+                IntegratedDApps::<T>::contains_key(&destination_contract),
+                Error::<T>::ContractNotFound
+            );
+
+            let maybe_source_dapp_info = IntegratedDApps::<T>::get(&source_contract);
+            let is_source_unregistered = maybe_source_dapp_info.is_none();
+
+            let (mut move_amount, bonus_status) = if is_source_unregistered {
+                Self::inner_unstake_from_unregistered(&account, &source_contract)?
+            } else {
+                Self::inner_unstake(&account, &source_contract, amount)?
+            };
+
+            // When bonus is forfeited, voting stake must be merged into b&e stake
+            if bonus_status == 0 && move_amount.voting > 0 {
+                move_amount.convert_bonus_into_regular_stake();
+            }
+
+            Self::inner_stake(&account, &destination_contract, move_amount, bonus_status)?;
+
+            Self::deposit_event(Event::<T>::StakeMoved {
+                account,
+                source_contract,
+                destination_contract,
+                amount: move_amount.total(),
+            });
+
+            Ok(Some(if is_source_unregistered {
+                T::WeightInfo::move_stake_unregistered_source()
+            } else {
+                T::WeightInfo::move_stake_from_registered_source()
+            })
+            .into())
+        }
+
+        /// Internal function that executes the `lock` logic for the specified account.
+        ///
+        /// If `purpose` is `None`, new accounts get the default purpose and existing accounts keep theirs.
+        fn internal_lock(
+            account: T::AccountId,
+            amount: Balance,
+            purpose: Option<LockPurpose>,
+        ) -> DispatchResultWithPostInfo {
+            let mut ledger = Ledger::<T>::get(&account);
+            Self::auto_relock_matured(&account, &mut ledger);
+
+            // Only do the check for new accounts.
+            // External logic should ensure that accounts which are already participating in dApp staking aren't
+            // allowed to participate elsewhere where they shouldn't.
+            let is_new_account = ledger.is_empty();
+            if is_new_account {
+                ensure!(
+                    T::AccountCheck::allowed_to_stake(&account),
+                    Error::<T>::AccountNotAvailableForDappStaking
+                );
+                ledger.purpose = purpose.unwrap_or_default();
+            } else if let Some(purpose) = purpose {
+                ensure!(ledger.purpose == purpose, Error::<T>::LockPurposeMismatch);
+            }
+
+            // Calculate & check amount available for locking
+            let available_balance =
+                T::Currency::total_balance(&account).saturating_sub(ledger.total_locked_amount());
+            let amount_to_lock = available_balance.min(amount);
+            ensure!(!amount_to_lock.is_zero(), Error::<T>::ZeroAmount);
+
+            if let Some(max_fraction) = T::MaxLockedIssuanceFraction::get() {
+                ensure!(
+                    CurrentEraInfo::<T>::get()
+                        .total_locked
+                        .saturating_add(amount_to_lock)
+                        <= max_fraction * T::Currency::total_issuance(),
+                    Error::<T>::GlobalLockCapReached
+                );
+            }
+
+            ledger.add_lock_amount(amount_to_lock);
+            ledger.note_fresh_lock(
+                amount_to_lock,
+                ActiveProtocolState::<T>::get().era,
+                T::LockToStakeDelayEras::get(),
+            );
+
+            ensure!(
+                ledger.active_locked_amount() >= T::MinimumLockedAmount::get(),
+                Error::<T>::LockedAmountBelowThreshold
+            );
+
+            // Account must keep enough free balance to pay the fees for e.g. unlocking later.
+            ensure!(
+                T::Currency::balance(&account).saturating_sub(ledger.total_locked_amount())
+                    >= T::PostLockMinFreeBalance::get(),
+                Error::<T>::InsufficientPostLockBalance
+            );
+
+            let purpose = ledger.purpose;
+            Self::update_ledger(&account, ledger)?;
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_locked(amount_to_lock);
+            });
+            LockedPerPurpose::<T>::mutate(purpose, |total| total.saturating_accrue(amount_to_lock));
+
+            Self::deposit_event(Event::<T>::Locked {
+                account,
+                amount: amount_to_lock,
+            });
 
-            // Update storage items
-            protocol_state.era = next_era;
-            ActiveProtocolState::<T>::put(protocol_state);
+            Ok(Some(if is_new_account {
+                T::WeightInfo::lock_new_account()
+            } else {
+                T::WeightInfo::lock_existing_account()
+            })
+            .into())
+        }
 
-            CurrentEraInfo::<T>::put(era_info);
+        /// Internal function that executes the `claim_unlocked` logic for the specified account.
+        fn internal_claim_unlocked(account: T::AccountId) -> DispatchResultWithPostInfo {
+            let mut ledger = Ledger::<T>::get(&account);
 
-            let era_span_index = Self::era_reward_span_index(current_era);
-            let mut span = EraRewards::<T>::get(&era_span_index).unwrap_or(EraRewardSpan::new());
-            if let Err(_) = span.push(current_era, era_reward) {
-                // This must never happen but we log the error just in case.
-                log::error!(
-                    target: LOG_TARGET,
-                    "Failed to push era {} into the era reward span.",
-                    current_era
-                );
+            // Matured chunks are relocked instead of being claimed, if the account opted in.
+            if !Self::auto_relock_matured(&account, &mut ledger).is_zero() {
+                Self::update_ledger(&account, ledger)?;
+                return Ok(Some(T::WeightInfo::claim_unlocked(0)).into());
             }
-            EraRewards::<T>::insert(&era_span_index, span);
-
-            // Re-calculate tier configuration for the upcoming new era
-            let tier_params = StaticTierParams::<T>::get();
-            let total_issuance = T::Currency::total_issuance();
 
-            let new_tier_config =
-                TierConfig::<T>::get().calculate_new(&tier_params, total_issuance);
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let amount = ledger.claim_unlocked(current_block.saturated_into());
+            ensure!(amount > Zero::zero(), Error::<T>::NoUnlockedChunksToClaim);
 
-            // Validate new tier configuration
-            if new_tier_config.is_valid() {
-                TierConfig::<T>::put(new_tier_config);
+            // In case it's full unlock, account is exiting dApp staking, ensure all storage is cleaned up.
+            let removed_entries = if ledger.is_empty() {
+                for (smart_contract, _) in StakerInfo::<T>::drain_prefix(&account)
+                    .take(ledger.contract_stake_count as usize)
+                {
+                    ContractStakers::<T>::remove(&smart_contract, &account);
+                }
+                Self::note_staked_contracts_change(ledger.contract_stake_count, 0);
+                ledger.contract_stake_count
             } else {
-                log::warn!(
-                    target: LOG_TARGET,
-                    "New tier configuration is invalid for era {}, preserving old one.",
-                    next_era
-                );
-            }
+                0
+            };
 
-            Self::deposit_event(Event::<T>::NewEra { era: next_era });
-            if let Some(period_event) = maybe_period_event {
-                Self::deposit_event(period_event);
-            }
+            let purpose = ledger.purpose;
+            Self::update_ledger(&account, ledger)?;
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unlocking_removed(amount);
+            });
+            LockedPerPurpose::<T>::mutate(purpose, |total| total.saturating_reduce(amount));
 
-            consumed_weight
-        }
+            Self::deposit_event(Event::<T>::ClaimedUnlocked { account, amount });
 
-        /// Used to notify observers about the upcoming new era in the next block.
-        fn notify_block_before_new_era(protocol_state: &ProtocolState) -> Weight {
-            let next_era = protocol_state.era.saturating_add(1);
-            T::Observers::block_before_new_era(next_era)
+            Ok(Some(T::WeightInfo::claim_unlocked(removed_entries)).into())
         }
 
-        /// Updates the cleanup marker with the new oldest valid era if possible.
+        /// Moves the matured unlocking chunks back to the active lock, if the account opted into auto-relocking.
         ///
-        /// It's possible that the call will be a no-op since we haven't advanced enough periods yet.
-        fn update_cleanup_marker(new_period_number: PeriodNumber) {
-            // 1. Find out the latest expired period; rewards can no longer be claimed for it or any older period.
-            let latest_expired_period = match new_period_number
-                .checked_sub(T::RewardRetentionInPeriods::get().saturating_add(1))
+        /// Nothing is relocked if the resulting active lock would be below the minimum locked amount.
+        /// The caller is responsible for storing the updated ledger. Returns the relocked amount.
+        fn auto_relock_matured(
+            account: &T::AccountId,
+            ledger: &mut AccountLedgerFor<T>,
+        ) -> Balance {
+            if !AutoRelock::<T>::get(account) {
+                return Balance::zero();
+            }
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let mut relocked_ledger = ledger.clone();
+            let amount = relocked_ledger.claim_unlocked(current_block.saturated_into());
+            relocked_ledger.add_lock_amount(amount);
+            if amount.is_zero()
+                || relocked_ledger.active_locked_amount() < T::MinimumLockedAmount::get()
             {
-                Some(period) if !period.is_zero() => period,
-                // Haven't advanced enough periods to have any expired entries.
-                _ => return,
-            };
+                return Balance::zero();
+            }
 
-            // 2. Find the oldest valid era for which rewards can still be claimed.
-            //    Technically, this will be `Voting` subperiod era but it doesn't matter.
-            //
-            //    Also, remove the expired `PeriodEnd` entry since it's no longer needed.
-            let oldest_valid_era = match PeriodEnd::<T>::take(latest_expired_period) {
-                Some(period_end_info) => period_end_info.final_era.saturating_add(1),
-                None => {
-                    // Should never happen but nothing we can do if it does.
-                    log::error!(
-                        target: LOG_TARGET,
-                        "No `PeriodEnd` entry for the expired period: {}",
-                        latest_expired_period
-                    );
-                    return;
-                }
-            };
+            *ledger = relocked_ledger;
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_locked(amount);
+                era_info.unlocking_removed(amount);
+            });
 
-            // 3. Update the cleanup marker with the new oldest valid era.
-            HistoryCleanupMarker::<T>::mutate(|marker| {
-                marker.oldest_valid_era = oldest_valid_era;
+            Self::deposit_event(Event::<T>::Relock {
+                account: account.clone(),
+                amount,
             });
+
+            amount
         }
 
-        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
+        /// Internal function that claims the dApp reward for the specified era, paying it out to the reward beneficiary.
         ///
-        /// Returns consumed weight.
-        fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
-            // Need to be able to process one full pass
-            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
-                return Weight::zero();
-            }
+        /// Returns the beneficiary and the claimed amount. Auto-compounding is left to the caller.
+        fn internal_claim_dapp_reward(
+            dapp_info: &DAppInfo<T::AccountId>,
+            smart_contract: &T::SmartContract,
+            era: EraNumber,
+            protocol_state: &ProtocolState,
+        ) -> Result<(T::AccountId, Balance), DispatchError> {
+            // Make sure provided era has ended
+            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
 
-            // Get the cleanup marker and ensure we have pending cleanups.
-            let mut cleanup_marker = HistoryCleanupMarker::<T>::get();
-            if !cleanup_marker.has_pending_cleanups() {
-                return T::DbWeight::get().reads(1);
-            }
+            // 'Consume' dApp reward for the specified era, if possible.
+            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
+            ensure!(
+                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
 
-            // 1. Attempt to cleanup one expired `EraRewards` entry.
-            if cleanup_marker.era_reward_index < cleanup_marker.oldest_valid_era {
-                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
-                    // If oldest valid era comes AFTER this span, it's safe to delete it.
-                    if era_reward.last_era() < cleanup_marker.oldest_valid_era {
-                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
-                        cleanup_marker
-                            .era_reward_index
-                            .saturating_accrue(T::EraRewardSpanLength::get());
-                    }
-                } else {
-                    // Can happen if the entry is part of history before dApp staking v3
-                    log::warn!(
-                        target: LOG_TARGET,
-                        "Era rewards span for era {} is missing, but cleanup marker is set.",
-                        cleanup_marker.era_reward_index
-                    );
-                    cleanup_marker
-                        .era_reward_index
-                        .saturating_accrue(T::EraRewardSpanLength::get());
-                }
-            }
+            let (amount, ranked_tier) =
+                dapp_tiers
+                    .try_claim(dapp_info.id)
+                    .map_err(|error| match error {
+                        DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
+                        _ => Error::<T>::InternalClaimDAppError,
+                    })?;
 
-            // 2. Attempt to cleanup one expired `DAppTiers` entry.
-            if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
-                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
-                cleanup_marker.dapp_tiers_index.saturating_inc();
-            }
+            let (tier_id, rank) = ranked_tier.deconstruct();
 
-            // Store the updated cleanup marker
-            HistoryCleanupMarker::<T>::put(cleanup_marker);
+            // Get reward destination, and deposit the reward.
+            let beneficiary = dapp_info.reward_beneficiary();
+            T::StakingRewardHandler::payout_reward(&beneficiary, amount)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            Self::record_reward_payout(amount);
+            DAppLifetimeRewards::<T>::mutate(dapp_info.id, |total| total.saturating_accrue(amount));
 
-            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
-            // we opt for the simpler solution where only 1 entry per block is cleaned up.
-            // It can be changed though.
+            // Write back updated struct to prevent double reward claims
+            DAppTiers::<T>::insert(&era, dapp_tiers);
 
-            // It could end up being less than this weight, but this won't occur often enough to be important.
-            T::WeightInfo::on_idle_cleanup()
+            Self::deposit_event(Event::<T>::DAppReward {
+                beneficiary: beneficiary.clone(),
+                smart_contract: smart_contract.clone(),
+                tier_id,
+                rank,
+                era,
+                amount,
+            });
+
+            Ok((beneficiary, amount))
         }
 
-        /// Internal function that executes the `claim_unlocked` logic for the specified account.
-        fn internal_claim_unlocked(account: T::AccountId) -> DispatchResultWithPostInfo {
-            let mut ledger = Ledger::<T>::get(&account);
+        /// Internal function that reclaims the unlocking chunks of `who` which matured at least `abandoned_blocks` ago.
+        pub(crate) fn internal_reclaim_abandoned(
+            who: T::AccountId,
+            abandoned_blocks: BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            // Only chunks which matured at least `abandoned_blocks` ago qualify.
+            let current_block: BlockNumber =
+                frame_system::Pallet::<T>::block_number().saturated_into();
+            let matured_before = current_block
+                .checked_sub(abandoned_blocks)
+                .ok_or(Error::<T>::NoAbandonedChunks)?;
 
-            let current_block = frame_system::Pallet::<T>::block_number();
-            let amount = ledger.claim_unlocked(current_block.saturated_into());
-            ensure!(amount > Zero::zero(), Error::<T>::NoUnlockedChunksToClaim);
+            let mut ledger = Ledger::<T>::get(&who);
+            let amount = ledger.claim_unlocked(matured_before);
+            ensure!(amount > Zero::zero(), Error::<T>::NoAbandonedChunks);
 
-            // In case it's full unlock, account is exiting dApp staking, ensure all storage is cleaned up.
+            // In case nothing remains, account is exiting dApp staking, ensure all storage is cleaned up.
             let removed_entries = if ledger.is_empty() {
-                let _ = StakerInfo::<T>::clear_prefix(&account, ledger.contract_stake_count, None);
+                for (smart_contract, _) in
+                    StakerInfo::<T>::drain_prefix(&who).take(ledger.contract_stake_count as usize)
+                {
+                    ContractStakers::<T>::remove(&smart_contract, &who);
+                }
+                Self::note_staked_contracts_change(ledger.contract_stake_count, 0);
                 ledger.contract_stake_count
             } else {
                 0
             };
 
-            Self::update_ledger(&account, ledger)?;
+            let purpose = ledger.purpose;
+            Self::update_ledger(&who, ledger)?;
+            let beneficiary = T::AbandonedChunkBeneficiary::get();
+            T::Currency::transfer(&who, &beneficiary, amount, Preservation::Expendable)?;
+
             CurrentEraInfo::<T>::mutate(|era_info| {
                 era_info.unlocking_removed(amount);
             });
+            LockedPerPurpose::<T>::mutate(purpose, |total| total.saturating_reduce(amount));
 
-            Self::deposit_event(Event::<T>::ClaimedUnlocked { account, amount });
+            Self::deposit_event(Event::<T>::AbandonedChunksReclaimed {
+                account: who,
+                beneficiary,
+                amount,
+            });
 
-            Ok(Some(T::WeightInfo::claim_unlocked(removed_entries)).into())
+            Ok(Some(T::WeightInfo::reclaim_abandoned(removed_entries)).into())
         }
 
         /// Internal function that executes the `claim_staker_rewards_` logic for the specified account.
-        fn internal_claim_staker_rewards_for(account: T::AccountId) -> DispatchResultWithPostInfo {
+        /// Rewards are paid out to the `destination` account if specified, otherwise to the `account` itself.
+        /// `burn_fraction` of the claimed rewards is burned right after the payout.
+        fn internal_claim_staker_rewards_for(
+            account: T::AccountId,
+            destination: Option<T::AccountId>,
+            max_claim_eras: Option<EraNumber>,
+            burn_fraction: Permill,
+        ) -> DispatchResultWithPostInfo {
             let mut ledger = Ledger::<T>::get(&account);
             let staked_period = ledger
                 .staked_period()
@@ -2325,7 +5199,15 @@ pub mod pallet {
             };
 
             // The last era for which we can claim rewards for this account.
-            let last_claim_era = era_rewards.last_era().min(last_period_era);
+            let mut last_claim_era = era_rewards.last_era().min(last_period_era);
+
+            // Limit the number of claimed eras, leaving the rest for the subsequent calls.
+            let max_claim_eras = max_claim_eras
+                .map_or(T::MaxEraRewardsPerClaim::get(), |max_claim_eras| {
+                    max_claim_eras.min(T::MaxEraRewardsPerClaim::get())
+                });
+            last_claim_era = last_claim_era
+                .min(earliest_staked_era.saturating_add(max_claim_eras.saturating_sub(1)));
 
             // Get chunks for reward claiming
             let rewards_iter =
@@ -2350,31 +5232,74 @@ pub mod pallet {
                 }
                 let staker_reward = Perbill::from_rational(amount, era_reward.staked)
                     * era_reward.staker_reward_pool;
+                let staker_reward = staker_reward.saturating_add(
+                    Self::loyalty_boost(ledger.locked_since(), era) * staker_reward,
+                );
 
                 rewards.push((era, staker_reward));
                 reward_sum.saturating_accrue(staker_reward);
             }
             let rewards_len: u32 = rewards.len().unique_saturated_into();
 
-            T::StakingRewardHandler::payout_reward(&account, reward_sum)
+            let beneficiary = destination.as_ref().unwrap_or(&account);
+            if destination.is_some() {
+                ensure!(
+                    T::Currency::total_balance(beneficiary).saturating_add(reward_sum)
+                        >= T::Currency::minimum_balance(),
+                    Error::<T>::RewardDestinationBelowMinimumBalance
+                );
+            }
+
+            T::StakingRewardHandler::payout_reward(beneficiary, reward_sum)
                 .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            Self::record_reward_payout(reward_sum);
+
+            let burned_amount = burn_fraction * reward_sum;
+            if !burned_amount.is_zero() {
+                T::Currency::burn_from(
+                    beneficiary,
+                    burned_amount,
+                    Preservation::Preserve,
+                    Precision::Exact,
+                    Fortitude::Polite,
+                )?;
+            }
 
             Self::update_ledger(&account, ledger)?;
 
             rewards.into_iter().for_each(|(era, reward)| {
-                Self::deposit_event(Event::<T>::Reward {
-                    account: account.clone(),
-                    era,
-                    amount: reward,
+                Self::deposit_event(match &destination {
+                    Some(destination) => Event::<T>::RewardTo {
+                        account: account.clone(),
+                        destination: destination.clone(),
+                        era,
+                        amount: reward,
+                    },
+                    None => Event::<T>::Reward {
+                        account: account.clone(),
+                        era,
+                        amount: reward,
+                    },
                 });
             });
 
-            Ok(Some(if period_end.is_some() {
+            let mut weight = if period_end.is_some() {
                 T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
             } else {
                 T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
-            })
-            .into())
+            };
+
+            if !burned_amount.is_zero() {
+                Self::deposit_event(Event::<T>::StakerRewardBurned {
+                    account,
+                    claimed: reward_sum,
+                    burned: burned_amount,
+                });
+                // Burning modifies the beneficiary's account & the total issuance.
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+            }
+
+            Ok(Some(weight).into())
         }
 
         /// Internal function that executes the `claim_bonus_reward` logic for the specified account & smart contract.
@@ -2420,10 +5345,16 @@ pub mod pallet {
 
             T::StakingRewardHandler::payout_reward(&account, bonus_reward)
                 .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            Self::record_reward_payout(bonus_reward);
 
             // Cleanup entry since the reward has been claimed
             StakerInfo::<T>::remove(&account, &smart_contract);
+            ContractStakers::<T>::remove(&smart_contract, &account);
             Ledger::<T>::mutate(&account, |ledger| {
+                Self::note_staked_contracts_change(
+                    ledger.contract_stake_count,
+                    ledger.contract_stake_count.saturating_sub(1),
+                );
                 ledger.contract_stake_count.saturating_dec();
             });
 
@@ -2448,6 +5379,27 @@ pub mod pallet {
             ranks_sum: u32,
             rank10_multiplier_bips: u32,
         ) -> (Balance, Balance) {
+            let (tier_base_reward0, reward_per_rank_step, _) =
+                Self::compute_tier_rewards_with_remainder(
+                    tier_allocation,
+                    tier_capacity,
+                    filled_slots,
+                    ranks_sum,
+                    rank10_multiplier_bips,
+                );
+            (tier_base_reward0, reward_per_rank_step)
+        }
+
+        /// Same as [`Self::compute_tier_rewards`], but additionally returns the remainder lost to
+        /// integer division, i.e. the difference between the share of `tier_allocation` belonging
+        /// to the occupied slots and the sum of all rewards claimable by them.
+        pub(crate) fn compute_tier_rewards_with_remainder(
+            tier_allocation: Balance,
+            tier_capacity: u16,
+            filled_slots: u32,
+            ranks_sum: u32,
+            rank10_multiplier_bips: u32,
+        ) -> (Balance, Balance, Balance) {
             const BASE_WEIGHT_BIPS: u128 = 10_000; // 100% in bips
 
             let max_rank: u128 = RankedTier::MAX_RANK as u128; // 10
@@ -2455,7 +5407,7 @@ pub mod pallet {
 
             // If there is nothing to distribute or no participants, return zero components.
             if tier_allocation.is_zero() || tier_capacity == 0 || filled_slots == 0 {
-                return (Balance::zero(), Balance::zero());
+                return (Balance::zero(), Balance::zero(), Balance::zero());
             }
 
             // Convert "Rank 10 earns X% of Rank 0" into a linear per-rank-step extra weight:
@@ -2491,7 +5443,7 @@ pub mod pallet {
             // - expected_full_weight: cap for extreme rank distributions / collisions
             let normalization_weight: u128 = observed_total_weight.max(expected_full_weight);
             if normalization_weight == 0 {
-                return (Balance::zero(), Balance::zero());
+                return (Balance::zero(), Balance::zero(), Balance::zero());
             }
 
             let alloc: u128 = tier_allocation.into();
@@ -2500,9 +5452,18 @@ pub mod pallet {
             let reward_per_rank_step_u128 =
                 alloc.saturating_mul(step_weight_bips) / normalization_weight;
 
+            // Share of the allocation belonging to the occupied slots, minus what they can actually claim.
+            let occupied_share_u128 =
+                alloc.saturating_mul(observed_total_weight) / normalization_weight;
+            let distributed_u128 = tier_base_reward0_u128
+                .saturating_mul(filled_slots as u128)
+                .saturating_add(reward_per_rank_step_u128.saturating_mul(ranks_sum as u128));
+            let remainder_u128 = occupied_share_u128.saturating_sub(distributed_u128);
+
             (
                 tier_base_reward0_u128.saturated_into::<Balance>(),
                 reward_per_rank_step_u128.saturated_into::<Balance>(),
+                remainder_u128.saturated_into::<Balance>(),
             )
         }
 
@@ -2648,6 +5609,8 @@ pub mod pallet {
         /// 3. Each staking entry in [`Ledger`] should be greater than or equal to the [`T::MinimumStakeAmount`] constant.
         /// 4. Each locking entry in [`Ledger`] should be greater than or equal to the [`T::MinimumLockedAmount`] constant.
         /// 5. The number of staking entries per account in [`Ledger`] should not exceed the [`T::MaxNumberOfStakedContracts`] constant.
+        /// 6. [`TotalStakers`] should match the number of accounts in [`Ledger`] with at least one staking entry.
+        /// 7. [`ContractStakers`] should contain exactly the entries present in [`StakerInfo`].
         #[cfg(any(feature = "try-runtime", test))]
         pub fn try_state_ledger() -> Result<(), sp_runtime::TryRuntimeError> {
             let current_period_number = ActiveProtocolState::<T>::get().period_number();
@@ -2658,6 +5621,7 @@ pub mod pallet {
             let mut ledger_total_stake = Balance::zero();
             let mut ledger_total_locked = Balance::zero();
             let mut ledger_total_unlocking = Balance::zero();
+            let mut stakers: u32 = 0;
 
             for (_, ledger) in Ledger::<T>::iter() {
                 let account_stake = ledger.staked_amount(current_period_number);
@@ -2691,6 +5655,27 @@ pub mod pallet {
                 if ledger.contract_stake_count > T::MaxNumberOfStakedContracts::get() {
                     return Err("An account exceeds the maximum number of staked contracts.".into());
                 }
+
+                if !ledger.contract_stake_count.is_zero() {
+                    stakers.saturating_inc();
+                }
+            }
+
+            // Invariant 6
+            if stakers != TotalStakers::<T>::get() {
+                return Err("Mismatch between Ledger stakers and TotalStakers.".into());
+            }
+
+            // Invariant 7
+            let mut staker_info_entries: u32 = 0;
+            for (account, smart_contract) in StakerInfo::<T>::iter_keys() {
+                if !ContractStakers::<T>::contains_key(&smart_contract, &account) {
+                    return Err("A StakerInfo entry is missing in ContractStakers.".into());
+                }
+                staker_info_entries.saturating_inc();
+            }
+            if staker_info_entries != ContractStakers::<T>::iter_keys().count() as u32 {
+                return Err("Mismatch between StakerInfo and ContractStakers entries.".into());
             }
 
             // Invariant 1
@@ -2822,4 +5807,38 @@ pub mod pallet {
             Self::set_maintenance_mode(false);
         }
     }
+
+    /// Cycle configuration used by the pallet.
+    ///
+    /// Returns the values from `ActiveCycleConfig` if set, falling back to the runtime provided `CycleConfiguration` otherwise.
+    pub struct ActiveCycleConfiguration<T>(PhantomData<T>);
+    impl<T: Config> CycleConfiguration for ActiveCycleConfiguration<T> {
+        fn periods_per_cycle() -> PeriodNumber {
+            ActiveCycleConfig::<T>::get()
+                .map_or_else(T::CycleConfiguration::periods_per_cycle, |config| {
+                    config.periods_per_cycle
+                })
+        }
+
+        fn eras_per_voting_subperiod() -> EraNumber {
+            ActiveCycleConfig::<T>::get()
+                .map_or_else(T::CycleConfiguration::eras_per_voting_subperiod, |config| {
+                    config.eras_per_voting_subperiod
+                })
+        }
+
+        fn eras_per_build_and_earn_subperiod() -> EraNumber {
+            ActiveCycleConfig::<T>::get().map_or_else(
+                T::CycleConfiguration::eras_per_build_and_earn_subperiod,
+                |config| config.eras_per_build_and_earn_subperiod,
+            )
+        }
+
+        fn blocks_per_era() -> BlockNumber {
+            ActiveCycleConfig::<T>::get()
+                .map_or_else(T::CycleConfiguration::blocks_per_era, |config| {
+                    config.blocks_per_era
+                })
+        }
+    }
 }
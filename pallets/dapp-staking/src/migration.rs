@@ -41,6 +41,682 @@ pub mod versioned_migrations {
         Pallet<T>,
         <T as frame_system::Config>::DbWeight,
     >;
+
+    /// Migration V12 to V13:
+    /// - Extend `DAppInfo` with `registered_era`
+    pub type V12ToV13<T> = frame_support::migrations::VersionedMigration<
+        12,
+        13,
+        v13::VersionMigrateV12ToV13<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V13 to V14:
+    /// - Extend `DAppTierRewards` with `remainders`
+    pub type V13ToV14<T> = frame_support::migrations::VersionedMigration<
+        13,
+        14,
+        v14::VersionMigrateV13ToV14<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V14 to V15:
+    /// - Initialize `LastStakedPeriod` for all registered dApps
+    pub type V14ToV15<T> = frame_support::migrations::VersionedMigration<
+        14,
+        15,
+        v15::VersionMigrateV14ToV15<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V15 to V16:
+    /// - Seed `TotalRewardsPaid` for the current era
+    pub type V15ToV16<T> = frame_support::migrations::VersionedMigration<
+        15,
+        16,
+        v16::VersionMigrateV15ToV16<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V16 to V17:
+    /// - Add `purpose` field to `AccountLedger`, defaulting existing locks to the zero tag
+    /// - Add `locked_since` & `fresh_lock` fields to `AccountLedger`, in the same translation
+    /// - Seed `LockedPerPurpose` for the zero tag
+    /// - Seed `TotalStakers` with the number of accounts having at least one staking entry
+    pub type V16ToV17<T> = frame_support::migrations::VersionedMigration<
+        16,
+        17,
+        v17::VersionMigrateV16ToV17<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V17 to V18:
+    /// - Seed `DAppLifetimeRewards` with zero for all registered dApps
+    pub type V17ToV18<T> = frame_support::migrations::VersionedMigration<
+        17,
+        18,
+        v18::VersionMigrateV17ToV18<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V18 to V19:
+    /// - Version bump only, `TotalStakers` is seeded by the V16 to V17 migration
+    pub type V18ToV19<T> = frame_support::migrations::VersionedMigration<
+        18,
+        19,
+        v19::VersionMigrateV18ToV19<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V19 to V20:
+    /// - Extend `DAppInfo` with `min_stake`, defaulting to `None`
+    pub type V19ToV20<T> = frame_support::migrations::VersionedMigration<
+        19,
+        20,
+        v20::VersionMigrateV19ToV20<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V20 to V21:
+    /// - Version bump only, `AccountLedger` is extended with `locked_since` by the V16 to V17 migration
+    pub type V20ToV21<T> = frame_support::migrations::VersionedMigration<
+        20,
+        21,
+        v21::VersionMigrateV20ToV21<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V21 to V22:
+    /// - Version bump only, `AccountLedger` is extended with `fresh_lock` by the V16 to V17 migration
+    pub type V21ToV22<T> = frame_support::migrations::VersionedMigration<
+        21,
+        22,
+        v22::VersionMigrateV21ToV22<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Migration V22 to V23:
+    /// - Seed `ContractStakers` index from the existing `StakerInfo` entries
+    pub type V22ToV23<T> = frame_support::migrations::VersionedMigration<
+        22,
+        23,
+        v23::VersionMigrateV22ToV23<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+mod v23 {
+    use super::*;
+
+    pub struct VersionMigrateV22ToV23<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV22ToV23<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut count: u64 = 0;
+
+            for (account, smart_contract) in StakerInfo::<T>::iter_keys() {
+                ContractStakers::<T>::insert(&smart_contract, &account, ());
+                count.saturating_inc();
+            }
+
+            log::info!(
+                target: LOG_TARGET,
+                "ContractStakers seeded with {} entries",
+                count
+            );
+
+            T::DbWeight::get().reads_writes(count, count)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((StakerInfo::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let old_count = u32::decode(&mut &data[..])
+                .map_err(|_| "Failed to decode pre-upgrade StakerInfo count")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(23),
+                "Storage version should be 23"
+            );
+            ensure!(
+                ContractStakers::<T>::iter_keys().count() as u32 == old_count,
+                "ContractStakers entry count must match StakerInfo"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v22 {
+    use super::*;
+
+    pub struct VersionMigrateV21ToV22<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV21ToV22<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // `fresh_lock` is already added to all ledgers by the single `Ledger` translation in v17.
+            Weight::zero()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(22),
+                "Storage version should be 22"
+            );
+            ensure!(
+                Ledger::<T>::iter_values().all(|ledger| ledger.fresh_lock.is_none()),
+                "All of the existing locks must be treated as aged"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v21 {
+    use super::*;
+
+    pub struct VersionMigrateV20ToV21<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV20ToV21<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // `locked_since` is already added to all ledgers by the single `Ledger` translation in v17.
+            Weight::zero()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(21),
+                "Storage version should be 21"
+            );
+            ensure!(
+                Ledger::<T>::iter_values()
+                    .all(|ledger| ledger.locked.is_zero() == ledger.locked_since.is_none()),
+                "Ledger lock streak must be set exactly for accounts with active locked funds"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v20 {
+    use super::*;
+
+    /// Old `DAppInfo` shape (without `min_stake` field).
+    #[derive(Encode, Decode, Clone)]
+    pub struct OldDAppInfo<AccountId> {
+        pub owner: AccountId,
+        #[codec(compact)]
+        pub id: DAppId,
+        pub reward_beneficiary: Option<AccountId>,
+        #[codec(compact)]
+        pub registered_era: EraNumber,
+    }
+
+    pub struct VersionMigrateV19ToV20<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV19ToV20<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            IntegratedDApps::<T>::translate::<OldDAppInfo<T::AccountId>, _>(|_, old_dapp_info| {
+                translated.saturating_inc();
+                Some(DAppInfo {
+                    owner: old_dapp_info.owner,
+                    id: old_dapp_info.id,
+                    reward_beneficiary: old_dapp_info.reward_beneficiary,
+                    registered_era: old_dapp_info.registered_era,
+                    min_stake: None,
+                })
+            });
+
+            log::info!(
+                target: LOG_TARGET,
+                "IntegratedDApps migrated to v20 successfully, {} entries translated",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(IntegratedDApps::<T>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let old_count = u32::decode(&mut &data[..])
+                .map_err(|_| "Failed to decode pre-upgrade IntegratedDApps count")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(20),
+                "Storage version should be 20"
+            );
+            ensure!(
+                IntegratedDApps::<T>::iter().count() as u32 == old_count,
+                "IntegratedDApps entry count mismatch after migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v19 {
+    use super::*;
+
+    pub struct VersionMigrateV18ToV19<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV18ToV19<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // `TotalStakers` is already seeded by the single `Ledger` translation in v17.
+            Weight::zero()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(19),
+                "Storage version should be 19"
+            );
+            ensure!(
+                Ledger::<T>::iter_values()
+                    .filter(|ledger| !ledger.contract_stake_count.is_zero())
+                    .count() as u32
+                    == TotalStakers::<T>::get(),
+                "TotalStakers must match the number of stakers"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v18 {
+    use super::*;
+
+    /// `IntegratedDApps` as it was at v18, before `DAppInfo` got the `min_stake` field.
+    #[frame_support::storage_alias]
+    type IntegratedDApps<T: Config> = CountedStorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::SmartContract,
+        v20::OldDAppInfo<<T as frame_system::Config>::AccountId>,
+    >;
+
+    pub struct VersionMigrateV17ToV18<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV17ToV18<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut count: u64 = 0;
+
+            // Rewards claimed before the upgrade aren't known, so tracking starts from zero.
+            for dapp_info in IntegratedDApps::<T>::iter_values() {
+                count.saturating_inc();
+                DAppLifetimeRewards::<T>::insert(dapp_info.id, Balance::zero());
+            }
+
+            log::info!(
+                target: LOG_TARGET,
+                "DAppLifetimeRewards seeded for {} dApps",
+                count
+            );
+
+            T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(18),
+                "Storage version should be 18"
+            );
+            ensure!(
+                IntegratedDApps::<T>::iter_values()
+                    .all(|dapp_info| DAppLifetimeRewards::<T>::contains_key(dapp_info.id)),
+                "DAppLifetimeRewards must exist for all registered dApps"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v17 {
+    use super::*;
+
+    /// Old `AccountLedger` shape (without `purpose`, `locked_since` & `fresh_lock` fields).
+    #[derive(Encode, Decode, Clone)]
+    pub struct OldAccountLedger<UnlockingLen: Get<u32>> {
+        #[codec(compact)]
+        pub locked: Balance,
+        pub unlocking: BoundedVec<UnlockingChunk, UnlockingLen>,
+        pub staked: StakeAmount,
+        pub staked_future: Option<StakeAmount>,
+        #[codec(compact)]
+        pub contract_stake_count: u32,
+    }
+
+    pub struct VersionMigrateV16ToV17<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV16ToV17<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_era = ActiveProtocolState::<T>::get().era;
+            let mut translated: u64 = 0;
+            let mut total_locked: Balance = 0;
+            let mut stakers: u32 = 0;
+
+            // All of the `AccountLedger` fields added up to v22 are set here, so ledgers are only translated once.
+            // Lock duration prior to the upgrade is unknown, so the streak starts from the current era,
+            // while all of the existing locks are treated as aged.
+            Ledger::<T>::translate::<OldAccountLedger<T::MaxUnlockingChunks>, _>(
+                |_, old_ledger| {
+                    translated.saturating_inc();
+                    total_locked.saturating_accrue(
+                        old_ledger
+                            .unlocking
+                            .iter()
+                            .fold(old_ledger.locked, |sum, chunk| {
+                                sum.saturating_add(chunk.amount)
+                            }),
+                    );
+                    if !old_ledger.contract_stake_count.is_zero() {
+                        stakers.saturating_inc();
+                    }
+                    Some(AccountLedger {
+                        locked_since: (!old_ledger.locked.is_zero()).then_some(current_era),
+                        locked: old_ledger.locked,
+                        unlocking: old_ledger.unlocking,
+                        staked: old_ledger.staked,
+                        staked_future: old_ledger.staked_future,
+                        contract_stake_count: old_ledger.contract_stake_count,
+                        purpose: LockPurpose::default(),
+                        fresh_lock: None,
+                    })
+                },
+            );
+            LockedPerPurpose::<T>::insert(LockPurpose::default(), total_locked);
+            TotalStakers::<T>::put(stakers);
+
+            log::info!(
+                target: LOG_TARGET,
+                "Ledger migrated to v17 successfully, {} entries translated, {} stakers",
+                translated,
+                stakers
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Ledger::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let old_count = u32::decode(&mut &data[..])
+                .map_err(|_| "Failed to decode pre-upgrade Ledger count")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(17),
+                "Storage version should be 17"
+            );
+            ensure!(
+                Ledger::<T>::iter().count() as u32 == old_count,
+                "Ledger entry count mismatch after migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v16 {
+    use super::*;
+
+    pub struct VersionMigrateV15ToV16<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV15ToV16<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_era = ActiveProtocolState::<T>::get().era;
+            TotalRewardsPaid::<T>::insert(current_era, Balance::zero());
+
+            log::info!(
+                target: LOG_TARGET,
+                "TotalRewardsPaid seeded for era {}",
+                current_era
+            );
+
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(16),
+                "Storage version should be 16"
+            );
+            ensure!(
+                TotalRewardsPaid::<T>::contains_key(ActiveProtocolState::<T>::get().era),
+                "TotalRewardsPaid must be seeded for the current era"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v15 {
+    use super::*;
+
+    /// `IntegratedDApps` read with the v13 `DAppInfo` shape, which was still in use at v15.
+    #[frame_support::storage_alias]
+    type IntegratedDApps<T: Config> = CountedStorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::SmartContract,
+        v20::OldDAppInfo<<T as frame_system::Config>::AccountId>,
+    >;
+
+    pub struct VersionMigrateV14ToV15<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV14ToV15<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+            let mut count: u64 = 0;
+
+            // Stake history isn't fully known for existing dApps, so the inactivity window
+            // starts with the current period for all of them.
+            for (_, dapp_info) in IntegratedDApps::<T>::iter() {
+                count.saturating_inc();
+                LastStakedPeriod::<T>::insert(dapp_info.id, current_period);
+            }
+
+            log::info!(
+                target: LOG_TARGET,
+                "LastStakedPeriod initialized for {} dApps",
+                count
+            );
+
+            T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(15),
+                "Storage version should be 15"
+            );
+            ensure!(
+                IntegratedDApps::<T>::iter_values()
+                    .all(|dapp_info| LastStakedPeriod::<T>::contains_key(dapp_info.id)),
+                "LastStakedPeriod must exist for all registered dApps"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v14 {
+    use super::*;
+    use frame_support::BoundedBTreeMap;
+
+    /// Old `DAppTierRewards` shape (without `remainders` field).
+    #[derive(Encode, Decode, Clone)]
+    pub struct OldDAppTierRewards<MD: Get<u32>, NT: Get<u32>> {
+        pub dapps: BoundedBTreeMap<DAppId, RankedTier, MD>,
+        pub rewards: BoundedVec<Balance, NT>,
+        #[codec(compact)]
+        pub period: PeriodNumber,
+        pub rank_rewards: BoundedVec<Balance, NT>,
+    }
+
+    pub struct VersionMigrateV13ToV14<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV13ToV14<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            DAppTiers::<T>::translate::<
+                OldDAppTierRewards<T::MaxNumberOfContracts, T::NumberOfTiers>,
+                _,
+            >(|_, old_dapp_tiers| {
+                translated.saturating_inc();
+                Some(DAppTierRewards {
+                    dapps: old_dapp_tiers.dapps,
+                    rewards: old_dapp_tiers.rewards,
+                    period: old_dapp_tiers.period,
+                    rank_rewards: old_dapp_tiers.rank_rewards,
+                    remainders: Default::default(),
+                })
+            });
+
+            log::info!(
+                target: LOG_TARGET,
+                "DAppTiers migrated to v14 successfully, {} entries translated",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((DAppTiers::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let old_count = u32::decode(&mut &data[..])
+                .map_err(|_| "Failed to decode pre-upgrade DAppTiers count")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(14),
+                "Storage version should be 14"
+            );
+            ensure!(
+                DAppTiers::<T>::iter().count() as u32 == old_count,
+                "DAppTiers entry count mismatch after migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v13 {
+    use super::*;
+
+    /// Old `DAppInfo` shape (without `registered_era` field).
+    #[derive(Encode, Decode, Clone)]
+    pub struct OldDAppInfo<AccountId> {
+        pub owner: AccountId,
+        #[codec(compact)]
+        pub id: DAppId,
+        pub reward_beneficiary: Option<AccountId>,
+    }
+
+    /// `IntegratedDApps` with the `DAppInfo` shape introduced in v13, later extended in v20.
+    #[frame_support::storage_alias]
+    type IntegratedDApps<T: Config> = CountedStorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::SmartContract,
+        v20::OldDAppInfo<<T as frame_system::Config>::AccountId>,
+    >;
+
+    pub struct VersionMigrateV12ToV13<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV12ToV13<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Registration era of existing dApps is unknown, so it's set to zero.
+            // This means `MinRegistrationEras` restriction doesn't apply to them.
+            IntegratedDApps::<T>::translate::<OldDAppInfo<T::AccountId>, _>(|_, old_dapp_info| {
+                translated.saturating_inc();
+                Some(v20::OldDAppInfo {
+                    owner: old_dapp_info.owner,
+                    id: old_dapp_info.id,
+                    reward_beneficiary: old_dapp_info.reward_beneficiary,
+                    registered_era: 0,
+                })
+            });
+
+            log::info!(
+                target: LOG_TARGET,
+                "IntegratedDApps migrated to v13 successfully, {} entries translated",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(IntegratedDApps::<T>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let old_count = u32::decode(&mut &data[..])
+                .map_err(|_| "Failed to decode pre-upgrade IntegratedDApps count")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(13),
+                "Storage version should be 13"
+            );
+            ensure!(
+                IntegratedDApps::<T>::iter().count() as u32 == old_count,
+                "IntegratedDApps entry count mismatch after migration"
+            );
+
+            Ok(())
+        }
+    }
 }
 
 mod v12 {
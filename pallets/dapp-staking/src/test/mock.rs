@@ -34,7 +34,7 @@ use sp_runtime::{BuildStorage, Permill};
 use sp_std::cell::RefCell;
 
 use astar_primitives::{
-    dapp_staking::{Observer as DappStakingObserver, SmartContract},
+    dapp_staking::{Observer as DappStakingObserver, SmartContract, UnlockingPeriodFn},
     Balance, BlockNumber,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
@@ -93,13 +93,40 @@ impl pallet_migrations::Config for Test {
 
 thread_local! {
     pub(crate) static DOES_PAYOUT_SUCCEED: RefCell<bool> = RefCell::new(false);
+    pub(crate) static IS_REWARD_POOL_EMPTY: RefCell<bool> = RefCell::new(false);
     pub(crate) static BLOCK_BEFORE_NEW_ERA: RefCell<EraNumber> = RefCell::new(0);
     pub(crate) static MAX_BONUS_SAFE_MOVES: RefCell<u8> = RefCell::new(0);
+    pub(crate) static MIN_REGISTRATION_ERAS: RefCell<EraNumber> = RefCell::new(0);
+    pub(crate) static TIER_REWARD_ROUNDING: RefCell<RewardRounding> = RefCell::new(RewardRounding::Truncate);
+    pub(crate) static KEEPER_CLAIM_WINDOW: RefCell<EraNumber> = RefCell::new(EraNumber::MAX);
+    pub(crate) static MAX_STAKE_PER_CONTRACT: RefCell<Option<Balance>> = RefCell::new(None);
+    pub(crate) static SCALED_UNLOCKING_PERIOD: RefCell<bool> = RefCell::new(false);
+    pub(crate) static TOP_TIER_BOOST: RefCell<Permill> = RefCell::new(Permill::zero());
+    pub(crate) static LOCK_UNLOCK_DURING_VOTING: RefCell<bool> = RefCell::new(true);
+    pub(crate) static ABANDONED_CHUNK_ERAS: RefCell<Option<EraNumber>> = RefCell::new(None);
+    pub(crate) static MAX_MOVES_PER_PERIOD: RefCell<Option<u32>> = RefCell::new(None);
+    pub(crate) static FINAL_ERA_STAKE_GRACE: RefCell<Balance> = RefCell::new(0);
+    pub(crate) static STAKE_INCREMENT: RefCell<Option<Balance>> = RefCell::new(None);
+    pub(crate) static REWARD_POOL_TOP_UP: RefCell<Balance> = RefCell::new(0);
+    pub(crate) static MAX_TOTAL_STAKERS: RefCell<Option<u32>> = RefCell::new(None);
+    pub(crate) static LOYALTY_BOOST: RefCell<Option<(EraNumber, Permill)>> = RefCell::new(None);
+    pub(crate) static POST_LOCK_MIN_FREE_BALANCE: RefCell<Balance> = RefCell::new(0);
+    pub(crate) static MIN_SUBPERIOD_FORCE_INTERVAL: RefCell<EraNumber> = RefCell::new(0);
+    pub(crate) static THRESHOLD_SMOOTHING: RefCell<Option<Permill>> = RefCell::new(None);
+    pub(crate) static MAX_LOCKED_ISSUANCE_FRACTION: RefCell<Option<Permill>> = RefCell::new(None);
+    pub(crate) static LOCK_TO_STAKE_DELAY_ERAS: RefCell<EraNumber> = RefCell::new(0);
+    pub(crate) static OPTED_OUT_REWARD_HANDLING: RefCell<OptOutRewardHandling> = RefCell::new(OptOutRewardHandling::Redistribute);
+    pub(crate) static MAX_ERA_REWARDS_PER_CLAIM: RefCell<u32> = RefCell::new(u32::MAX);
+    pub(crate) static INACTIVITY_UNREGISTER_PERIODS: RefCell<Option<PeriodNumber>> = RefCell::new(Some(2));
 }
 
 pub struct DummyStakingRewardHandler;
 impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
     fn staker_and_dapp_reward_pools(_total_staked_value: Balance) -> (Balance, Balance) {
+        if IS_REWARD_POOL_EMPTY.with(|v| v.borrow().clone()) {
+            return (0, 0);
+        }
+
         (
             Balance::from(1_000_000_000_000_u128),
             Balance::from(1_000_000_000_u128),
@@ -118,6 +145,10 @@ impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
             Err(())
         }
     }
+
+    fn take_reward_pool_top_up() -> Balance {
+        REWARD_POOL_TOP_UP.with(|v| core::mem::take(&mut *v.borrow_mut()))
+    }
 }
 
 pub(crate) type MockSmartContract = SmartContract<AccountId>;
@@ -129,7 +160,8 @@ impl crate::BenchmarkHelper<MockSmartContract, AccountId>
     for BenchmarkHelper<MockSmartContract, AccountId>
 {
     fn get_smart_contract(id: u32) -> MockSmartContract {
-        MockSmartContract::wasm(id as AccountId)
+        // Zero address isn't a valid smart contract
+        MockSmartContract::wasm(id as AccountId + 1)
     }
 
     fn set_balance(account: &AccountId, amount: Balance) {
@@ -181,12 +213,171 @@ impl Get<u8> for DynamicMaxBonusSafeMovesPerPeriod {
     }
 }
 
+pub struct DynamicMinRegistrationEras;
+impl Get<EraNumber> for DynamicMinRegistrationEras {
+    fn get() -> EraNumber {
+        MIN_REGISTRATION_ERAS.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicKeeperClaimWindow;
+impl Get<EraNumber> for DynamicKeeperClaimWindow {
+    fn get() -> EraNumber {
+        KEEPER_CLAIM_WINDOW.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicMaxStakePerContract;
+impl Get<Option<Balance>> for DynamicMaxStakePerContract {
+    fn get() -> Option<Balance> {
+        MAX_STAKE_PER_CONTRACT.with(|v| *v.borrow())
+    }
+}
+
+/// Amount unlocked per each additional standard unlocking period, when scaling is enabled.
+pub(crate) const UNLOCKING_PERIOD_SCALING_STEP: Balance = 100;
+
+pub struct DynamicUnlockingPeriodFn;
+impl UnlockingPeriodFn for DynamicUnlockingPeriodFn {
+    fn unlocking_period(amount: Balance, unlocking_period: BlockNumber) -> BlockNumber {
+        if SCALED_UNLOCKING_PERIOD.with(|v| *v.borrow()) {
+            let multiplier = (amount / UNLOCKING_PERIOD_SCALING_STEP).saturating_add(1);
+            unlocking_period.saturating_mul(multiplier.saturated_into())
+        } else {
+            unlocking_period
+        }
+    }
+}
+
+pub struct DynamicTierRewardRounding;
+impl Get<RewardRounding> for DynamicTierRewardRounding {
+    fn get() -> RewardRounding {
+        TIER_REWARD_ROUNDING.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicTopTierBoost;
+impl Get<Permill> for DynamicTopTierBoost {
+    fn get() -> Permill {
+        TOP_TIER_BOOST.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicLockUnlockDuringVoting;
+impl Get<bool> for DynamicLockUnlockDuringVoting {
+    fn get() -> bool {
+        LOCK_UNLOCK_DURING_VOTING.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicAbandonedChunkEras;
+impl Get<Option<EraNumber>> for DynamicAbandonedChunkEras {
+    fn get() -> Option<EraNumber> {
+        ABANDONED_CHUNK_ERAS.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicMaxMovesPerPeriod;
+impl Get<Option<u32>> for DynamicMaxMovesPerPeriod {
+    fn get() -> Option<u32> {
+        MAX_MOVES_PER_PERIOD.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicFinalEraStakeGrace;
+impl Get<Balance> for DynamicFinalEraStakeGrace {
+    fn get() -> Balance {
+        FINAL_ERA_STAKE_GRACE.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicStakeIncrement;
+impl Get<Option<Balance>> for DynamicStakeIncrement {
+    fn get() -> Option<Balance> {
+        STAKE_INCREMENT.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicMaxTotalStakers;
+impl Get<Option<u32>> for DynamicMaxTotalStakers {
+    fn get() -> Option<u32> {
+        MAX_TOTAL_STAKERS.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicLoyaltyBoost;
+impl Get<Option<(EraNumber, Permill)>> for DynamicLoyaltyBoost {
+    fn get() -> Option<(EraNumber, Permill)> {
+        LOYALTY_BOOST.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicPostLockMinFreeBalance;
+impl Get<Balance> for DynamicPostLockMinFreeBalance {
+    fn get() -> Balance {
+        POST_LOCK_MIN_FREE_BALANCE.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicMinSubperiodForceInterval;
+impl Get<EraNumber> for DynamicMinSubperiodForceInterval {
+    fn get() -> EraNumber {
+        MIN_SUBPERIOD_FORCE_INTERVAL.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicThresholdSmoothing;
+impl Get<Option<Permill>> for DynamicThresholdSmoothing {
+    fn get() -> Option<Permill> {
+        THRESHOLD_SMOOTHING.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicMaxLockedIssuanceFraction;
+impl Get<Option<Permill>> for DynamicMaxLockedIssuanceFraction {
+    fn get() -> Option<Permill> {
+        MAX_LOCKED_ISSUANCE_FRACTION.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicLockToStakeDelayEras;
+impl Get<EraNumber> for DynamicLockToStakeDelayEras {
+    fn get() -> EraNumber {
+        LOCK_TO_STAKE_DELAY_ERAS.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicOptedOutRewardHandling;
+impl Get<OptOutRewardHandling> for DynamicOptedOutRewardHandling {
+    fn get() -> OptOutRewardHandling {
+        OPTED_OUT_REWARD_HANDLING.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicMaxEraRewardsPerClaim;
+impl Get<u32> for DynamicMaxEraRewardsPerClaim {
+    fn get() -> u32 {
+        MAX_ERA_REWARDS_PER_CLAIM.with(|v| *v.borrow())
+    }
+}
+
+pub struct DynamicInactivityUnregisterPeriods;
+impl Get<Option<PeriodNumber>> for DynamicInactivityUnregisterPeriods {
+    fn get() -> Option<PeriodNumber> {
+        INACTIVITY_UNREGISTER_PERIODS.with(|v| *v.borrow())
+    }
+}
+
 ord_parameter_types! {
     pub const ContractRegisterAccount: AccountId = 1337;
     pub const ContractUnregisterAccount: AccountId = 1779;
     pub const ManagerAccount: AccountId = 25711;
 }
 
+parameter_types! {
+    pub const AbandonedChunkBeneficiary: AccountId = 7331;
+}
+
 impl pallet_dapp_staking::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -210,11 +401,36 @@ impl pallet_dapp_staking::Config for Test {
     type MaxUnlockingChunks = ConstU32<5>;
     type MinimumLockedAmount = ConstU128<MINIMUM_LOCK_AMOUNT>;
     type UnlockingPeriod = ConstU32<2>;
+    type UnlockingPeriodFn = DynamicUnlockingPeriodFn;
     type MaxNumberOfStakedContracts = ConstU32<5>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierRewardRounding = DynamicTierRewardRounding;
+    type TopTierBoost = DynamicTopTierBoost;
     type MaxBonusSafeMovesPerPeriod = DynamicMaxBonusSafeMovesPerPeriod;
+    type MinRegistrationEras = DynamicMinRegistrationEras;
+    type InactivityUnregisterPeriods = DynamicInactivityUnregisterPeriods;
+    type KeeperClaimWindow = DynamicKeeperClaimWindow;
+    type MaxStakePerContract = DynamicMaxStakePerContract;
+    type MaxClaimRemindersPerEra = ConstU32<3>;
+    type LockUnlockDuringVoting = DynamicLockUnlockDuringVoting;
+    type AbandonedChunkEras = DynamicAbandonedChunkEras;
+    type AbandonedChunkBeneficiary = AbandonedChunkBeneficiary;
+    type MaxDAppClaimBatch = ConstU32<4>;
+    type MaxMovesPerPeriod = DynamicMaxMovesPerPeriod;
+    type FinalEraStakeGrace = DynamicFinalEraStakeGrace;
+    type StakeIncrement = DynamicStakeIncrement;
+    type MaxTotalStakers = DynamicMaxTotalStakers;
+    type MaxAutoClaimsPerBlock = ConstU32<2>;
+    type LoyaltyBoost = DynamicLoyaltyBoost;
+    type PostLockMinFreeBalance = DynamicPostLockMinFreeBalance;
+    type MinSubperiodForceInterval = DynamicMinSubperiodForceInterval;
+    type ThresholdSmoothing = DynamicThresholdSmoothing;
+    type MaxLockedIssuanceFraction = DynamicMaxLockedIssuanceFraction;
+    type LockToStakeDelayEras = DynamicLockToStakeDelayEras;
+    type OptedOutRewardHandling = DynamicOptedOutRewardHandling;
+    type MaxEraRewardsPerClaim = DynamicMaxEraRewardsPerClaim;
     type WeightInfo = weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -232,6 +448,7 @@ impl ExtBuilder {
     pub fn build(self) -> TestExternalities {
         // Normal behavior is for reward payout to succeed
         DOES_PAYOUT_SUCCEED.with(|v| *v.borrow_mut() = true);
+        IS_REWARD_POOL_EMPTY.with(|v| *v.borrow_mut() = false);
 
         let mut storage = frame_system::GenesisConfig::<Test>::default()
             .build_storage()
@@ -358,6 +575,111 @@ impl ExtBuilder {
         MAX_BONUS_SAFE_MOVES.with(|v| *v.borrow_mut() = value);
         self
     }
+
+    pub fn with_min_registration_eras(self, value: EraNumber) -> Self {
+        MIN_REGISTRATION_ERAS.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_tier_reward_rounding(self, value: RewardRounding) -> Self {
+        TIER_REWARD_ROUNDING.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_keeper_claim_window(self, value: EraNumber) -> Self {
+        KEEPER_CLAIM_WINDOW.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_max_stake_per_contract(self, value: Option<Balance>) -> Self {
+        MAX_STAKE_PER_CONTRACT.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_scaled_unlocking_period(self) -> Self {
+        SCALED_UNLOCKING_PERIOD.with(|v| *v.borrow_mut() = true);
+        self
+    }
+
+    pub fn with_top_tier_boost(self, value: Permill) -> Self {
+        TOP_TIER_BOOST.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_lock_unlock_during_voting(self, value: bool) -> Self {
+        LOCK_UNLOCK_DURING_VOTING.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_abandoned_chunk_eras(self, value: Option<EraNumber>) -> Self {
+        ABANDONED_CHUNK_ERAS.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_max_moves_per_period(self, value: Option<u32>) -> Self {
+        MAX_MOVES_PER_PERIOD.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_final_era_stake_grace(self, value: Balance) -> Self {
+        FINAL_ERA_STAKE_GRACE.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_stake_increment(self, value: Option<Balance>) -> Self {
+        STAKE_INCREMENT.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_max_total_stakers(self, value: Option<u32>) -> Self {
+        MAX_TOTAL_STAKERS.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_loyalty_boost(self, value: Option<(EraNumber, Permill)>) -> Self {
+        LOYALTY_BOOST.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_post_lock_min_free_balance(self, value: Balance) -> Self {
+        POST_LOCK_MIN_FREE_BALANCE.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_min_subperiod_force_interval(self, value: EraNumber) -> Self {
+        MIN_SUBPERIOD_FORCE_INTERVAL.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_threshold_smoothing(self, value: Option<Permill>) -> Self {
+        THRESHOLD_SMOOTHING.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_max_locked_issuance_fraction(self, value: Option<Permill>) -> Self {
+        MAX_LOCKED_ISSUANCE_FRACTION.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_lock_to_stake_delay_eras(self, value: EraNumber) -> Self {
+        LOCK_TO_STAKE_DELAY_ERAS.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_opted_out_reward_handling(self, value: OptOutRewardHandling) -> Self {
+        OPTED_OUT_REWARD_HANDLING.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_max_era_rewards_per_claim(self, value: u32) -> Self {
+        MAX_ERA_REWARDS_PER_CLAIM.with(|v| *v.borrow_mut() = value);
+        self
+    }
+
+    pub fn with_inactivity_unregister_periods(self, value: Option<PeriodNumber>) -> Self {
+        INACTIVITY_UNREGISTER_PERIODS.with(|v| *v.borrow_mut() = value);
+        self
+    }
 }
 
 /// Run to the specified block number.
@@ -19,9 +19,10 @@
 use crate::test::mock::*;
 use crate::types::*;
 use crate::{
-    pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppTiers,
-    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, NextDAppId,
-    PeriodEnd, PeriodEndInfo, StakerInfo,
+    pallet::Config, ActiveCycleConfiguration, ActiveProtocolState, AutoRelock, ClaimReminderEra,
+    ContractStake, CurrentEraInfo, DAppId, DAppTiers, EraRewards, Event, FreezeReason,
+    HistoryCleanupMarker, IntegratedDApps, Ledger, MovesInPeriod, NextDAppId, PeriodEnd,
+    PeriodEndInfo, PositionTransferApprovals, StakeDelegate, StakerInfo,
 };
 
 use frame_support::{
@@ -115,6 +116,10 @@ pub(crate) fn assert_register(owner: AccountId, smart_contract: &MockSmartContra
     assert_eq!(dapp_info.owner, owner);
     assert_eq!(dapp_info.id, pre_snapshot.next_dapp_id);
     assert!(dapp_info.reward_beneficiary.is_none());
+    assert_eq!(
+        dapp_info.registered_era,
+        pre_snapshot.active_protocol_state.era
+    );
 
     assert_eq!(pre_snapshot.next_dapp_id + 1, NextDAppId::<Test>::get());
     assert_eq!(
@@ -339,6 +344,83 @@ pub(crate) fn assert_unlock(account: AccountId, amount: Balance) {
     );
 }
 
+/// Transfer the entire staking position of `source` to `dest` and assert success.
+pub(crate) fn assert_transfer_position(source: AccountId, dest: AccountId) {
+    let pre_snapshot = MemorySnapshot::new();
+    let pre_source_ledger = pre_snapshot.ledger[&source].clone();
+    let pre_source_balance = Balances::total_balance(&source);
+    let pre_dest_balance = Balances::total_balance(&dest);
+    let locked_amount = pre_source_ledger.total_locked_amount();
+    let pre_source_auto_relock = AutoRelock::<Test>::get(&source);
+    let pre_dest_auto_relock = AutoRelock::<Test>::get(&dest);
+
+    assert_ok!(DappStaking::transfer_position(
+        RuntimeOrigin::signed(source),
+        dest
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::PositionTransferred {
+        source,
+        dest,
+    }));
+
+    // Verify post-state
+    let post_snapshot = MemorySnapshot::new();
+
+    // Ledger & locked funds are moved over to the destination
+    assert!(!post_snapshot.ledger.contains_key(&source));
+    assert_eq!(post_snapshot.ledger[&dest], pre_source_ledger);
+    assert!(Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &source).is_zero());
+    assert_eq!(
+        Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &dest),
+        locked_amount
+    );
+    assert_eq!(
+        Balances::total_balance(&source),
+        pre_source_balance - locked_amount
+    );
+    assert_eq!(
+        Balances::total_balance(&dest),
+        pre_dest_balance + locked_amount
+    );
+
+    // All staker info entries are moved over to the destination
+    let pre_source_staker_info: HashMap<_, _> = pre_snapshot
+        .staker_info
+        .iter()
+        .filter(|((account, _), _)| *account == source)
+        .map(|((_, smart_contract), info)| (*smart_contract, info.clone()))
+        .collect();
+    let post_dest_staker_info: HashMap<_, _> = post_snapshot
+        .staker_info
+        .iter()
+        .filter(|((account, _), _)| *account == dest)
+        .map(|((_, smart_contract), info)| (*smart_contract, info.clone()))
+        .collect();
+    assert!(post_snapshot
+        .staker_info
+        .keys()
+        .all(|(account, _)| *account != source));
+    assert_eq!(pre_source_staker_info, post_dest_staker_info);
+
+    // Per-account state is carried over to the destination or cleared
+    assert_eq!(
+        AutoRelock::<Test>::get(&dest),
+        pre_source_auto_relock || pre_dest_auto_relock
+    );
+    assert!(!AutoRelock::<Test>::contains_key(&source));
+    assert!(!MovesInPeriod::<Test>::contains_key(&source));
+    assert!(!StakeDelegate::<Test>::contains_key(&source));
+    assert!(!ClaimReminderEra::<Test>::contains_key(&source));
+
+    // Protocol-wide totals remain unchanged
+    assert_eq!(pre_snapshot.contract_stake, post_snapshot.contract_stake);
+    assert_eq!(
+        pre_snapshot.current_era_info,
+        post_snapshot.current_era_info
+    );
+    assert!(!PositionTransferApprovals::<Test>::contains_key(&dest));
+}
+
 /// Claims the unlocked funds back into free balance of the user and assert success.
 pub(crate) fn assert_claim_unlocked(account: AccountId) {
     let pre_snapshot = MemorySnapshot::new();
@@ -1023,6 +1105,15 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
         (last_claim_era, is_full_claim)
     };
 
+    // Number of claimed eras is limited by the `MaxEraRewardsPerClaim` cap.
+    let max_claim_eras: EraNumber = <Test as Config>::MaxEraRewardsPerClaim::get();
+    let capped_claim_era = first_claim_era.saturating_add(max_claim_eras - 1);
+    let (last_claim_era, is_full_claim) = if capped_claim_era < last_claim_era {
+        (capped_claim_era, false)
+    } else {
+        (last_claim_era, is_full_claim)
+    };
+
     assert!(
         last_claim_era < pre_snapshot.active_protocol_state.era,
         "Sanity check."
@@ -1041,6 +1132,7 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
 
         let reward = Perbill::from_rational(amount, era_reward_info.staked)
             * era_reward_info.staker_reward_pool;
+        let reward = reward + DappStaking::loyalty_boost(pre_ledger.locked_since(), era) * reward;
         if reward.is_zero() {
             continue;
         }
@@ -1421,14 +1513,14 @@ pub(crate) fn assert_block_bump(pre_snapshot: &MemorySnapshot) {
             );
 
             let eras_per_bep =
-                <Test as Config>::CycleConfiguration::eras_per_build_and_earn_subperiod();
+                ActiveCycleConfiguration::<Test>::eras_per_build_and_earn_subperiod();
             assert_eq!(
                 post_protoc_state.period_info.next_subperiod_start_era,
                 post_protoc_state.era + eras_per_bep,
                 "Build&earn must last for the predefined amount of standard eras."
             );
 
-            let standard_era_length = <Test as Config>::CycleConfiguration::blocks_per_era();
+            let standard_era_length = ActiveCycleConfiguration::<Test>::blocks_per_era();
             assert_eq!(
                 post_protoc_state.next_era_start,
                 current_block_number + standard_era_length,
@@ -1453,10 +1545,9 @@ pub(crate) fn assert_block_bump(pre_snapshot: &MemorySnapshot) {
                     "Voting era must last for a single era."
                 );
 
-                let blocks_per_standard_era =
-                    <Test as Config>::CycleConfiguration::blocks_per_era();
+                let blocks_per_standard_era = ActiveCycleConfiguration::<Test>::blocks_per_era();
                 let eras_per_voting_subperiod =
-                    <Test as Config>::CycleConfiguration::eras_per_voting_subperiod();
+                    ActiveCycleConfiguration::<Test>::eras_per_voting_subperiod();
                 let eras_per_voting_subperiod: BlockNumber = eras_per_voting_subperiod.into();
                 let era_length: BlockNumber = blocks_per_standard_era * eras_per_voting_subperiod;
                 assert_eq!(
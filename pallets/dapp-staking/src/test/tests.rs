@@ -18,22 +18,30 @@
 
 use crate::test::{mock::*, testing_utils::*};
 use crate::{
-    pallet::Config, ActiveProtocolState, BonusStatusWrapperFor, ContractStake, CurrentEraInfo,
-    DAppId, DAppTierRewardsFor, DAppTiers, EraReward, EraRewards, Error, Event, ForcingType,
-    GenesisConfig, IntegratedDApps, Ledger, NextDAppId, Perbill, PeriodNumber, Permill, Safeguard,
-    SingularStakingInfo, StakeAmount, StakerInfo, StaticTierParams, Subperiod, TierConfig,
-    TierParameters, TierThreshold,
+    pallet::Config, ActiveCycleConfig, ActiveProtocolState, AutoClaimCursor, AutoRelock,
+    BonusStatusWrapperFor, ClaimReminder, ClaimReminderCursor, ClaimReminderEra, CleanupMarker,
+    ContractStake, ContractStakers, CurrentEraInfo, CycleParameters, DAppAutoClaim,
+    DAppAutoCompound, DAppId, DAppLifetimeRewards, DAppRewardOptOut, DAppTierCap,
+    DAppTierRewardsFor, DAppTiers, EraReward, EraRewards, Error, Event, ForcingType, FreezeReason,
+    GenesisConfig, HistoryCleanupMarker, IntegratedDApps, LastForcedSubperiodEra, LastStakedPeriod,
+    Ledger, LockPurpose, LockedPerPurpose, MovesInPeriod, NextDAppId, OptOutRewardHandling,
+    PendingCycleConfig, Perbill, PeriodEnd, PeriodNumber, Permill, RewardRounding, Safeguard,
+    SingularStakingInfo, SlotsPerTierOverride, StakeAmount, StakeDelegate, StakerInfo,
+    StaticTierParams, Subperiod, TierAssignmentSnapshot, TierConfig, TierParameters, TierThreshold,
+    TotalRewardsPaid, TotalStakers,
 };
 
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
     error::BadOrigin,
     traits::{
-        fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnInitialize,
-        ReservableCurrency, SafeModeNotify,
+        fungible::{InspectFreeze, Unbalanced as FunUnbalanced},
+        Currency, Get, OnFinalize, OnIdle, OnInitialize, ReservableCurrency, SafeModeNotify,
     },
+    weights::Weight,
     BoundedVec,
 };
+use sp_core::H160;
 use sp_runtime::{
     traits::{ConstU32, Zero},
     BoundedBTreeMap,
@@ -41,8 +49,8 @@ use sp_runtime::{
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, RankedTier, SmartContractHandle, StakingRewardHandler,
-        FIXED_NUMBER_OF_TIER_SLOTS,
+        CycleConfiguration, EraNumber, RankedTier, SmartContractHandle, StakePreflightError,
+        StakingRewardHandler, FIXED_NUMBER_OF_TIER_SLOTS,
     },
     Balance, BlockNumber,
 };
@@ -183,6 +191,38 @@ fn maintenance_mode_call_filtering_works() {
             DappStaking::withdraw_unbonded(RuntimeOrigin::signed(1),),
             Error::<Test>::Disabled
         );
+        assert_noop!(
+            DappStaking::set_stake_delegate(RuntimeOrigin::signed(1), Some(2)),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::stake_delegated(
+                RuntimeOrigin::signed(2),
+                1,
+                MockSmartContract::wasm(1 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::unstake_delegated(
+                RuntimeOrigin::signed(2),
+                1,
+                MockSmartContract::wasm(1 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::move_stake_delegated(
+                RuntimeOrigin::signed(2),
+                1,
+                MockSmartContract::wasm(1 as AccountId),
+                MockSmartContract::wasm(2 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
     })
 }
 
@@ -339,6 +379,24 @@ fn register_with_incorrect_origin_fails() {
     })
 }
 
+#[test]
+fn register_zero_address_contract_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            DappStaking::register(RuntimeOrigin::root(), 2, MockSmartContract::Wasm(0)),
+            Error::<Test>::InvalidSmartContract
+        );
+        assert_noop!(
+            DappStaking::register(
+                RuntimeOrigin::root(),
+                2,
+                MockSmartContract::evm(H160::zero())
+            ),
+            Error::<Test>::InvalidSmartContract
+        );
+    })
+}
+
 #[test]
 fn register_already_registered_contract_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -436,6 +494,82 @@ fn set_dapp_reward_beneficiary_fails() {
     })
 }
 
+#[test]
+fn force_set_dapp_reward_beneficiary_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        let beneficiary = Some(5);
+
+        // Random signed account can use neither the normal, nor the forced call
+        assert_noop!(
+            DappStaking::set_dapp_reward_beneficiary(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract,
+                beneficiary
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::force_set_dapp_reward_beneficiary(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract,
+                beneficiary
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            DappStaking::force_set_dapp_reward_beneficiary(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                beneficiary
+            ),
+            BadOrigin
+        );
+
+        // Manager origin bypasses the owner check
+        assert_ok!(DappStaking::force_set_dapp_reward_beneficiary(
+            RuntimeOrigin::signed(ManagerAccount::get()),
+            smart_contract,
+            beneficiary
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::DAppRewardDestinationUpdated {
+                smart_contract,
+                beneficiary,
+            },
+        ));
+        assert_eq!(
+            IntegratedDApps::<Test>::get(&smart_contract)
+                .unwrap()
+                .reward_beneficiary,
+            beneficiary
+        );
+
+        // Reset back to owner
+        assert_ok!(DappStaking::force_set_dapp_reward_beneficiary(
+            RuntimeOrigin::root(),
+            smart_contract,
+            None
+        ));
+        assert!(IntegratedDApps::<Test>::get(&smart_contract)
+            .unwrap()
+            .reward_beneficiary
+            .is_none());
+
+        // Contract must exist
+        assert_noop!(
+            DappStaking::force_set_dapp_reward_beneficiary(
+                RuntimeOrigin::root(),
+                MockSmartContract::Wasm(4),
+                beneficiary
+            ),
+            Error::<Test>::ContractNotFound
+        );
+    })
+}
+
 #[test]
 fn set_dapp_owner_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
@@ -555,6 +689,149 @@ fn unregister_fails() {
     })
 }
 
+#[test]
+fn unregister_respects_min_registration_eras() {
+    let min_registration_eras = 3;
+    ExtBuilder::default()
+        .with_min_registration_eras(min_registration_eras)
+        .build_and_execute(|| {
+            let owner = 1;
+            let smart_contract = MockSmartContract::Wasm(3);
+            assert_register(owner, &smart_contract);
+            let registered_era = ActiveProtocolState::<Test>::get().era;
+
+            // Cannot unregister before the minimum number of eras has passed
+            for _ in 0..min_registration_eras {
+                assert_noop!(
+                    DappStaking::unregister(
+                        RuntimeOrigin::signed(ContractUnregisterAccount::get()),
+                        smart_contract.clone(),
+                    ),
+                    Error::<Test>::UnregisterTooSoon
+                );
+                advance_to_next_era();
+            }
+
+            // Exactly at the boundary, unregister is allowed
+            assert_eq!(
+                ActiveProtocolState::<Test>::get().era,
+                registered_era + min_registration_eras
+            );
+            assert_ok!(DappStaking::unregister(
+                RuntimeOrigin::signed(ContractUnregisterAccount::get()),
+                smart_contract.clone(),
+            ));
+        })
+}
+
+#[test]
+fn unregister_inactive_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        let registered_period = ActiveProtocolState::<Test>::get().period_number();
+        assert_eq!(
+            LastStakedPeriod::<Test>::get(dapp_id),
+            Some(registered_period)
+        );
+
+        // dApp without any stake can be unregistered once enough full periods have passed.
+        let inactivity_periods: PeriodNumber =
+            <Test as Config>::InactivityUnregisterPeriods::get().unwrap();
+        advance_to_period(registered_period + inactivity_periods);
+        assert_noop!(
+            DappStaking::unregister_inactive(RuntimeOrigin::signed(2), smart_contract.clone()),
+            Error::<Test>::DAppNotInactive
+        );
+
+        advance_to_next_period();
+        assert_ok!(DappStaking::unregister_inactive(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppUnregistered {
+            smart_contract: smart_contract.clone(),
+            era: ActiveProtocolState::<Test>::get().era,
+        }));
+        assert!(!IntegratedDApps::<Test>::contains_key(&smart_contract));
+        assert!(!LastStakedPeriod::<Test>::contains_key(dapp_id));
+    })
+}
+
+#[test]
+fn unregister_inactive_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_noop!(
+            DappStaking::unregister_inactive(RuntimeOrigin::signed(2), smart_contract.clone()),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Stake on the dApp, keeping it active.
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert_lock(account, 100);
+        assert_stake(account, &smart_contract, 100);
+
+        let inactivity_periods: PeriodNumber =
+            <Test as Config>::InactivityUnregisterPeriods::get().unwrap();
+        advance_to_period(ActiveProtocolState::<Test>::get().period_number() + inactivity_periods);
+
+        // Staking again resets the inactivity.
+        assert_lock(3, 100);
+        assert_stake(3, &smart_contract, 100);
+        advance_to_next_period();
+        assert_noop!(
+            DappStaking::unregister_inactive(RuntimeOrigin::signed(2), smart_contract.clone()),
+            Error::<Test>::DAppNotInactive
+        );
+
+        // Unsigned origin isn't allowed.
+        assert_noop!(
+            DappStaking::unregister_inactive(RuntimeOrigin::none(), smart_contract.clone()),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn unregister_inactive_fails_when_disabled() {
+    ExtBuilder::default()
+        .with_inactivity_unregister_periods(None)
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::Wasm(3);
+            assert_register(1, &smart_contract);
+
+            advance_to_period(ActiveProtocolState::<Test>::get().period_number() + 5);
+            assert_noop!(
+                DappStaking::unregister_inactive(RuntimeOrigin::signed(2), smart_contract),
+                Error::<Test>::InactivityUnregisterDisabled
+            );
+        })
+}
+
+#[test]
+fn unregister_with_root_bypasses_min_registration_eras() {
+    ExtBuilder::default()
+        .with_min_registration_eras(5)
+        .build_and_execute(|| {
+            let owner = 1;
+            let smart_contract = MockSmartContract::Wasm(3);
+            assert_register(owner, &smart_contract);
+
+            // Non-root origin is restricted, but root can unregister immediately
+            assert_noop!(
+                DappStaking::unregister(
+                    RuntimeOrigin::signed(ContractUnregisterAccount::get()),
+                    smart_contract.clone(),
+                ),
+                Error::<Test>::UnregisterTooSoon
+            );
+            assert_unregister(&smart_contract);
+        })
+}
+
 #[test]
 fn lock_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
@@ -593,6 +870,85 @@ fn lock_with_reserve_is_ok() {
     })
 }
 
+#[test]
+fn lock_respects_post_lock_min_free_balance() {
+    let min_free_balance = 50;
+    ExtBuilder::default()
+        .with_post_lock_min_free_balance(min_free_balance)
+        .build_and_execute(|| {
+            let locker = 2;
+            let free_balance = Balances::free_balance(&locker);
+
+            // Locking one unit too much fails
+            assert_noop!(
+                DappStaking::lock(
+                    RuntimeOrigin::signed(locker),
+                    free_balance - min_free_balance + 1
+                ),
+                Error::<Test>::InsufficientPostLockBalance
+            );
+
+            // Locking exactly up to the threshold works
+            assert_lock(locker, free_balance - min_free_balance - 10);
+            assert_lock(locker, 10);
+
+            // Nothing more can be locked
+            assert_noop!(
+                DappStaking::lock(RuntimeOrigin::signed(locker), 1),
+                Error::<Test>::InsufficientPostLockBalance
+            );
+
+            // Locking the entire balance is not possible
+            let locker = 3;
+            assert_noop!(
+                DappStaking::lock(
+                    RuntimeOrigin::signed(locker),
+                    Balances::total_balance(&locker)
+                ),
+                Error::<Test>::InsufficientPostLockBalance
+            );
+        })
+}
+
+#[test]
+fn lock_respects_max_locked_issuance_fraction() {
+    let max_fraction = Permill::from_percent(10);
+    ExtBuilder::default()
+        .with_max_locked_issuance_fraction(Some(max_fraction))
+        .build_and_execute(|| {
+            let cap = max_fraction * Balances::total_issuance();
+            assert!(cap > 200, "Sanity check");
+
+            // Lock up to the cap
+            assert_lock(2, cap - 100);
+            assert_noop!(
+                DappStaking::lock(RuntimeOrigin::signed(3), 101),
+                Error::<Test>::GlobalLockCapReached
+            );
+            assert_lock(3, 100);
+            assert_eq!(CurrentEraInfo::<Test>::get().total_locked, cap);
+
+            // Once the cap is reached, no one can lock more
+            assert_noop!(
+                DappStaking::lock(RuntimeOrigin::signed(4), MINIMUM_LOCK_AMOUNT),
+                Error::<Test>::GlobalLockCapReached
+            );
+            assert_noop!(
+                DappStaking::lock(RuntimeOrigin::signed(2), 1),
+                Error::<Test>::GlobalLockCapReached
+            );
+
+            // Unlocking frees up room below the cap
+            assert_unlock(2, 50);
+            assert_noop!(
+                DappStaking::lock(RuntimeOrigin::signed(4), 51),
+                Error::<Test>::GlobalLockCapReached
+            );
+            assert_lock(4, 50);
+            assert_eq!(CurrentEraInfo::<Test>::get().total_locked, cap);
+        })
+}
+
 #[test]
 fn lock_with_incorrect_amount_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -633,6 +989,72 @@ fn lock_with_blacklisted_account_fails() {
     })
 }
 
+#[test]
+fn lock_tagged_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let gov_purpose: LockPurpose = *b"gov\0";
+        let default_locked = LockedPerPurpose::<Test>::get(LockPurpose::default());
+
+        // New account is tagged with the specified purpose
+        let locker = 2;
+        assert_ok!(DappStaking::lock_tagged(
+            RuntimeOrigin::signed(locker),
+            100,
+            gov_purpose
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Locked {
+            account: locker,
+            amount: 100,
+        }));
+        assert_eq!(Ledger::<Test>::get(&locker).purpose(), gov_purpose);
+        assert_eq!(LockedPerPurpose::<Test>::get(gov_purpose), 100);
+
+        // Plain lock keeps the existing purpose
+        assert_ok!(DappStaking::lock(RuntimeOrigin::signed(locker), 50));
+        assert_eq!(Ledger::<Test>::get(&locker).purpose(), gov_purpose);
+        assert_eq!(LockedPerPurpose::<Test>::get(gov_purpose), 150);
+
+        // Plain lock for a new account uses the default purpose
+        let other_locker = 3;
+        assert_lock(other_locker, 200);
+        assert_eq!(
+            Ledger::<Test>::get(&other_locker).purpose(),
+            LockPurpose::default()
+        );
+        assert_eq!(
+            LockedPerPurpose::<Test>::get(LockPurpose::default()),
+            default_locked + 200
+        );
+
+        // Unlocking funds still counts into the purpose total until they are claimed
+        assert_unlock(locker, 30);
+        assert_eq!(LockedPerPurpose::<Test>::get(gov_purpose), 150);
+        run_for_blocks(DappStaking::unlocking_period());
+        assert_claim_unlocked(locker);
+        assert_eq!(LockedPerPurpose::<Test>::get(gov_purpose), 120);
+    })
+}
+
+#[test]
+fn lock_tagged_with_different_purpose_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let locker = 2;
+        assert_lock(locker, 100);
+
+        assert_noop!(
+            DappStaking::lock_tagged(RuntimeOrigin::signed(locker), 100, *b"gov\0"),
+            Error::<Test>::LockPurposeMismatch,
+        );
+
+        // Same purpose is fine
+        assert_ok!(DappStaking::lock_tagged(
+            RuntimeOrigin::signed(locker),
+            100,
+            LockPurpose::default()
+        ));
+    })
+}
+
 #[test]
 fn unbond_and_unstake_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
@@ -858,6 +1280,52 @@ fn withdraw_unbonded_is_ok() {
     })
 }
 
+#[test]
+fn unlock_with_amount_based_unlocking_period_is_ok() {
+    ExtBuilder::default()
+        .with_scaled_unlocking_period()
+        .build_and_execute(|| {
+            let unlocking_blocks = DappStaking::unlocking_period();
+            let account = 2;
+            assert_lock(account, 500);
+
+            // Small amount uses the standard unlocking period, larger one is scaled up
+            let small_amount = UNLOCKING_PERIOD_SCALING_STEP - 1;
+            let large_amount = UNLOCKING_PERIOD_SCALING_STEP * 2;
+            let unlock_start_block = System::block_number();
+            assert_unlock(account, small_amount);
+            assert_unlock(account, large_amount);
+
+            let unlocking = Ledger::<Test>::get(&account).unlocking;
+            assert_eq!(unlocking.len(), 2);
+            assert_eq!(unlocking[0].amount, small_amount);
+            assert_eq!(
+                unlocking[0].unlock_block,
+                unlock_start_block + unlocking_blocks
+            );
+            assert_eq!(unlocking[1].amount, large_amount);
+            assert_eq!(
+                unlocking[1].unlock_block,
+                unlock_start_block + unlocking_blocks * 3
+            );
+
+            // Only the small chunk can be claimed after the standard unlocking period
+            run_for_blocks(unlocking_blocks);
+            assert_claim_unlocked(account);
+            System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimedUnlocked {
+                account,
+                amount: small_amount,
+            }));
+
+            run_for_blocks(unlocking_blocks * 2);
+            assert_claim_unlocked(account);
+            System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimedUnlocked {
+                account,
+                amount: large_amount,
+            }));
+        })
+}
+
 #[test]
 fn claim_unlocked_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
@@ -1019,6 +1487,38 @@ fn stake_basic_example_is_ok() {
     })
 }
 
+#[test]
+fn stake_into_empty_reward_pool_emits_warning() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+
+        let is_warning_emitted = || {
+            System::events().iter().any(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::DappStaking(Event::StakingIntoEmptyPool { .. })
+                )
+            })
+        };
+
+        // Non-empty reward pool, no warning.
+        assert_stake(account, &smart_contract, 50);
+        assert!(!is_warning_emitted());
+
+        // Empty reward pool, staking still works but a warning is emitted.
+        IS_REWARD_POOL_EMPTY.with(|v| *v.borrow_mut() = true);
+        assert_stake(account, &smart_contract, 50);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::StakingIntoEmptyPool {
+            account,
+            smart_contract,
+        }));
+    })
+}
+
 #[test]
 fn stake_after_expiry_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
@@ -1054,20 +1554,64 @@ fn stake_after_expiry_is_ok() {
 }
 
 #[test]
-fn stake_with_zero_amount_fails() {
-    ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract & lock some amount
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &smart_contract);
-        let account = 2;
-        assert_lock(account, 300);
-
-        assert_noop!(
-            DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 0),
-            Error::<Test>::ZeroAmount,
-        );
-    })
-}
+fn stake_with_lock_to_stake_delay_works() {
+    ExtBuilder::default()
+        .with_lock_to_stake_delay_eras(2)
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+
+            // Freshly locked funds cannot be staked
+            let account = 2;
+            assert_lock(account, 100);
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 50),
+                Error::<Test>::LockedFundsNotAged
+            );
+            assert_eq!(
+                DappStaking::can_stake(&account, &smart_contract, 50),
+                Err(StakePreflightError::LockedFundsNotAged)
+            );
+            advance_to_next_era();
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 50),
+                Error::<Test>::LockedFundsNotAged
+            );
+
+            // Once aged, they can be staked
+            advance_to_next_era();
+            assert_ok!(DappStaking::can_stake(&account, &smart_contract, 50));
+            assert_stake(account, &smart_contract, 50);
+
+            // Only the aged part can be staked after locking more
+            assert_lock(account, 100);
+            assert_stake(account, &smart_contract, 50);
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 10),
+                Error::<Test>::LockedFundsNotAged
+            );
+
+            advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+            assert_claim_staker_rewards(account);
+            assert_stake(account, &smart_contract, 100);
+        })
+}
+
+#[test]
+fn stake_with_zero_amount_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract & lock some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert_lock(account, 300);
+
+        assert_noop!(
+            DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 0),
+            Error::<Test>::ZeroAmount,
+        );
+    })
+}
 
 #[test]
 fn stake_on_invalid_dapp_fails() {
@@ -1115,6 +1659,102 @@ fn stake_in_final_era_fails() {
     })
 }
 
+#[test]
+fn stake_in_final_era_within_grace_is_ok() {
+    let grace = 10;
+    ExtBuilder::default()
+        .with_final_era_stake_grace(grace)
+        .build_and_execute(|| {
+            // Register smart contract & lock some amount
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            let account = 2;
+            assert_register(1, &smart_contract);
+            assert_lock(account, 300);
+
+            // Force Build&Earn period
+            ActiveProtocolState::<Test>::mutate(|state| {
+                state.period_info.subperiod = Subperiod::BuildAndEarn;
+                state.period_info.next_subperiod_start_era = state.era + 1;
+            });
+
+            // Stake above the grace still fails
+            assert_noop!(
+                DappStaking::stake(
+                    RuntimeOrigin::signed(account),
+                    smart_contract.clone(),
+                    grace + 1
+                ),
+                Error::<Test>::PeriodEndsInNextEra
+            );
+
+            // Stake up to the grace is allowed
+            assert_ok!(DappStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                grace
+            ));
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, &smart_contract)
+                    .expect("Entry must exist after staking.")
+                    .total_staked_amount(),
+                grace
+            );
+        })
+}
+
+#[test]
+fn stake_in_final_era_grace_applies_to_total_stake() {
+    let grace = 10;
+    ExtBuilder::default()
+        .with_final_era_stake_grace(grace)
+        .build_and_execute(|| {
+            // Register smart contracts & lock some amount
+            let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+            let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+            let account = 2;
+            assert_register(1, &smart_contract_1);
+            assert_register(1, &smart_contract_2);
+            assert_lock(account, 300);
+            assert_stake(account, &smart_contract_1, 100);
+
+            // Force Build&Earn period, and advance into the final era
+            ActiveProtocolState::<Test>::mutate(|state| {
+                state.period_info.subperiod = Subperiod::BuildAndEarn;
+                state.period_info.next_subperiod_start_era = state.era + 2;
+            });
+            advance_to_next_era();
+
+            // Stake can be split into multiple calls, as long as the total is within the grace
+            assert_ok!(DappStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1.clone(),
+                grace / 2
+            ));
+            assert_ok!(DappStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_2.clone(),
+                grace / 2
+            ));
+
+            // Any additional stake exceeds the grace
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(account), smart_contract_2.clone(), 3),
+                Error::<Test>::PeriodEndsInNextEra
+            );
+
+            // Moving stake in the final era isn't allowed, even if the amount is within the grace
+            assert_noop!(
+                DappStaking::move_stake(
+                    RuntimeOrigin::signed(account),
+                    smart_contract_1.clone(),
+                    smart_contract_2.clone(),
+                    3
+                ),
+                Error::<Test>::PeriodEndsInNextEra
+            );
+        })
+}
+
 #[test]
 fn stake_fails_if_unclaimed_staker_rewards_from_past_remain() {
     ExtBuilder::default().build_and_execute(|| {
@@ -1290,6 +1930,100 @@ fn stake_fails_due_to_too_small_staking_amount() {
     })
 }
 
+#[test]
+fn set_dapp_min_stake_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        let min_stake = Some(<Test as Config>::MinimumStakeAmount::get() * 10);
+
+        // Only the owner can set the override
+        assert_noop!(
+            DappStaking::set_dapp_min_stake(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract,
+                min_stake
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        assert_ok!(DappStaking::set_dapp_min_stake(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            min_stake
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMinStakeUpdated {
+            smart_contract,
+            min_stake,
+        }));
+        assert_eq!(
+            IntegratedDApps::<Test>::get(&smart_contract)
+                .unwrap()
+                .min_stake(),
+            min_stake
+        );
+
+        // Reset back to the global minimum
+        assert_ok!(DappStaking::set_dapp_min_stake(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            None
+        ));
+        assert!(IntegratedDApps::<Test>::get(&smart_contract)
+            .unwrap()
+            .min_stake()
+            .is_none());
+
+        // Contract must exist
+        assert_noop!(
+            DappStaking::set_dapp_min_stake(
+                RuntimeOrigin::signed(owner),
+                MockSmartContract::Wasm(4),
+                min_stake
+            ),
+            Error::<Test>::ContractNotFound
+        );
+    })
+}
+
+#[test]
+fn stake_fails_below_dapp_min_stake_override() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::Wasm(1);
+        let smart_contract_2 = MockSmartContract::Wasm(2);
+        let account = 3;
+        assert_register(1, &smart_contract_1);
+        assert_register(2, &smart_contract_2);
+        assert_lock(account, 300);
+
+        let global_min_stake: Balance = <Test as Config>::MinimumStakeAmount::get();
+        let dapp_min_stake = global_min_stake * 10;
+        assert_ok!(DappStaking::set_dapp_min_stake(
+            RuntimeOrigin::signed(1),
+            smart_contract_1,
+            Some(dapp_min_stake)
+        ));
+
+        // Global minimum is no longer enough for the contract with the override
+        assert_noop!(
+            DappStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1,
+                dapp_min_stake - 1
+            ),
+            Error::<Test>::InsufficientStakeAmount
+        );
+        assert_stake(account, &smart_contract_1, dapp_min_stake);
+
+        // Once above the override, arbitrary small amounts can be added
+        assert_stake(account, &smart_contract_1, 1);
+
+        // Other contracts are unaffected
+        assert_stake(account, &smart_contract_2, global_min_stake);
+    })
+}
+
 #[test]
 fn stake_fails_due_to_too_many_staked_contracts() {
     ExtBuilder::default().build_and_execute(|| {
@@ -1624,6 +2358,79 @@ fn unstake_from_past_period_fails() {
     })
 }
 
+#[test]
+fn unstake_all_matches_full_unstake() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::Wasm(1);
+        assert_register(1, &smart_contract);
+
+        // Two accounts with identical positions
+        let (account_1, account_2) = (2, 3);
+        let stake_amount = 100;
+        for account in [account_1, account_2] {
+            assert_lock(account, 300);
+            assert_stake(account, &smart_contract, stake_amount);
+        }
+        advance_to_next_era();
+
+        // First one unstakes the full amount explicitly, the second one uses `unstake_all`
+        assert_unstake(account_1, &smart_contract, stake_amount);
+        assert_ok!(DappStaking::unstake_all(
+            RuntimeOrigin::signed(account_2),
+            smart_contract
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Unstake {
+            account: account_2,
+            smart_contract,
+            amount: stake_amount,
+        }));
+
+        // Both accounts end up in the same state
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account_2,
+            &smart_contract
+        ));
+        let (ledger_1, ledger_2) = (
+            Ledger::<Test>::get(&account_1),
+            Ledger::<Test>::get(&account_2),
+        );
+        assert_eq!(ledger_1.staked, ledger_2.staked);
+        assert_eq!(ledger_1.staked_future, ledger_2.staked_future);
+        assert_eq!(ledger_1.contract_stake_count, ledger_2.contract_stake_count);
+
+        // Nothing left to unstake
+        assert_noop!(
+            DappStaking::unstake_all(RuntimeOrigin::signed(account_2), smart_contract),
+            Error::<Test>::NoStakingInfo
+        );
+    })
+}
+
+#[test]
+fn unstake_all_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::Wasm(1);
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // Claimable rewards must be claimed first
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_noop!(
+            DappStaking::unstake_all(RuntimeOrigin::signed(account), smart_contract),
+            Error::<Test>::UnclaimedRewards
+        );
+
+        // Stake from a past period cannot be unstaked
+        advance_to_next_period();
+        assert_noop!(
+            DappStaking::unstake_all(RuntimeOrigin::signed(account), smart_contract),
+            Error::<Test>::UnstakeFromPastPeriod
+        );
+    })
+}
+
 #[test]
 fn move_from_past_period_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -1688,6 +2495,89 @@ fn claim_staker_rewards_basic_example_is_ok() {
     })
 }
 
+#[test]
+fn claim_staker_rewards_with_loyalty_boost_favors_long_term_lockers() {
+    ExtBuilder::default()
+        .with_loyalty_boost(Some((2, Permill::from_percent(10))))
+        .build_and_execute(|| {
+            // Register smart contract
+            let dev_account = 1;
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(dev_account, &smart_contract);
+
+            // Long-term locker locks in the first era, short-term one a few eras later
+            let long_term_account = 2;
+            let lock_era = ActiveProtocolState::<Test>::get().era;
+            assert_lock(long_term_account, 300);
+            advance_to_era(lock_era + 4);
+
+            let short_term_account = 3;
+            assert_lock(short_term_account, 300);
+            assert_eq!(
+                Ledger::<Test>::get(&short_term_account).locked_since(),
+                Some(lock_era + 4)
+            );
+
+            // Both accounts stake the same amount at the same time
+            let stake_amount = 100;
+            assert_stake(long_term_account, &smart_contract, stake_amount);
+            assert_stake(short_term_account, &smart_contract, stake_amount);
+
+            // Claim reward for the same, single era
+            advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+            let pre_long_term_balance = Balances::free_balance(&long_term_account);
+            let pre_short_term_balance = Balances::free_balance(&short_term_account);
+            assert_claim_staker_rewards(long_term_account);
+            assert_claim_staker_rewards(short_term_account);
+            let long_term_reward =
+                Balances::free_balance(&long_term_account) - pre_long_term_balance;
+            let short_term_reward =
+                Balances::free_balance(&short_term_account) - pre_short_term_balance;
+
+            // Claimed era is 5 eras after the long-term lock (2 full steps), and only 1 era after
+            // the short-term lock (no full step).
+            assert!(!short_term_reward.is_zero());
+            assert_eq!(
+                long_term_reward,
+                short_term_reward + Permill::from_percent(20) * short_term_reward
+            );
+        })
+}
+
+#[test]
+fn loyalty_boost_streak_is_reset_on_full_unlock() {
+    ExtBuilder::default()
+        .with_loyalty_boost(Some((2, Permill::from_percent(10))))
+        .build_and_execute(|| {
+            let account = 2;
+            let lock_era = ActiveProtocolState::<Test>::get().era;
+            assert_lock(account, 300);
+            advance_to_era(lock_era + 5);
+
+            // Partial unlock keeps the streak
+            assert_unlock(account, 100);
+            assert_eq!(Ledger::<Test>::get(&account).locked_since(), Some(lock_era));
+            assert_eq!(
+                DappStaking::loyalty_boost(
+                    Ledger::<Test>::get(&account).locked_since(),
+                    lock_era + 5
+                ),
+                Permill::from_percent(20)
+            );
+
+            // Full unlock resets it, and a new lock starts a new streak
+            assert_unlock(account, 200);
+            assert!(Ledger::<Test>::get(&account).locked_since().is_none());
+
+            advance_to_era(lock_era + 6);
+            assert_lock(account, 300);
+            assert_eq!(
+                Ledger::<Test>::get(&account).locked_since(),
+                Some(lock_era + 6)
+            );
+        })
+}
+
 #[test]
 fn claim_staker_rewards_double_call_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -2661,6 +3551,43 @@ fn force_subperiod_works() {
     })
 }
 
+#[test]
+fn force_subperiod_respects_min_interval() {
+    let min_interval = 3;
+    ExtBuilder::default()
+        .with_min_subperiod_force_interval(min_interval)
+        .build_and_execute(|| {
+            // First subperiod force is always allowed
+            let forced_era = ActiveProtocolState::<Test>::get().era;
+            assert_ok!(DappStaking::force(
+                RuntimeOrigin::root(),
+                ForcingType::Subperiod
+            ));
+            assert_eq!(LastForcedSubperiodEra::<Test>::get(), Some(forced_era));
+            run_for_blocks(1);
+
+            // Subperiod cannot be forced again until the interval passes, but era forcing is unaffected
+            for _ in 1..min_interval {
+                assert_noop!(
+                    DappStaking::force(RuntimeOrigin::root(), ForcingType::Subperiod),
+                    Error::<Test>::SubperiodForceTooSoon
+                );
+                assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+                run_for_blocks(1);
+            }
+            assert_eq!(LastForcedSubperiodEra::<Test>::get(), Some(forced_era));
+
+            // Exactly at the interval boundary, subperiod can be forced again
+            let current_era = ActiveProtocolState::<Test>::get().era;
+            assert_eq!(current_era, forced_era + min_interval, "Sanity check.");
+            assert_ok!(DappStaking::force(
+                RuntimeOrigin::root(),
+                ForcingType::Subperiod
+            ));
+            assert_eq!(LastForcedSubperiodEra::<Test>::get(), Some(current_era));
+        })
+}
+
 #[test]
 fn force_with_incorrect_origin_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -2905,46 +3832,283 @@ fn get_dapp_tier_assignment_and_rewards_zero_slots_per_tier_works() {
 }
 
 #[test]
-fn advance_for_some_periods_works() {
-    ExtBuilder::default().build_and_execute(|| {
-        advance_to_period(10);
-    })
+fn get_dapp_tier_assignment_and_rewards_distributes_remainder() {
+    ExtBuilder::default()
+        .with_tier_reward_rounding(RewardRounding::DistributeRemainder)
+        .build_and_execute(|| {
+            // Entire reward pool goes to the 2nd tier, which will be completely filled.
+            TierConfig::<Test>::mutate(|config| {
+                config.slots_per_tier = BoundedVec::try_from(vec![0, 3, 0, 0]).unwrap();
+                config.reward_portion = BoundedVec::try_from(vec![
+                    Permill::zero(),
+                    Permill::from_percent(100),
+                    Permill::zero(),
+                    Permill::zero(),
+                ])
+                .unwrap();
+            });
+            let tier_config = TierConfig::<Test>::get();
+
+            for x in 1..=3 {
+                let smart_contract = MockSmartContract::wasm(x);
+                assert_register(x, &smart_contract);
+
+                let amount = tier_config.tier_thresholds[0] + x as Balance;
+                Balances::make_free_balance_be(&x, amount);
+                assert_lock(x, amount);
+                assert_stake(x, &smart_contract, amount);
+            }
+
+            let protocol_state = ActiveProtocolState::<Test>::get();
+            let dapp_reward_pool: Balance = 1_000_000;
+            let (mut tier_assignment, _) = DappStaking::get_dapp_tier_assignment_and_rewards(
+                protocol_state.era + 1,
+                protocol_state.period_number(),
+                dapp_reward_pool,
+            );
+
+            // Remainder is assigned to the dApp with the highest stake.
+            let top_dapp_id = IntegratedDApps::<Test>::get(&MockSmartContract::wasm(3))
+                .unwrap()
+                .id;
+            assert_eq!(tier_assignment.remainders.len(), 1);
+            assert!(!tier_assignment.remainders[&top_dapp_id].is_zero());
+
+            // Nothing is lost to rounding.
+            let dapp_ids: Vec<_> = tier_assignment.dapps.keys().copied().collect();
+            let total_distributed: Balance = dapp_ids
+                .into_iter()
+                .map(|dapp_id| tier_assignment.try_claim(dapp_id).unwrap().0)
+                .sum();
+            assert_eq!(total_distributed, dapp_reward_pool);
+        })
 }
 
 #[test]
-fn unlock_after_staked_period_ends_is_ok() {
-    ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract, lock&stake some amount
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &smart_contract);
+fn top_tier_boost_is_taken_proportionally_from_lower_tiers() {
+    ExtBuilder::default()
+        .with_top_tier_boost(Permill::from_percent(10))
+        .build_and_execute(|| {
+            let tier_config = TierConfig::<Test>::get();
+            let reward_portion = tier_config.reward_portion.into_inner();
+            let slots_per_tier = tier_config.slots_per_tier.into_inner();
+            assert_eq!(
+                reward_portion,
+                vec![
+                    Permill::from_percent(40),
+                    Permill::from_percent(30),
+                    Permill::from_percent(20),
+                    Permill::from_percent(10),
+                ]
+            );
 
-        let account = 2;
-        let amount = 101;
-        assert_lock(account, amount);
-        assert_stake(account, &smart_contract, amount);
+            assert_eq!(
+                DappStaking::boosted_reward_portion(&reward_portion, &slots_per_tier),
+                vec![
+                    Permill::from_percent(50),
+                    Permill::from_percent(25),
+                    Permill::from_parts(166_667),
+                    Permill::from_parts(83_333),
+                ]
+            );
 
-        // Advance to the next period, and ensure stake is reset and can be fully unlocked
-        advance_to_next_period();
-        assert!(Ledger::<Test>::get(&account)
-            .staked_amount(ActiveProtocolState::<Test>::get().period_number())
-            .is_zero());
-        assert_unlock(account, amount);
-        assert_eq!(Ledger::<Test>::get(&account).unlocking_amount(), amount);
-    })
+            // Boost can't take more than the lower tiers have.
+            TOP_TIER_BOOST.with(|v| *v.borrow_mut() = Permill::from_percent(80));
+            assert_eq!(
+                DappStaking::boosted_reward_portion(&reward_portion, &slots_per_tier),
+                vec![
+                    Permill::from_percent(100),
+                    Permill::zero(),
+                    Permill::zero(),
+                    Permill::zero(),
+                ]
+            );
+        })
 }
 
 #[test]
-fn unstake_from_a_contract_staked_in_past_period_fails() {
-    ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract & lock some amount
-        let smart_contract_1 = MockSmartContract::Wasm(1);
-        let smart_contract_2 = MockSmartContract::Wasm(2);
-        assert_register(1, &smart_contract_1);
-        assert_register(1, &smart_contract_2);
-        let account = 2;
-        assert_lock(account, 300);
+fn top_tier_boost_skips_tiers_without_slots() {
+    ExtBuilder::default()
+        .with_top_tier_boost(Permill::from_percent(10))
+        .build_and_execute(|| {
+            // Shaped like the production tier configuration, where the first & last tiers are unused.
+            let reward_portion = vec![
+                Permill::zero(),
+                Permill::from_percent(70),
+                Permill::from_percent(30),
+                Permill::zero(),
+            ];
+            let slots_per_tier: Vec<u16> = vec![0, 6, 10, 0];
 
-        // Stake some amount on the 2nd contract.
+            assert_eq!(
+                DappStaking::boosted_reward_portion(&reward_portion, &slots_per_tier),
+                vec![
+                    Permill::zero(),
+                    Permill::from_percent(80),
+                    Permill::from_percent(20),
+                    Permill::zero(),
+                ]
+            );
+
+            // Nothing to boost if no tier has any slots.
+            assert_eq!(
+                DappStaking::boosted_reward_portion(&reward_portion, &[0, 0, 0, 0]),
+                reward_portion
+            );
+        })
+}
+
+#[test]
+fn top_tier_boost_increases_top_tier_payouts() {
+    ExtBuilder::default().build_and_execute(|| {
+        // One dApp per tier, each exactly at the tier threshold.
+        let tier_config = TierConfig::<Test>::get();
+        let dapp_stakes: Vec<(DAppId, Balance)> = tier_config
+            .tier_thresholds
+            .iter()
+            .enumerate()
+            .map(|(idx, threshold)| (idx as DAppId, *threshold))
+            .collect();
+
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        let dapp_reward_pool: Balance = 1_000_000;
+        let claim_all = || {
+            let mut tier_assignment = DappStaking::compute_dapp_tier_assignment_and_rewards(
+                dapp_stakes.clone(),
+                period,
+                dapp_reward_pool,
+            );
+            dapp_stakes
+                .iter()
+                .map(|(dapp_id, _)| tier_assignment.try_claim(*dapp_id).unwrap().0)
+                .collect::<Vec<_>>()
+        };
+
+        let payouts = claim_all();
+        TOP_TIER_BOOST.with(|v| *v.borrow_mut() = Permill::from_percent(10));
+        let boosted_payouts = claim_all();
+
+        // Top tier receives 50% instead of 40% of the pool, lower tiers lose a 1/6 of their share.
+        assert!(boosted_payouts[0] > payouts[0]);
+        assert!((boosted_payouts[0] * 4 / 5).abs_diff(payouts[0]) <= 10);
+        for tier in 1..payouts.len() {
+            assert!(boosted_payouts[tier] < payouts[tier]);
+            assert!(boosted_payouts[tier].abs_diff(payouts[tier] * 5 / 6) <= 10);
+        }
+        assert!(boosted_payouts.iter().sum::<Balance>() <= dapp_reward_pool);
+    })
+}
+
+#[test]
+fn dapp_stakes_snapshot_is_used_for_tier_assignment() {
+    ExtBuilder::default().build_and_execute(|| {
+        let tier_config = TierConfig::<Test>::get();
+        for x in 1..=4 {
+            let smart_contract = MockSmartContract::wasm(x);
+            assert_register(x, &smart_contract);
+
+            let amount = tier_config.tier_thresholds[0] + x as Balance;
+            Balances::make_free_balance_be(&x, amount);
+            assert_lock(x, amount);
+            assert_stake(x, &smart_contract, amount);
+        }
+        let dapp_id = |x: AccountId| {
+            IntegratedDApps::<Test>::get(&MockSmartContract::wasm(x))
+                .unwrap()
+                .id
+        };
+
+        // Snapshot is only collected during the `Build&Earn` subperiod.
+        DappStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(TierAssignmentSnapshot::<Test>::get().is_none());
+
+        advance_to_next_era();
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        assert_eq!(protocol_state.subperiod(), Subperiod::BuildAndEarn);
+
+        DappStaking::on_idle(System::block_number(), Weight::MAX);
+        let snapshot = TierAssignmentSnapshot::<Test>::get().expect("Must be collected.");
+        assert_eq!(snapshot.era, protocol_state.era);
+        assert!(snapshot.complete);
+        assert_eq!(snapshot.stakes.len(), 4);
+
+        // Changes to the contract stake must be reflected in the snapshot.
+        assert_unstake(
+            1,
+            &MockSmartContract::wasm(1),
+            tier_config.tier_thresholds[0] - 4,
+        );
+        assert_unstake(
+            2,
+            &MockSmartContract::wasm(2),
+            tier_config.tier_thresholds[0] + 2,
+        );
+        assert_unregister(&MockSmartContract::wasm(3));
+
+        let snapshot = TierAssignmentSnapshot::<Test>::get().expect("Must still exist.");
+        assert_eq!(snapshot.stakes.len(), 2);
+        assert!(snapshot.stakes.contains(&(dapp_id(1), 5)));
+        assert!(snapshot
+            .stakes
+            .contains(&(dapp_id(4), tier_config.tier_thresholds[0] + 4)));
+
+        // Tier assignment based on the snapshot must match the one based on the contract stake entries.
+        let (expected_tier_assignment, _) = DappStaking::get_dapp_tier_assignment_and_rewards(
+            protocol_state.era,
+            protocol_state.period_number(),
+            1_000_000,
+        );
+
+        advance_to_next_era();
+        assert!(TierAssignmentSnapshot::<Test>::get().is_none());
+        assert_eq!(
+            DAppTiers::<Test>::get(&protocol_state.era).unwrap().dapps,
+            expected_tier_assignment.dapps
+        );
+    })
+}
+
+#[test]
+fn advance_for_some_periods_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        advance_to_period(10);
+    })
+}
+
+#[test]
+fn unlock_after_staked_period_ends_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let amount = 101;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance to the next period, and ensure stake is reset and can be fully unlocked
+        advance_to_next_period();
+        assert!(Ledger::<Test>::get(&account)
+            .staked_amount(ActiveProtocolState::<Test>::get().period_number())
+            .is_zero());
+        assert_unlock(account, amount);
+        assert_eq!(Ledger::<Test>::get(&account).unlocking_amount(), amount);
+    })
+}
+
+#[test]
+fn unstake_from_a_contract_staked_in_past_period_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract & lock some amount
+        let smart_contract_1 = MockSmartContract::Wasm(1);
+        let smart_contract_2 = MockSmartContract::Wasm(2);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+        let account = 2;
+        assert_lock(account, 300);
+
+        // Stake some amount on the 2nd contract.
         let stake_amount = 100;
         assert_stake(account, &smart_contract_2, stake_amount);
 
@@ -3317,7 +4481,7 @@ fn unregister_after_max_number_of_contracts_allows_register_again() {
         let developer = 2;
 
         // Reach max number of contracts
-        for id in 0..max_number_of_contracts {
+        for id in 1..=max_number_of_contracts {
             assert_register(developer, &MockSmartContract::Wasm(id.into()));
         }
 
@@ -3326,13 +4490,13 @@ fn unregister_after_max_number_of_contracts_allows_register_again() {
             DappStaking::register(
                 RuntimeOrigin::root(),
                 developer,
-                MockSmartContract::Wasm((max_number_of_contracts).into())
+                MockSmartContract::Wasm((max_number_of_contracts + 1).into())
             ),
             Error::<Test>::ExceededMaxNumberOfContracts
         );
 
         // Unregister one contract, and ensure register works again
-        let smart_contract = MockSmartContract::Wasm(0);
+        let smart_contract = MockSmartContract::Wasm(1);
         assert_unregister(&smart_contract);
         assert_register(developer, &smart_contract);
     })
@@ -3646,6 +4810,83 @@ fn claim_staker_rewards_for_basic_example_is_ok() {
     })
 }
 
+#[test]
+fn claim_staker_rewards_for_is_capped_by_keeper_claim_window() {
+    ExtBuilder::default()
+        .with_keeper_claim_window(2)
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+
+            let staker_account = 2;
+            assert_lock(staker_account, 300);
+            assert_stake(staker_account, &smart_contract, 100);
+
+            // Advance into Build&Earn period, and allow multiple eras to pass.
+            advance_to_era(ActiveProtocolState::<Test>::get().era + 6);
+
+            let claimed_eras = || {
+                System::events()
+                    .iter()
+                    .filter(|record| {
+                        matches!(
+                            record.event,
+                            RuntimeEvent::DappStaking(Event::Reward { .. })
+                        )
+                    })
+                    .count()
+            };
+
+            // Keeper claim is limited by the window.
+            System::reset_events();
+            assert_ok!(DappStaking::claim_staker_rewards_for(
+                RuntimeOrigin::signed(3),
+                staker_account
+            ));
+            assert_eq!(claimed_eras(), 2);
+
+            // Self claim isn't limited.
+            System::reset_events();
+            assert_ok!(DappStaking::claim_staker_rewards_for(
+                RuntimeOrigin::signed(staker_account),
+                staker_account
+            ));
+            assert_eq!(claimed_eras(), 3);
+        })
+}
+
+#[test]
+fn claim_staker_rewards_is_capped_by_max_era_rewards_per_claim() {
+    ExtBuilder::default()
+        .with_max_era_rewards_per_claim(2)
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+
+            let account = 2;
+            assert_lock(account, 300);
+            assert_stake(account, &smart_contract, 100);
+
+            // Advance into Build&Earn period, and allow 5 eras to pass.
+            let stake_era = ActiveProtocolState::<Test>::get().era + 1;
+            advance_to_era(stake_era + 5);
+
+            // Each claim covers at most 2 eras, so 3 claims are needed.
+            for expected_last_era in [stake_era + 1, stake_era + 3, stake_era + 4] {
+                System::reset_events();
+                assert_claim_staker_rewards(account);
+                assert_eq!(
+                    Ledger::<Test>::get(&account).staked.era,
+                    expected_last_era + 1
+                );
+            }
+            assert_noop!(
+                DappStaking::claim_staker_rewards(RuntimeOrigin::signed(account)),
+                Error::<Test>::NoClaimableRewards
+            );
+        })
+}
+
 #[test]
 fn claim_bonus_reward_for_works() {
     ExtBuilder::default().build_and_execute(|| {
@@ -4464,6 +5705,7 @@ fn cleanup_expired_entries_correctly_with_ledger_counter_reconciliation() {
             &keep_bonus,
             SingularStakingInfo::new(current_period - 1, 1),
         );
+        ContractStakers::<Test>::insert(&keep_bonus, &account, ());
 
         // 2. Past but claimable + NOT bonus eligible (delete after cleanup)
         StakerInfo::<Test>::insert(
@@ -4471,6 +5713,7 @@ fn cleanup_expired_entries_correctly_with_ledger_counter_reconciliation() {
             &delete_no_bonus,
             SingularStakingInfo::new(current_period - 1, 0),
         );
+        ContractStakers::<Test>::insert(&delete_no_bonus, &account, ());
 
         // 3. Older than oldest claimable period → (delete after cleanup)
         StakerInfo::<Test>::insert(
@@ -4478,6 +5721,7 @@ fn cleanup_expired_entries_correctly_with_ledger_counter_reconciliation() {
             &delete_too_old,
             SingularStakingInfo::new(threshold_period - 1, 1),
         );
+        ContractStakers::<Test>::insert(&delete_too_old, &account, ());
 
         // Inflate the ledger counter
         Ledger::<Test>::mutate(&account, |ledger| {
@@ -4514,3 +5758,3409 @@ fn cleanup_expired_entries_correctly_with_ledger_counter_reconciliation() {
         );
     });
 }
+
+#[test]
+fn estimate_apr_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+
+        // Unregistered contract has no estimate
+        assert!(DappStaking::estimate_apr(&smart_contract, 100).is_none());
+
+        assert_register(owner, &smart_contract);
+        let lowest_threshold = *TierConfig::<Test>::get()
+            .tier_thresholds()
+            .last()
+            .expect("Tier thresholds must exist.");
+
+        // Zero stake, or stake which doesn't satisfy any tier threshold, has no estimate
+        assert!(DappStaking::estimate_apr(&smart_contract, 0).is_none());
+        assert!(DappStaking::estimate_apr(&smart_contract, lowest_threshold - 1).is_none());
+
+        // Already staked amount on the contract is taken into account
+        let min_stake = <Test as Config>::MinimumStakeAmount::get();
+        assert_lock(owner, 100);
+        assert_stake(owner, &smart_contract, min_stake);
+        assert!(DappStaking::estimate_apr(&smart_contract, lowest_threshold - min_stake).is_some());
+
+        // Inflate the total stake so the estimate isn't saturated
+        let init_era_info = CurrentEraInfo::<Test>::get();
+        let total_staked: Balance = 1_000_000_000_000_000;
+        CurrentEraInfo::<Test>::mutate(|era_info| {
+            era_info.next_stake_amount.voting = total_staked;
+        });
+
+        let stake = lowest_threshold;
+        let (staker_reward_pool, _) =
+            <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(
+                total_staked + stake,
+            );
+        let era_reward = Perbill::from_rational(stake, total_staked + stake) * staker_reward_pool;
+        let annual_reward = era_reward
+            * Balance::from(<Test as Config>::CycleConfiguration::build_and_earn_eras_per_cycle());
+        let expected_apr = Perbill::from_rational(annual_reward, stake);
+        assert!(expected_apr < Perbill::one(), "Sanity check.");
+
+        assert_eq!(
+            DappStaking::estimate_apr(&smart_contract, stake),
+            Some(expected_apr)
+        );
+
+        // Restore the era info so the try-state checks pass
+        CurrentEraInfo::<Test>::put(init_era_info);
+    })
+}
+
+#[test]
+fn staking_constants_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let constants = DappStaking::staking_constants();
+
+        assert_eq!(
+            constants.minimum_locked_amount,
+            <Test as Config>::MinimumLockedAmount::get()
+        );
+        assert_eq!(
+            constants.minimum_stake_amount,
+            <Test as Config>::MinimumStakeAmount::get()
+        );
+        assert_eq!(
+            constants.max_number_of_staked_contracts,
+            <Test as Config>::MaxNumberOfStakedContracts::get()
+        );
+        assert_eq!(
+            constants.max_unlocking_chunks,
+            <Test as Config>::MaxUnlockingChunks::get()
+        );
+        assert_eq!(
+            constants.unlocking_period,
+            <Test as Config>::UnlockingPeriod::get()
+                * <Test as Config>::CycleConfiguration::blocks_per_era()
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_to_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let staker_account = 2;
+        assert_lock(staker_account, 300);
+        assert_stake(staker_account, &smart_contract, 93);
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        // Blacklisted account cannot be used as the reward destination
+        assert_noop!(
+            DappStaking::claim_staker_rewards_to(
+                RuntimeOrigin::signed(staker_account),
+                BLACKLISTED_ACCOUNT
+            ),
+            Error::<Test>::InvalidRewardDestination
+        );
+
+        // Claim rewards to a new account, with no prior balance
+        let destination = 1234;
+        let init_staker_balance = Balances::free_balance(&staker_account);
+        assert!(Balances::free_balance(&destination).is_zero());
+
+        assert_ok!(DappStaking::claim_staker_rewards_to(
+            RuntimeOrigin::signed(staker_account),
+            destination
+        ));
+        let reward = <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0;
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::RewardTo {
+            account: staker_account,
+            destination,
+            era: ActiveProtocolState::<Test>::get().era - 1,
+            // for this simple test, entire staker reward pool goes to the staker
+            amount: reward,
+        }));
+
+        assert_eq!(Balances::free_balance(&destination), reward);
+        assert_eq!(
+            Balances::free_balance(&staker_account),
+            init_staker_balance,
+            "Staker balance must not change since reward is deposited to the destination."
+        );
+
+        // Rewards cannot be claimed again
+        assert_noop!(
+            DappStaking::claim_staker_rewards_to(
+                RuntimeOrigin::signed(staker_account),
+                destination
+            ),
+            Error::<Test>::NoClaimableRewards
+        );
+    })
+}
+
+#[test]
+fn dapp_current_standing_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1);
+        assert!(DappStaking::dapp_current_standing(&smart_contract).is_none());
+
+        assert_register(1, &smart_contract);
+        assert_eq!(
+            DappStaking::dapp_current_standing(&smart_contract),
+            Some((0, 0, None))
+        );
+
+        // Stake is only valid from the next era.
+        let tier_config = TierConfig::<Test>::get();
+        let amount = tier_config.tier_thresholds[1];
+        let account = 2;
+        Balances::make_free_balance_be(&account, amount * 2);
+        assert_lock(account, amount * 2);
+        assert_stake(account, &smart_contract, amount);
+        assert_eq!(
+            DappStaking::dapp_current_standing(&smart_contract),
+            Some((0, amount, None))
+        );
+
+        // Once the stake is active, the projected tier is based on it.
+        advance_to_next_era();
+        let tier_thresholds = TierConfig::<Test>::get().tier_thresholds;
+        let expected_tier = tier_thresholds
+            .iter()
+            .position(|threshold| amount >= *threshold)
+            .map(|tier_id| tier_id as u8);
+        assert!(expected_tier.is_some());
+        assert_eq!(
+            DappStaking::dapp_current_standing(&smart_contract),
+            Some((amount, amount, expected_tier))
+        );
+
+        // Additional stake only affects the next era amount.
+        assert_stake(account, &smart_contract, amount);
+        assert_eq!(
+            DappStaking::dapp_current_standing(&smart_contract),
+            Some((amount, amount * 2, expected_tier))
+        );
+    })
+}
+
+#[test]
+fn bonus_status_is_ok() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(2)
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1);
+            assert_register(1, &smart_contract);
+
+            let (voter, builder) = (2, 3);
+            assert!(DappStaking::bonus_status(&voter, &smart_contract).is_none());
+
+            // Stake in the `Voting` subperiod is eligible for the bonus, with all safe moves available.
+            assert_lock(voter, 100);
+            assert_stake(voter, &smart_contract, 100);
+            assert_eq!(DappStaking::bonus_status(&voter, &smart_contract), Some(3));
+
+            // Stake in the `Build&Earn` subperiod isn't eligible for the bonus.
+            advance_to_next_era();
+            assert_lock(builder, 100);
+            assert_stake(builder, &smart_contract, 100);
+            assert_eq!(
+                DappStaking::bonus_status(&builder, &smart_contract),
+                Some(0)
+            );
+        })
+}
+
+#[test]
+fn total_rewards_paid_is_tracked_per_era() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let claim_era = ActiveProtocolState::<Test>::get().era;
+        assert!(TotalRewardsPaid::<Test>::get(claim_era).is_zero());
+
+        let pre_balance = Balances::free_balance(&account);
+        assert_claim_staker_rewards(account);
+        let paid = Balances::free_balance(&account) - pre_balance;
+        assert!(!paid.is_zero());
+        assert_eq!(TotalRewardsPaid::<Test>::get(claim_era), paid);
+
+        // Summary is emitted once the era ends.
+        advance_to_next_era();
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::EraRewardsSummary {
+            era: claim_era,
+            total_paid: paid,
+        }));
+        assert!(TotalRewardsPaid::<Test>::get(claim_era + 1).is_zero());
+    })
+}
+
+#[test]
+fn period_era_range_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let eras_per_bep: EraNumber =
+            <Test as Config>::CycleConfiguration::eras_per_build_and_earn_subperiod();
+
+        // Non-existing & future periods
+        assert!(DappStaking::period_era_range(0).is_none());
+        assert!(DappStaking::period_era_range(2).is_none());
+
+        // Ongoing period, projected last era
+        let expected_range = (1, 1 + eras_per_bep);
+        assert_eq!(DappStaking::period_era_range(1), Some(expected_range));
+        advance_to_next_era();
+        assert_eq!(DappStaking::period_era_range(1), Some(expected_range));
+
+        // Finished period uses the recorded period end
+        advance_to_next_period();
+        let final_era = PeriodEnd::<Test>::get(1).unwrap().final_era;
+        assert_eq!(DappStaking::period_era_range(1), Some((1, final_era)));
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        assert_eq!(current_era, final_era + 1, "Sanity check");
+        assert_eq!(
+            DappStaking::period_era_range(2),
+            Some((current_era, current_era + eras_per_bep))
+        );
+        assert!(DappStaking::period_era_range(3).is_none());
+    })
+}
+
+#[test]
+fn stake_is_capped_by_max_stake_per_contract() {
+    ExtBuilder::default()
+        .with_max_stake_per_contract(Some(100))
+        .build_and_execute(|| {
+            let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+            let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+            assert_register(1, &smart_contract_1);
+            assert_register(1, &smart_contract_2);
+
+            let (account_1, account_2) = (2, 3);
+            assert_lock(account_1, 300);
+            assert_lock(account_2, 300);
+
+            // Stake exactly up to the cap
+            assert_stake(account_1, &smart_contract_1, 60);
+            assert_stake(account_2, &smart_contract_1, 40);
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(account_1), smart_contract_1, 1),
+                Error::<Test>::ContractStakeCapExceeded
+            );
+
+            // Moving stake frees up space on the source contract
+            assert_stake(account_1, &smart_contract_2, 50);
+            assert_move_stake(account_1, &smart_contract_1, &smart_contract_2, 10);
+            assert_stake(account_2, &smart_contract_1, 10);
+
+            // Moving stake into a contract at the cap fails
+            assert_stake(account_2, &smart_contract_2, 40);
+            assert_noop!(
+                DappStaking::move_stake(
+                    RuntimeOrigin::signed(account_1),
+                    smart_contract_1,
+                    smart_contract_2,
+                    1
+                ),
+                Error::<Test>::ContractStakeCapExceeded
+            );
+        })
+}
+
+#[test]
+fn transfer_position_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        // Prepare a position with locked, unlocking & staked amounts
+        let (source, dest) = (2, 100);
+        assert_lock(source, 300);
+        assert_stake(source, &smart_contract_1, 100);
+        assert_stake(source, &smart_contract_2, 50);
+        assert_unlock(source, 20);
+
+        // Destination approves, then the position is transferred
+        assert_ok!(DappStaking::approve_position_transfer(
+            RuntimeOrigin::signed(dest),
+            source,
+            dest
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::PositionTransferApproved {
+            source,
+            dest,
+        }));
+        assert_transfer_position(source, dest);
+
+        // Destination can keep using the position, in the next period as well
+        assert_unstake(dest, &smart_contract_1, 10);
+        run_for_blocks(DappStaking::unlocking_period());
+        assert_claim_unlocked(dest);
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(dest) {
+            assert_claim_staker_rewards(dest);
+        }
+        assert_claim_bonus_reward(dest, &smart_contract_1);
+
+        // Source can start over with a fresh position
+        assert_lock(source, 100);
+    })
+}
+
+#[test]
+fn transfer_position_with_manager_approval_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (source, dest) = (2, 3);
+        assert_lock(source, 300);
+        assert_stake(source, &smart_contract, 100);
+
+        assert_ok!(DappStaking::approve_position_transfer(
+            RuntimeOrigin::signed(ManagerAccount::get()),
+            source,
+            dest
+        ));
+        assert_transfer_position(source, dest);
+    })
+}
+
+#[test]
+fn approve_position_transfer_with_bad_origin_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let (source, dest) = (2, 3);
+
+        // Neither the source, nor a random account can approve on behalf of the destination
+        assert_noop!(
+            DappStaking::approve_position_transfer(RuntimeOrigin::signed(source), source, dest),
+            BadOrigin
+        );
+        assert_noop!(
+            DappStaking::approve_position_transfer(RuntimeOrigin::signed(4), source, dest),
+            BadOrigin
+        );
+        assert_noop!(
+            DappStaking::approve_position_transfer(RuntimeOrigin::none(), source, dest),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn transfer_position_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (source, dest) = (2, 3);
+        assert_lock(source, 300);
+        assert_stake(source, &smart_contract, 100);
+
+        // Transfer without approval
+        assert_noop!(
+            DappStaking::transfer_position(RuntimeOrigin::signed(source), dest),
+            Error::<Test>::PositionTransferNotApproved
+        );
+
+        // Approval given for a different source account
+        assert_ok!(DappStaking::approve_position_transfer(
+            RuntimeOrigin::signed(dest),
+            4,
+            dest
+        ));
+        assert_noop!(
+            DappStaking::transfer_position(RuntimeOrigin::signed(source), dest),
+            Error::<Test>::PositionTransferNotApproved
+        );
+
+        // Destination already has a position
+        assert_ok!(DappStaking::approve_position_transfer(
+            RuntimeOrigin::signed(dest),
+            source,
+            dest
+        ));
+        assert_lock(dest, 100);
+        assert_noop!(
+            DappStaking::transfer_position(RuntimeOrigin::signed(source), dest),
+            Error::<Test>::DestinationHasPosition
+        );
+
+        // Source has no position
+        let (empty_source, fresh_dest) = (5, 6);
+        assert_ok!(DappStaking::approve_position_transfer(
+            RuntimeOrigin::signed(fresh_dest),
+            empty_source,
+            fresh_dest
+        ));
+        assert_noop!(
+            DappStaking::transfer_position(RuntimeOrigin::signed(empty_source), fresh_dest),
+            Error::<Test>::NoPositionToTransfer
+        );
+
+        // Destination isn't allowed to stake
+        assert_ok!(DappStaking::approve_position_transfer(
+            RuntimeOrigin::signed(BLACKLISTED_ACCOUNT),
+            source,
+            BLACKLISTED_ACCOUNT
+        ));
+        assert_noop!(
+            DappStaking::transfer_position(RuntimeOrigin::signed(source), BLACKLISTED_ACCOUNT),
+            Error::<Test>::AccountNotAvailableForDappStaking
+        );
+
+        // Pallet is disabled
+        assert_ok!(DappStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert_noop!(
+            DappStaking::transfer_position(RuntimeOrigin::signed(source), dest),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::approve_position_transfer(RuntimeOrigin::signed(dest), source, dest),
+            Error::<Test>::Disabled
+        );
+    })
+}
+
+#[test]
+fn set_stake_delegate_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let (owner, delegate) = (2, 3);
+
+        // Set the delegate
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            Some(delegate)
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeDelegateSet {
+            owner,
+            delegate: Some(delegate),
+        }));
+        assert_eq!(StakeDelegate::<Test>::get(&owner), Some(delegate));
+
+        // Replace it with another one
+        let new_delegate = 4;
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            Some(new_delegate)
+        ));
+        assert_eq!(StakeDelegate::<Test>::get(&owner), Some(new_delegate));
+
+        // Clear it
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeDelegateSet {
+            owner,
+            delegate: None,
+        }));
+        assert!(!StakeDelegate::<Test>::contains_key(&owner));
+    })
+}
+
+#[test]
+fn delegated_staking_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let (owner, delegate) = (2, 3);
+        assert_lock(owner, 300);
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            Some(delegate)
+        ));
+        let delegate_balance = Balances::total_balance(&delegate);
+
+        // Stake on behalf of the owner, using the owner's locked funds
+        assert_ok!(DappStaking::stake_delegated(
+            RuntimeOrigin::signed(delegate),
+            owner,
+            smart_contract_1,
+            200
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Stake {
+            account: owner,
+            smart_contract: smart_contract_1,
+            amount: 200,
+        }));
+        assert_eq!(
+            StakerInfo::<Test>::get(&owner, &smart_contract_1)
+                .unwrap()
+                .total_staked_amount(),
+            200
+        );
+        assert!(!Ledger::<Test>::contains_key(&delegate));
+
+        // Unstake & move stake on behalf of the owner
+        assert_ok!(DappStaking::unstake_delegated(
+            RuntimeOrigin::signed(delegate),
+            owner,
+            smart_contract_1,
+            50
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Unstake {
+            account: owner,
+            smart_contract: smart_contract_1,
+            amount: 50,
+        }));
+        assert_ok!(DappStaking::move_stake_delegated(
+            RuntimeOrigin::signed(delegate),
+            owner,
+            smart_contract_1,
+            smart_contract_2,
+            100
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+            account: owner,
+            source_contract: smart_contract_1,
+            destination_contract: smart_contract_2,
+            amount: 100,
+        }));
+        assert_eq!(Ledger::<Test>::get(&owner).staked_amount(1), 150);
+
+        // Rewards are paid out to the owner, delegate's balance remains unchanged
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        for _ in 0..required_number_of_reward_claims(owner) {
+            assert_claim_staker_rewards(owner);
+        }
+        assert_eq!(Balances::total_balance(&delegate), delegate_balance);
+        assert!(!Ledger::<Test>::contains_key(&delegate));
+    })
+}
+
+#[test]
+fn delegated_staking_without_delegation_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let (owner, delegate, other) = (2, 3, 4);
+        assert_lock(owner, 300);
+        assert_stake(owner, &smart_contract_1, 100);
+
+        let assert_not_delegate = |caller: AccountId| {
+            assert_noop!(
+                DappStaking::stake_delegated(
+                    RuntimeOrigin::signed(caller),
+                    owner,
+                    smart_contract_1,
+                    100
+                ),
+                Error::<Test>::NotStakeDelegate
+            );
+            assert_noop!(
+                DappStaking::unstake_delegated(
+                    RuntimeOrigin::signed(caller),
+                    owner,
+                    smart_contract_1,
+                    50
+                ),
+                Error::<Test>::NotStakeDelegate
+            );
+            assert_noop!(
+                DappStaking::move_stake_delegated(
+                    RuntimeOrigin::signed(caller),
+                    owner,
+                    smart_contract_1,
+                    smart_contract_2,
+                    50
+                ),
+                Error::<Test>::NotStakeDelegate
+            );
+        };
+
+        // No delegate set, not even the owner can use the delegated calls
+        assert_not_delegate(delegate);
+        assert_not_delegate(owner);
+
+        // Only the delegate set by the owner is allowed
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            Some(delegate)
+        ));
+        assert_not_delegate(other);
+        assert_not_delegate(owner);
+
+        // Delegation is directional, the owner cannot act on behalf of the delegate
+        assert_lock(delegate, 300);
+        assert_noop!(
+            DappStaking::stake_delegated(
+                RuntimeOrigin::signed(owner),
+                delegate,
+                smart_contract_1,
+                100
+            ),
+            Error::<Test>::NotStakeDelegate
+        );
+
+        // Once the delegation is replaced or cleared, the previous delegate can no longer act
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            Some(other)
+        ));
+        assert_not_delegate(delegate);
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            None
+        ));
+        assert_not_delegate(other);
+
+        // Unsigned origin is rejected
+        assert_noop!(
+            DappStaking::stake_delegated(RuntimeOrigin::none(), owner, smart_contract_1, 100),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn delegated_staking_respects_owner_limits() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (owner, delegate) = (2, 3);
+        assert_ok!(DappStaking::set_stake_delegate(
+            RuntimeOrigin::signed(owner),
+            Some(delegate)
+        ));
+
+        // Delegate's own locked funds cannot be used for the owner's stake
+        assert_lock(delegate, 300);
+        assert_noop!(
+            DappStaking::stake_delegated(
+                RuntimeOrigin::signed(delegate),
+                owner,
+                smart_contract,
+                100
+            ),
+            Error::<Test>::UnavailableStakeFunds
+        );
+
+        // Cannot stake more than the owner has locked
+        assert_lock(owner, 100);
+        assert_noop!(
+            DappStaking::stake_delegated(
+                RuntimeOrigin::signed(delegate),
+                owner,
+                smart_contract,
+                101
+            ),
+            Error::<Test>::UnavailableStakeFunds
+        );
+
+        // Cannot unstake what the owner hasn't staked
+        assert_noop!(
+            DappStaking::unstake_delegated(
+                RuntimeOrigin::signed(delegate),
+                owner,
+                smart_contract,
+                10
+            ),
+            Error::<Test>::NoStakingInfo
+        );
+    })
+}
+
+#[test]
+fn tier_config_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let assert_matches_storage = || {
+            let view = DappStaking::tier_config();
+            let tier_config = TierConfig::<Test>::get();
+            assert_eq!(view.slots_per_tier, tier_config.slots_per_tier().to_vec());
+            assert_eq!(view.tier_thresholds, tier_config.tier_thresholds().to_vec());
+            assert_eq!(view.reward_portion, tier_config.reward_portion().to_vec());
+            assert_eq!(
+                view.total_number_of_slots,
+                view.slots_per_tier.iter().sum::<u16>()
+            );
+        };
+        assert_matches_storage();
+
+        // Tier configuration is recalculated at the start of a new period
+        Balances::set_total_issuance(Balances::total_issuance() * 2);
+        let init_thresholds = DappStaking::tier_config().tier_thresholds;
+        advance_to_next_period();
+        assert_ne!(DappStaking::tier_config().tier_thresholds, init_thresholds);
+        assert_matches_storage();
+    })
+}
+
+#[test]
+fn tier_threshold_smoothing_works() {
+    ExtBuilder::default()
+        .with_threshold_smoothing(Some(Permill::from_percent(50)))
+        .build_and_execute(|| {
+            // Large jump in the total issuance moves the target thresholds far away
+            advance_to_next_era();
+            Balances::set_total_issuance(Balances::total_issuance() * 10);
+            let target_thresholds = TierConfig::<Test>::get()
+                .calculate_new(&StaticTierParams::<Test>::get(), Balances::total_issuance())
+                .tier_thresholds;
+
+            // Each era, thresholds only move halfway towards the target
+            let mut previous_thresholds = TierConfig::<Test>::get().tier_thresholds;
+            for _ in 0..3 {
+                advance_to_next_era();
+                let thresholds = TierConfig::<Test>::get().tier_thresholds;
+
+                for ((threshold, previous), target) in thresholds
+                    .iter()
+                    .zip(previous_thresholds.iter())
+                    .zip(target_thresholds.iter())
+                {
+                    assert!(previous < threshold && threshold < target);
+                    assert_eq!(
+                        *threshold,
+                        previous + Permill::from_percent(50) * (target - previous)
+                    );
+                }
+                previous_thresholds = thresholds;
+            }
+        })
+}
+
+#[test]
+fn tier_thresholds_without_smoothing_are_applied_immediately() {
+    ExtBuilder::default().build_and_execute(|| {
+        advance_to_next_era();
+        Balances::set_total_issuance(Balances::total_issuance() * 10);
+        let target_thresholds = TierConfig::<Test>::get()
+            .calculate_new(&StaticTierParams::<Test>::get(), Balances::total_issuance())
+            .tier_thresholds;
+
+        advance_to_next_era();
+        assert_eq!(TierConfig::<Test>::get().tier_thresholds, target_thresholds);
+    })
+}
+
+#[test]
+fn available_tier_slots_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Three dApps satisfy the first tier threshold, but there are only two slots available.
+        let tier_config = TierConfig::<Test>::get();
+        assert_eq!(tier_config.slots_per_tier[0], 2, "Sanity check");
+        let amount = tier_config.tier_thresholds[0];
+        for id in 1..=3 {
+            let smart_contract = MockSmartContract::wasm(id);
+            assert_register(1, &smart_contract);
+
+            let account = 10 + id;
+            Balances::make_free_balance_be(&account, amount * 2);
+            assert_lock(account, amount);
+            assert_stake(account, &smart_contract, amount);
+        }
+
+        // No tier assignment during voting subperiod.
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().subperiod(),
+            Subperiod::Voting
+        );
+        assert_eq!(
+            DappStaking::available_tier_slots().into_inner(),
+            vec![0; tier_config.slots_per_tier.len()]
+        );
+
+        // The overflowing dApp is pushed into the second tier.
+        advance_to_next_subperiod();
+        let tier_config = TierConfig::<Test>::get();
+        let mut expected = tier_config.slots_per_tier.clone().into_inner();
+        expected[0] = 0;
+        expected[1] -= 1;
+        assert_eq!(DappStaking::available_tier_slots().into_inner(), expected);
+    })
+}
+
+#[test]
+fn history_cleanup_status_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Nothing to cleanup at the beginning
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        let (_, era, pending_entries) = DappStaking::history_cleanup_status();
+        assert_eq!(era, current_era);
+        assert!(pending_entries.is_zero());
+
+        // Both era reward spans & dApp tiers are pending cleanup
+        let span_length: EraNumber = <Test as Config>::EraRewardSpanLength::get();
+        let oldest_valid_era = 2 * span_length + 2;
+        HistoryCleanupMarker::<Test>::put(CleanupMarker {
+            era_reward_index: 1,
+            dapp_tiers_index: 3,
+            oldest_valid_era,
+        });
+
+        let expected_era_rewards = 3;
+        let expected_dapp_tiers = oldest_valid_era - 3;
+        assert_eq!(
+            DappStaking::history_cleanup_status(),
+            (1, current_era, expected_era_rewards + expected_dapp_tiers)
+        );
+    })
+}
+
+#[test]
+fn reconcile_held_funds_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_eq!(DappStaking::reconcile_held_funds(), (0, 0, true));
+
+        // Lock & unlock some funds
+        assert_lock(2, 300);
+        assert_lock(3, 200);
+        assert_unlock(3, 50);
+        assert_eq!(DappStaking::reconcile_held_funds(), (500, 500, true));
+
+        // Mismatch is reported
+        CurrentEraInfo::<Test>::mutate(|era_info| era_info.unlocking += 1);
+        assert_eq!(DappStaking::reconcile_held_funds(), (500, 501, false));
+        CurrentEraInfo::<Test>::mutate(|era_info| era_info.unlocking -= 1);
+    })
+}
+
+#[test]
+fn retained_era_window_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // No era rewards at the beginning
+        assert_eq!(DappStaking::retained_era_window(), (0, 0));
+
+        // Fill up a few spans
+        let span_length: EraNumber = <Test as Config>::EraRewardSpanLength::get();
+        advance_to_era(2 * span_length + 3);
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        assert_eq!(DappStaking::retained_era_window(), (1, current_era - 1));
+
+        // Cleanup the oldest span, window is adjusted accordingly
+        EraRewards::<Test>::remove(0);
+        HistoryCleanupMarker::<Test>::mutate(|marker| marker.era_reward_index = span_length);
+        assert_eq!(
+            DappStaking::retained_era_window(),
+            (span_length, current_era - 1)
+        );
+    })
+}
+
+#[test]
+fn eras_until_subperiod_change_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Voting subperiod lasts a single era
+        assert_eq!(DappStaking::eras_until_subperiod_change(), 1);
+
+        // Count down through the build&earn subperiod
+        advance_to_next_era();
+        let eras_per_build_and_earn: EraNumber =
+            <Test as Config>::CycleConfiguration::eras_per_build_and_earn_subperiod();
+        for remaining in (1..=eras_per_build_and_earn).rev() {
+            assert_eq!(DappStaking::eras_until_subperiod_change(), remaining);
+            assert_eq!(
+                ActiveProtocolState::<Test>::get().subperiod(),
+                Subperiod::BuildAndEarn
+            );
+            advance_to_next_era();
+        }
+
+        // New period starts with the voting subperiod
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().subperiod(),
+            Subperiod::Voting
+        );
+        assert_eq!(DappStaking::eras_until_subperiod_change(), 1);
+    })
+}
+
+#[test]
+fn claimable_dapp_rewards_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert!(DappStaking::claimable_dapp_rewards(&smart_contract).is_empty());
+
+        // Register smart contract, lock&stake some amount
+        assert_register(1, &smart_contract);
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Nothing to claim until a build&earn era ends
+        assert!(DappStaking::claimable_dapp_rewards(&smart_contract).is_empty());
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 4);
+        assert_eq!(ActiveProtocolState::<Test>::get().era, 5, "Sanity check");
+        assert_eq!(
+            DappStaking::claimable_dapp_rewards(&smart_contract),
+            vec![2, 3, 4]
+        );
+
+        // Claimed eras are no longer listed
+        assert_claim_dapp_reward(account, &smart_contract, 3);
+        assert_eq!(
+            DappStaking::claimable_dapp_rewards(&smart_contract),
+            vec![2, 4]
+        );
+
+        // All of the remaining build&earn eras of the period are listed
+        let staked_period = ActiveProtocolState::<Test>::get().period_number();
+        let reward_retention_in_periods: PeriodNumber =
+            <Test as Config>::RewardRetentionInPeriods::get();
+        advance_to_period(staked_period + reward_retention_in_periods);
+        let final_era = PeriodEnd::<Test>::get(staked_period).unwrap().final_era;
+        let expected_eras: Vec<EraNumber> = [2].into_iter().chain(4..=final_era).collect();
+        assert_eq!(
+            DappStaking::claimable_dapp_rewards(&smart_contract),
+            expected_eras
+        );
+
+        // Once expired, none of them are listed
+        advance_to_next_period();
+        assert!(DappStaking::claimable_dapp_rewards(&smart_contract).is_empty());
+    })
+}
+
+#[test]
+fn set_slots_per_tier_override_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let calculated_slots = TierConfig::<Test>::get().slots_per_tier;
+        let slots: BoundedVec<u16, <Test as Config>::NumberOfTiers> =
+            BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        assert_ne!(calculated_slots, slots, "Sanity check");
+
+        assert_ok!(DappStaking::set_slots_per_tier_override(
+            RuntimeOrigin::signed(ManagerAccount::get()),
+            Some(slots.clone())
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::SlotsPerTierOverrideSet {
+            slots: Some(slots.clone()),
+        }));
+
+        // Override is applied from the next era, and remains in effect across era & period changes
+        assert_eq!(TierConfig::<Test>::get().slots_per_tier, calculated_slots);
+        advance_to_next_era();
+        assert_eq!(TierConfig::<Test>::get().slots_per_tier, slots);
+        advance_to_next_period();
+        advance_to_next_era();
+        assert_eq!(TierConfig::<Test>::get().slots_per_tier, slots);
+
+        // Once cleared, calculated values are used again from the next era
+        assert_ok!(DappStaking::set_slots_per_tier_override(
+            RuntimeOrigin::root(),
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::SlotsPerTierOverrideSet {
+            slots: None,
+        }));
+        assert!(SlotsPerTierOverride::<Test>::get().is_none());
+        advance_to_next_era();
+        assert_eq!(TierConfig::<Test>::get().slots_per_tier, calculated_slots);
+    })
+}
+
+#[test]
+fn set_slots_per_tier_override_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let slots: BoundedVec<u16, <Test as Config>::NumberOfTiers> =
+            BoundedVec::try_from(vec![1, 2, 3, 4]).unwrap();
+        assert_noop!(
+            DappStaking::set_slots_per_tier_override(RuntimeOrigin::signed(1), Some(slots)),
+            BadOrigin
+        );
+
+        let too_short = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_noop!(
+            DappStaking::set_slots_per_tier_override(RuntimeOrigin::root(), Some(too_short)),
+            Error::<Test>::InvalidSlotsPerTierOverride
+        );
+    })
+}
+
+#[test]
+fn can_stake_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let account = 2;
+        let lock_amount = 300;
+
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, 100),
+            Err(StakePreflightError::ContractNotFound)
+        );
+        assert_register(1, &smart_contract);
+
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, 100),
+            Err(StakePreflightError::UnavailableStakeFunds)
+        );
+        assert_lock(account, lock_amount);
+
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, 0),
+            Err(StakePreflightError::ZeroAmount)
+        );
+        let min_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, min_stake_amount - 1),
+            Err(StakePreflightError::InsufficientStakeAmount)
+        );
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, lock_amount + 1),
+            Err(StakePreflightError::UnavailableStakeFunds)
+        );
+
+        // Successful check doesn't modify the state
+        assert_storage_noop!(assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, lock_amount),
+            Ok(())
+        ));
+        assert_stake(account, &smart_contract, 100);
+
+        // Unclaimed rewards from past eras must be claimed first
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, 100),
+            Err(StakePreflightError::UnclaimedRewards)
+        );
+
+        assert_ok!(DappStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert_eq!(
+            DappStaking::can_stake(&account, &smart_contract, 100),
+            Err(StakePreflightError::Disabled)
+        );
+    })
+}
+
+#[test]
+fn set_dapp_auto_compound_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert!(!DAppAutoCompound::<Test>::get(dapp_id));
+
+        // Enable auto-compounding
+        assert_ok!(DappStaking::set_dapp_auto_compound(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppAutoCompoundUpdated {
+            smart_contract,
+            enabled: true,
+        }));
+        assert!(DAppAutoCompound::<Test>::get(dapp_id));
+
+        // Disable it again
+        assert_ok!(DappStaking::set_dapp_auto_compound(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppAutoCompoundUpdated {
+            smart_contract,
+            enabled: false,
+        }));
+        assert!(!DAppAutoCompound::<Test>::contains_key(dapp_id));
+
+        // Only the owner can change it
+        assert_noop!(
+            DappStaking::set_dapp_auto_compound(RuntimeOrigin::signed(2), smart_contract, true),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::set_dapp_auto_compound(
+                RuntimeOrigin::signed(owner),
+                MockSmartContract::Wasm(4),
+                true
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Entry is cleaned up on unregistration
+        assert_ok!(DappStaking::set_dapp_auto_compound(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+        assert_unregister(&smart_contract);
+        assert!(!DAppAutoCompound::<Test>::contains_key(dapp_id));
+    })
+}
+
+#[test]
+fn set_dapp_auto_claim_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert!(!DAppAutoClaim::<Test>::contains_key(dapp_id));
+
+        // Enable auto-claiming
+        assert_ok!(DappStaking::set_dapp_auto_claim(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppAutoClaimUpdated {
+            smart_contract,
+            enabled: true,
+        }));
+        assert_eq!(DAppAutoClaim::<Test>::get(dapp_id), Some(smart_contract));
+
+        // Disable it again
+        assert_ok!(DappStaking::set_dapp_auto_claim(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppAutoClaimUpdated {
+            smart_contract,
+            enabled: false,
+        }));
+        assert!(!DAppAutoClaim::<Test>::contains_key(dapp_id));
+
+        // Only the owner can change it
+        assert_noop!(
+            DappStaking::set_dapp_auto_claim(RuntimeOrigin::signed(2), smart_contract, true),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::set_dapp_auto_claim(
+                RuntimeOrigin::signed(owner),
+                MockSmartContract::Wasm(4),
+                true
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Entry is cleaned up on unregistration
+        assert_ok!(DappStaking::set_dapp_auto_claim(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+        assert_unregister(&smart_contract);
+        assert!(!DAppAutoClaim::<Test>::contains_key(dapp_id));
+    })
+}
+
+#[test]
+fn dapp_auto_claim_respects_per_block_budget() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register & stake on more dApps than can be auto-claimed in a single block
+        let max_claims: u32 = <Test as Config>::MaxAutoClaimsPerBlock::get();
+        let number_of_dapps = max_claims + 1;
+        let staker = 100;
+        assert_lock(staker, 1000);
+        let smart_contracts: Vec<_> = (1..=number_of_dapps)
+            .map(|idx| {
+                let smart_contract = MockSmartContract::wasm(idx as AccountId);
+                assert_register(idx as AccountId, &smart_contract);
+                assert_stake(staker, &smart_contract, 100);
+                assert_ok!(DappStaking::set_dapp_auto_claim(
+                    RuntimeOrigin::signed(idx as AccountId),
+                    smart_contract,
+                    true
+                ));
+                smart_contract
+            })
+            .collect();
+
+        // dApp which didn't opt in isn't auto-claimed
+        let manual_contract = MockSmartContract::wasm(number_of_dapps as AccountId + 1);
+        assert_register(number_of_dapps as AccountId + 1, &manual_contract);
+        assert_stake(staker, &manual_contract, 100);
+        let manual_dapp_id = IntegratedDApps::<Test>::get(&manual_contract).unwrap().id;
+
+        advance_to_next_subperiod();
+        let era = ActiveProtocolState::<Test>::get().era;
+        advance_to_next_era();
+
+        // Nothing is claimed in the era transition block
+        let unclaimed = || DAppTiers::<Test>::get(era).unwrap().dapps.len() as u32;
+        assert_eq!(unclaimed(), number_of_dapps + 1);
+
+        // Auto-claim respects the per-block budget
+        run_for_blocks(1);
+        assert_eq!(unclaimed(), number_of_dapps + 1 - max_claims);
+        assert!(!AutoClaimCursor::<Test>::get().unwrap().complete);
+
+        // Remaining dApps are processed in the next block
+        run_for_blocks(1);
+        let dapp_tiers = DAppTiers::<Test>::get(era).unwrap();
+        assert_eq!(dapp_tiers.dapps.len(), 1);
+        assert!(dapp_tiers.dapps.contains_key(&manual_dapp_id));
+        assert!(AutoClaimCursor::<Test>::get().unwrap().complete);
+
+        // Rewards were paid out to the dApp owners
+        for (idx, smart_contract) in smart_contracts.iter().enumerate() {
+            let dapp_id = IntegratedDApps::<Test>::get(smart_contract).unwrap().id;
+            assert!(
+                !DAppLifetimeRewards::<Test>::get(dapp_id).is_zero(),
+                "dApp {} must have been auto-claimed",
+                idx
+            );
+        }
+
+        // Manual claim still works for the dApp which didn't opt in
+        assert_claim_dapp_reward(staker, &manual_contract, era);
+    })
+}
+
+#[test]
+fn claim_dapp_reward_with_auto_compound_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(owner, &smart_contract);
+        assert_ok!(DappStaking::set_dapp_auto_compound(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+
+        let staker = 2;
+        assert_lock(staker, 300);
+        assert_stake(staker, &smart_contract, 300);
+
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let (reward, _) = DAppTiers::<Test>::get(&era)
+            .unwrap()
+            .try_claim(IntegratedDApps::<Test>::get(&smart_contract).unwrap().id)
+            .unwrap();
+        let pre_free_balance = Balances::free_balance(owner);
+
+        assert_ok!(DappStaking::claim_dapp_reward(
+            RuntimeOrigin::signed(staker),
+            smart_contract,
+            era
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardCompounded {
+            beneficiary: owner,
+            smart_contract,
+            amount: reward,
+        }));
+
+        // Reward is paid out, but locked & staked on the same contract
+        assert_eq!(Balances::free_balance(owner), pre_free_balance + reward);
+        assert_eq!(Ledger::<Test>::get(&owner).active_locked_amount(), reward);
+        assert_eq!(
+            StakerInfo::<Test>::get(&owner, &smart_contract)
+                .expect("Entry must exist after compounding.")
+                .total_staked_amount(),
+            reward
+        );
+    })
+}
+
+#[test]
+fn claim_dapp_reward_with_auto_compound_failure_keeps_reward_liquid() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(owner, &smart_contract);
+        assert_ok!(DappStaking::set_dapp_auto_compound(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+
+        // Owner stakes too, and won't claim the staker rewards
+        assert_lock(owner, 300);
+        assert_stake(owner, &smart_contract, 300);
+
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let pre_ledger = Ledger::<Test>::get(&owner);
+        let pre_staker_info = StakerInfo::<Test>::get(&owner, &smart_contract);
+
+        assert_ok!(DappStaking::claim_dapp_reward(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            era
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardCompoundFailed {
+            beneficiary: owner,
+            smart_contract,
+            error: Error::<Test>::UnclaimedRewards.into(),
+        }));
+
+        // Neither the lock nor the stake are applied
+        assert_eq!(Ledger::<Test>::get(&owner), pre_ledger);
+        assert_eq!(
+            StakerInfo::<Test>::get(&owner, &smart_contract),
+            pre_staker_info
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_burn_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 93);
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        let burn_fraction = Permill::from_percent(30);
+        let init_balance = Balances::free_balance(&account);
+        let init_total_issuance = Balances::total_issuance();
+        assert_ok!(DappStaking::claim_staker_rewards_with_burn(
+            RuntimeOrigin::signed(account),
+            burn_fraction
+        ));
+
+        // for this simple test, entire staker reward pool goes to the staker
+        let reward = <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0;
+        let burned = burn_fraction * reward;
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::Reward {
+            account,
+            era: ActiveProtocolState::<Test>::get().era - 1,
+            amount: reward,
+        }));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakerRewardBurned {
+            account,
+            claimed: reward,
+            burned,
+        }));
+
+        assert_eq!(
+            Balances::free_balance(&account),
+            init_balance + reward - burned
+        );
+        assert_eq!(
+            Balances::total_issuance(),
+            init_total_issuance + reward - burned
+        );
+
+        // Rewards cannot be claimed again
+        assert_noop!(
+            DappStaking::claim_staker_rewards_with_burn(
+                RuntimeOrigin::signed(account),
+                burn_fraction
+            ),
+            Error::<Test>::NoClaimableRewards
+        );
+
+        // Zero burn fraction behaves the same as a regular claim
+        advance_to_next_era();
+        let init_balance = Balances::free_balance(&account);
+        assert_ok!(DappStaking::claim_staker_rewards_with_burn(
+            RuntimeOrigin::signed(account),
+            Permill::zero()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Reward {
+            account,
+            era: ActiveProtocolState::<Test>::get().era - 1,
+            amount: reward,
+        }));
+        assert_eq!(Balances::free_balance(&account), init_balance + reward);
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_full_burn_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 93);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        assert_noop!(
+            DappStaking::claim_staker_rewards_with_burn(
+                RuntimeOrigin::signed(account),
+                Permill::one()
+            ),
+            Error::<Test>::InvalidBurnFraction
+        );
+    })
+}
+
+#[test]
+fn set_tier_cap_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert!(DAppTierCap::<Test>::get(dapp_id).is_none());
+
+        // Set the cap
+        assert_ok!(DappStaking::set_tier_cap(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            Some(2)
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppTierCapSet {
+            smart_contract,
+            max_tier: Some(2),
+        }));
+        assert_eq!(DAppTierCap::<Test>::get(dapp_id), Some(2));
+
+        // Clear it
+        assert_ok!(DappStaking::set_tier_cap(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppTierCapSet {
+            smart_contract,
+            max_tier: None,
+        }));
+        assert!(!DAppTierCap::<Test>::contains_key(dapp_id));
+
+        // Only the owner can set it, and only to an existing tier
+        assert_noop!(
+            DappStaking::set_tier_cap(RuntimeOrigin::signed(2), smart_contract, Some(1)),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::set_tier_cap(
+                RuntimeOrigin::signed(owner),
+                MockSmartContract::Wasm(4),
+                Some(1)
+            ),
+            Error::<Test>::ContractNotFound
+        );
+        let number_of_tiers: u32 = <Test as Config>::NumberOfTiers::get();
+        assert_noop!(
+            DappStaking::set_tier_cap(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                Some(number_of_tiers as u8)
+            ),
+            Error::<Test>::InvalidTierCap
+        );
+
+        // Entry is cleaned up on unregistration
+        assert_ok!(DappStaking::set_tier_cap(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            Some(1)
+        ));
+        assert_unregister(&smart_contract);
+        assert!(!DAppTierCap::<Test>::contains_key(dapp_id));
+    })
+}
+
+#[test]
+fn capped_dapp_yields_its_tier_slot() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Only a single slot in the top tier.
+        TierConfig::<Test>::mutate(|config| {
+            config.slots_per_tier = BoundedVec::try_from(vec![1, 2, 2, 2]).unwrap();
+        });
+        let tier_config = TierConfig::<Test>::get();
+
+        // Both dApps qualify for the top tier, the 1st one has the larger stake.
+        let smart_contracts: Vec<_> = (1..=2)
+            .map(|x| {
+                let smart_contract = MockSmartContract::wasm(x);
+                assert_register(x, &smart_contract);
+
+                let amount = tier_config.tier_thresholds[0] + 3 - x as Balance;
+                Balances::make_free_balance_be(&x, amount);
+                assert_lock(x, amount);
+                assert_stake(x, &smart_contract, amount);
+                smart_contract
+            })
+            .collect();
+        let dapp_ids: Vec<_> = smart_contracts
+            .iter()
+            .map(|smart_contract| IntegratedDApps::<Test>::get(smart_contract).unwrap().id)
+            .collect();
+
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let tier_assignment = || {
+            DappStaking::get_dapp_tier_assignment_and_rewards(
+                protocol_state.era + 1,
+                protocol_state.period_number(),
+                1_000_000,
+            )
+            .0
+        };
+
+        let assignment = tier_assignment();
+        assert_eq!(assignment.dapps[&dapp_ids[0]].tier(), 0);
+        assert_eq!(assignment.dapps[&dapp_ids[1]].tier(), 1);
+
+        // Top dApp caps itself to the 2nd tier, freeing the top tier slot.
+        assert_ok!(DappStaking::set_tier_cap(
+            RuntimeOrigin::signed(1),
+            smart_contracts[0],
+            Some(1)
+        ));
+
+        let assignment = tier_assignment();
+        assert_eq!(assignment.dapps[&dapp_ids[0]].tier(), 1);
+        assert_eq!(assignment.dapps[&dapp_ids[1]].tier(), 0);
+    })
+}
+
+#[test]
+fn set_dapp_reward_opt_out_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert!(!DAppRewardOptOut::<Test>::get(dapp_id));
+
+        // Opt out
+        assert_ok!(DappStaking::set_dapp_reward_opt_out(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardOptOutUpdated {
+            smart_contract,
+            opted_out: true,
+        }));
+        assert!(DAppRewardOptOut::<Test>::get(dapp_id));
+
+        // Opt back in
+        assert_ok!(DappStaking::set_dapp_reward_opt_out(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardOptOutUpdated {
+            smart_contract,
+            opted_out: false,
+        }));
+        assert!(!DAppRewardOptOut::<Test>::contains_key(dapp_id));
+
+        // Only the owner of a registered dApp can do it
+        assert_noop!(
+            DappStaking::set_dapp_reward_opt_out(RuntimeOrigin::signed(2), smart_contract, true),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::set_dapp_reward_opt_out(
+                RuntimeOrigin::signed(owner),
+                MockSmartContract::Wasm(4),
+                true
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Entry is cleaned up on unregistration
+        assert_ok!(DappStaking::set_dapp_reward_opt_out(
+            RuntimeOrigin::signed(owner),
+            smart_contract,
+            true
+        ));
+        assert_unregister(&smart_contract);
+        assert!(!DAppRewardOptOut::<Test>::contains_key(dapp_id));
+    })
+}
+
+/// Registers two dApps which both qualify for the top tier, which has only a single slot.
+/// The 1st dApp has the larger stake. Returns the registered dApp Ids.
+fn setup_two_dapps_competing_for_top_tier() -> Vec<DAppId> {
+    TierConfig::<Test>::mutate(|config| {
+        config.slots_per_tier = BoundedVec::try_from(vec![1, 2, 2, 2]).unwrap();
+    });
+    let tier_config = TierConfig::<Test>::get();
+
+    (1..=2)
+        .map(|x| {
+            let smart_contract = MockSmartContract::wasm(x);
+            assert_register(x, &smart_contract);
+
+            let amount = tier_config.tier_thresholds[0] + 3 - x as Balance;
+            Balances::make_free_balance_be(&x, amount);
+            assert_lock(x, amount);
+            assert_stake(x, &smart_contract, amount);
+            IntegratedDApps::<Test>::get(&smart_contract).unwrap().id
+        })
+        .collect()
+}
+
+#[test]
+fn opted_out_dapp_slot_is_redistributed() {
+    ExtBuilder::default()
+        .with_opted_out_reward_handling(OptOutRewardHandling::Redistribute)
+        .build_and_execute(|| {
+            let dapp_ids = setup_two_dapps_competing_for_top_tier();
+
+            let protocol_state = ActiveProtocolState::<Test>::get();
+            let tier_assignment = || {
+                DappStaking::get_dapp_tier_assignment_and_rewards(
+                    protocol_state.era + 1,
+                    protocol_state.period_number(),
+                    1_000_000,
+                )
+                .0
+            };
+
+            let assignment = tier_assignment();
+            assert_eq!(assignment.dapps[&dapp_ids[0]].tier(), 0);
+            assert_eq!(assignment.dapps[&dapp_ids[1]].tier(), 1);
+            let top_tier_reward = assignment.rewards[0];
+
+            // Top dApp opts out, leaving the top tier slot & its reward to the other dApp.
+            assert_ok!(DappStaking::set_dapp_reward_opt_out(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1),
+                true
+            ));
+
+            let assignment = tier_assignment();
+            assert!(!assignment.dapps.contains_key(&dapp_ids[0]));
+            assert_eq!(assignment.dapps[&dapp_ids[1]].tier(), 0);
+            assert_eq!(assignment.rewards[0], top_tier_reward);
+        })
+}
+
+#[test]
+fn opted_out_dapp_reward_returns_to_pool() {
+    ExtBuilder::default()
+        .with_opted_out_reward_handling(OptOutRewardHandling::ReturnToPool)
+        .build_and_execute(|| {
+            let dapp_ids = setup_two_dapps_competing_for_top_tier();
+
+            let protocol_state = ActiveProtocolState::<Test>::get();
+            let tier_assignment = || {
+                DappStaking::get_dapp_tier_assignment_and_rewards(
+                    protocol_state.era + 1,
+                    protocol_state.period_number(),
+                    1_000_000,
+                )
+                .0
+            };
+            let before = tier_assignment();
+
+            // Top dApp opts out, but keeps occupying the top tier slot without being rewarded for it.
+            assert_ok!(DappStaking::set_dapp_reward_opt_out(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1),
+                true
+            ));
+
+            let assignment = tier_assignment();
+            assert!(!assignment.dapps.contains_key(&dapp_ids[0]));
+            assert_eq!(assignment.dapps[&dapp_ids[1]], before.dapps[&dapp_ids[1]]);
+            assert_eq!(assignment.rewards, before.rewards);
+            assert_eq!(assignment.rank_rewards, before.rank_rewards);
+        })
+}
+
+#[test]
+fn total_unlocking_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert!(DappStaking::total_unlocking().is_zero());
+
+        assert_lock(1, 100);
+        assert_lock(2, 200);
+        assert_unlock(1, 30);
+        assert_unlock(2, 50);
+        assert_eq!(DappStaking::total_unlocking(), 80);
+
+        // Claimed chunks are no longer in the unlocking process
+        run_for_blocks(DappStaking::unlocking_period());
+        assert_claim_unlocked(1);
+        assert_eq!(DappStaking::total_unlocking(), 50);
+    })
+}
+
+#[test]
+fn set_claim_reminder_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 1;
+        assert_lock(account, 100);
+        let era = ActiveProtocolState::<Test>::get().era;
+
+        // Set the reminder
+        assert_ok!(DappStaking::set_claim_reminder(
+            RuntimeOrigin::signed(account),
+            Some(2)
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimReminderSet {
+            account,
+            every: Some(2),
+        }));
+        assert_eq!(
+            ClaimReminderEra::<Test>::get(account),
+            Some(ClaimReminder {
+                every: 2,
+                next_era: era + 2,
+            })
+        );
+
+        // Clear it
+        assert_ok!(DappStaking::set_claim_reminder(
+            RuntimeOrigin::signed(account),
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimReminderSet {
+            account,
+            every: None,
+        }));
+        assert!(!ClaimReminderEra::<Test>::contains_key(account));
+    })
+}
+
+#[test]
+fn set_claim_reminder_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Account without locked funds
+        assert_noop!(
+            DappStaking::set_claim_reminder(RuntimeOrigin::signed(1), Some(1)),
+            Error::<Test>::ClaimReminderWithoutLock
+        );
+
+        // Zero interval
+        assert_lock(1, 100);
+        assert_noop!(
+            DappStaking::set_claim_reminder(RuntimeOrigin::signed(1), Some(0)),
+            Error::<Test>::InvalidClaimReminderInterval
+        );
+
+        // Disabled pallet
+        assert_ok!(DappStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert_noop!(
+            DappStaking::set_claim_reminder(RuntimeOrigin::signed(1), Some(1)),
+            Error::<Test>::Disabled
+        );
+    })
+}
+
+#[test]
+fn claim_reminder_due_is_emitted_and_anchor_advanced() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 1;
+        assert_lock(account, 100);
+        assert_ok!(DappStaking::set_claim_reminder(
+            RuntimeOrigin::signed(account),
+            Some(2)
+        ));
+        let era = ActiveProtocolState::<Test>::get().era;
+        let reminder_due = || dapp_staking_events().contains(&Event::ClaimReminderDue { account });
+
+        // Not due yet
+        System::reset_events();
+        advance_to_next_era();
+        assert!(!reminder_due());
+
+        // Due, anchor is moved by the interval
+        System::reset_events();
+        advance_to_next_era();
+        assert!(reminder_due());
+        assert_eq!(
+            ClaimReminderEra::<Test>::get(account).unwrap().next_era,
+            era + 4
+        );
+
+        // Full unlock removes the reminder at the next check
+        assert_unlock(account, 100);
+        run_for_blocks(DappStaking::unlocking_period());
+        assert_claim_unlocked(account);
+        assert!(Ledger::<Test>::get(account).is_empty());
+
+        System::reset_events();
+        advance_to_next_era();
+        assert!(!reminder_due());
+        assert!(!ClaimReminderEra::<Test>::contains_key(account));
+    })
+}
+
+#[test]
+fn claim_reminders_are_processed_in_bounded_batches() {
+    ExtBuilder::default().build_and_execute(|| {
+        let max_per_era: u32 = <Test as Config>::MaxClaimRemindersPerEra::get();
+        let accounts: Vec<AccountId> = (1..=max_per_era as AccountId + 2).collect();
+        for account in &accounts {
+            assert_lock(*account, 100);
+            assert_ok!(DappStaking::set_claim_reminder(
+                RuntimeOrigin::signed(*account),
+                Some(1)
+            ));
+        }
+        let due_accounts = || {
+            dapp_staking_events()
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::ClaimReminderDue { account } => Some(account),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // First batch is limited, the cursor remembers where it stopped
+        System::reset_events();
+        advance_to_next_era();
+        let first_batch = due_accounts();
+        assert_eq!(first_batch.len(), max_per_era as usize);
+        assert!(ClaimReminderCursor::<Test>::get().is_some());
+
+        // Second batch covers the rest, and the cursor is reset
+        System::reset_events();
+        advance_to_next_era();
+        let second_batch = due_accounts();
+        assert_eq!(second_batch.len(), 2);
+        assert!(ClaimReminderCursor::<Test>::get().is_none());
+
+        // Each account was reminded exactly once
+        let mut reminded: Vec<_> = first_batch.into_iter().chain(second_batch).collect();
+        reminded.sort();
+        assert_eq!(reminded, accounts);
+    })
+}
+
+#[test]
+fn dapp_lifetime_rewards_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_eq!(DappStaking::dapp_lifetime_rewards(&smart_contract), 0);
+
+        assert_register(1, &smart_contract);
+        assert_lock(2, 300);
+        assert_stake(2, &smart_contract, 300);
+        assert_eq!(DappStaking::dapp_lifetime_rewards(&smart_contract), 0);
+
+        let claimed_reward = |era| {
+            assert_claim_dapp_reward(2, &smart_contract, era);
+            match dapp_staking_events().last() {
+                Some(Event::DAppReward { amount, .. }) => *amount,
+                _ => panic!("Last event must be DAppReward."),
+            }
+        };
+
+        // Each claim is added to the total
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let first_reward = claimed_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert!(first_reward > 0);
+        assert_eq!(
+            DappStaking::dapp_lifetime_rewards(&smart_contract),
+            first_reward
+        );
+
+        advance_to_next_era();
+        let second_reward = claimed_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(
+            DappStaking::dapp_lifetime_rewards(&smart_contract),
+            first_reward + second_reward
+        );
+
+        // Entry is removed once the dApp is unregistered
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert_unregister(&smart_contract);
+        assert!(!DAppLifetimeRewards::<Test>::contains_key(dapp_id));
+        assert_eq!(DappStaking::dapp_lifetime_rewards(&smart_contract), 0);
+    })
+}
+
+#[test]
+fn unlock_during_voting_can_be_forbidden() {
+    ExtBuilder::default()
+        .with_lock_unlock_during_voting(false)
+        .build_and_execute(|| {
+            let account = 2;
+            assert_lock(account, 300);
+            assert_eq!(
+                ActiveProtocolState::<Test>::get().subperiod(),
+                Subperiod::Voting
+            );
+
+            // Unlocking isn't allowed during the voting subperiod
+            assert_noop!(
+                DappStaking::unlock(RuntimeOrigin::signed(account), 100),
+                Error::<Test>::UnlockForbiddenDuringVoting
+            );
+
+            // Unlocking is possible once build&earn starts
+            advance_to_next_subperiod();
+            assert_eq!(
+                ActiveProtocolState::<Test>::get().subperiod(),
+                Subperiod::BuildAndEarn
+            );
+            assert_unlock(account, 100);
+
+            // Claiming unlocked chunks is still possible during the next voting subperiod
+            advance_to_next_period();
+            assert_eq!(
+                ActiveProtocolState::<Test>::get().subperiod(),
+                Subperiod::Voting
+            );
+            assert_claim_unlocked(account);
+            assert_noop!(
+                DappStaking::unlock(RuntimeOrigin::signed(account), 100),
+                Error::<Test>::UnlockForbiddenDuringVoting
+            );
+        })
+}
+
+#[test]
+fn export_staker_state_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+
+        // Empty state for an unknown account
+        let export = DappStaking::export_staker_state(&account);
+        assert!(export.locked.is_zero());
+        assert!(export.unlocking.is_empty());
+        assert!(export.stakes.is_empty());
+
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        assert_lock(account, 500);
+        assert_stake(account, &smart_contract_1, 100);
+        assert_stake(account, &smart_contract_2, 150);
+        assert_unlock(account, 50);
+
+        let ledger = Ledger::<Test>::get(&account);
+        let export = DappStaking::export_staker_state(&account);
+        assert_eq!(export.locked, ledger.locked);
+        assert_eq!(
+            export.unlocking,
+            ledger
+                .unlocking
+                .iter()
+                .map(|chunk| (chunk.amount, chunk.unlock_block))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(export.staked.voting, ledger.staked.voting);
+        assert_eq!(export.staked.era, ledger.staked.era);
+        assert_eq!(
+            export
+                .staked_future
+                .map(|amount| (amount.voting, amount.era)),
+            ledger
+                .staked_future
+                .map(|amount| (amount.voting, amount.era))
+        );
+        assert_eq!(export.contract_stake_count, ledger.contract_stake_count);
+        assert_eq!(export.purpose, ledger.purpose);
+
+        // Every staking info entry is exported, including the bonus status
+        assert_eq!(export.stakes.len(), 2);
+        for entry in export.stakes {
+            let staking_info = StakerInfo::<Test>::get(&account, &entry.smart_contract)
+                .expect("Entry must exist.");
+            assert_eq!(entry.staked.voting, staking_info.staked.voting);
+            assert_eq!(
+                entry.staked.build_and_earn,
+                staking_info.staked.build_and_earn
+            );
+            assert_eq!(entry.staked.era, staking_info.staked.era);
+            assert_eq!(entry.staked.period, staking_info.staked.period);
+            assert_eq!(
+                entry.previous_staked.voting,
+                staking_info.previous_staked.voting
+            );
+            assert_eq!(entry.bonus_status, staking_info.bonus_status);
+        }
+    })
+}
+
+#[test]
+fn claimed_eras_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        assert!(DappStaking::claimed_eras(&account).is_empty());
+
+        // Stake during voting, nothing claimed yet
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        assert!(DappStaking::claimed_eras(&account).is_empty());
+
+        // Claim some rewards, the range ends right before the ledger cursor
+        advance_to_next_subperiod();
+        let first_reward_era = ActiveProtocolState::<Test>::get().era;
+        advance_to_era(first_reward_era + 3);
+        assert_claim_staker_rewards(account);
+
+        let cursor = Ledger::<Test>::get(&account)
+            .earliest_staked_era()
+            .expect("Stake must exist.");
+        let claimed_eras = DappStaking::claimed_eras(&account);
+        assert_eq!(claimed_eras, (first_reward_era..cursor).collect::<Vec<_>>());
+        assert_eq!(claimed_eras.first(), Some(&first_reward_era));
+    })
+}
+
+#[test]
+fn reclaim_abandoned_is_disabled_by_default() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+        assert_lock(account, 300);
+        assert_unlock(account, 100);
+        run_to_block(Ledger::<Test>::get(&account).unlocking[0].unlock_block + 1_000);
+
+        assert_noop!(
+            DappStaking::reclaim_abandoned(RuntimeOrigin::signed(3), account),
+            Error::<Test>::AbandonedChunkReclaimDisabled
+        );
+    });
+
+    // Zero threshold is treated as disabled
+    ExtBuilder::default()
+        .with_abandoned_chunk_eras(Some(0))
+        .build_and_execute(|| {
+            let account = 2;
+            assert_lock(account, 300);
+            assert_unlock(account, 100);
+            run_to_block(Ledger::<Test>::get(&account).unlocking[0].unlock_block);
+
+            assert_noop!(
+                DappStaking::reclaim_abandoned(RuntimeOrigin::signed(3), account),
+                Error::<Test>::AbandonedChunkReclaimDisabled
+            );
+        })
+}
+
+#[test]
+fn reclaim_abandoned_is_ok() {
+    let abandoned_eras = 2;
+    ExtBuilder::default()
+        .with_abandoned_chunk_eras(Some(abandoned_eras))
+        .build_and_execute(|| {
+            let abandoned_blocks = <Test as Config>::CycleConfiguration::blocks_per_era()
+                * abandoned_eras as BlockNumber;
+
+            let account = 2;
+            let (lock_amount, unlock_amount) = (300, 100);
+            assert_lock(account, lock_amount);
+            assert_unlock(account, unlock_amount);
+            let unlock_block = Ledger::<Test>::get(&account).unlocking[0].unlock_block;
+
+            // Chunk is still unlocking
+            assert_noop!(
+                DappStaking::reclaim_abandoned(RuntimeOrigin::signed(3), account),
+                Error::<Test>::NoAbandonedChunks
+            );
+
+            // Chunk has matured, but hasn't been abandoned for long enough
+            run_to_block(unlock_block + abandoned_blocks - 1);
+            assert_noop!(
+                DappStaking::reclaim_abandoned(RuntimeOrigin::signed(3), account),
+                Error::<Test>::NoAbandonedChunks
+            );
+
+            // Chunk has been abandoned for long enough, anyone can reclaim it
+            run_to_block(unlock_block + abandoned_blocks);
+            let beneficiary = AbandonedChunkBeneficiary::get();
+            let pre_account_balance = Balances::total_balance(&account);
+            let pre_beneficiary_balance = Balances::total_balance(&beneficiary);
+            let pre_era_info = CurrentEraInfo::<Test>::get();
+
+            assert_ok!(DappStaking::reclaim_abandoned(
+                RuntimeOrigin::signed(3),
+                account
+            ));
+            System::assert_last_event(RuntimeEvent::DappStaking(Event::AbandonedChunksReclaimed {
+                account,
+                beneficiary,
+                amount: unlock_amount,
+            }));
+
+            assert_eq!(
+                Balances::total_balance(&account),
+                pre_account_balance - unlock_amount
+            );
+            assert_eq!(
+                Balances::total_balance(&beneficiary),
+                pre_beneficiary_balance + unlock_amount
+            );
+            let ledger = Ledger::<Test>::get(&account);
+            assert!(ledger.unlocking.is_empty());
+            assert_eq!(ledger.active_locked_amount(), lock_amount - unlock_amount);
+            assert_eq!(
+                CurrentEraInfo::<Test>::get().unlocking,
+                pre_era_info.unlocking - unlock_amount
+            );
+
+            // Nothing left to reclaim
+            assert_noop!(
+                DappStaking::reclaim_abandoned(RuntimeOrigin::signed(3), account),
+                Error::<Test>::NoAbandonedChunks
+            );
+        })
+}
+
+#[test]
+fn reclaim_abandoned_only_takes_abandoned_chunks() {
+    let abandoned_eras = 2;
+    ExtBuilder::default()
+        .with_abandoned_chunk_eras(Some(abandoned_eras))
+        .build_and_execute(|| {
+            let abandoned_blocks = <Test as Config>::CycleConfiguration::blocks_per_era()
+                * abandoned_eras as BlockNumber;
+
+            let account = 2;
+            assert_lock(account, 300);
+            assert_unlock(account, 50);
+            let old_unlock_block = Ledger::<Test>::get(&account).unlocking[0].unlock_block;
+
+            // Second chunk is created later, and will have matured only recently
+            run_to_block(old_unlock_block);
+            assert_unlock(account, 70);
+
+            run_to_block(old_unlock_block + abandoned_blocks);
+            assert_ok!(DappStaking::reclaim_abandoned(
+                RuntimeOrigin::signed(3),
+                account
+            ));
+
+            // Only the old chunk was reclaimed, the newer one can still be claimed by the staker
+            let ledger = Ledger::<Test>::get(&account);
+            assert_eq!(ledger.unlocking.len(), 1);
+            assert_eq!(ledger.unlocking[0].amount, 70);
+            assert_claim_unlocked(account);
+        })
+}
+
+#[test]
+fn reclaim_abandoned_with_full_unlock_cleans_up_ledger() {
+    let abandoned_eras = 1;
+    ExtBuilder::default()
+        .with_abandoned_chunk_eras(Some(abandoned_eras))
+        .build_and_execute(|| {
+            let abandoned_blocks = <Test as Config>::CycleConfiguration::blocks_per_era()
+                * abandoned_eras as BlockNumber;
+
+            let account = 2;
+            let amount = 300;
+            assert_lock(account, amount);
+            assert_unlock(account, amount);
+            let unlock_block = Ledger::<Test>::get(&account).unlocking[0].unlock_block;
+
+            run_to_block(unlock_block + abandoned_blocks);
+            assert_ok!(DappStaking::reclaim_abandoned(
+                RuntimeOrigin::signed(3),
+                account
+            ));
+
+            assert!(!Ledger::<Test>::contains_key(&account));
+            assert!(
+                Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &account).is_zero()
+            );
+        })
+}
+
+#[test]
+fn claim_dapp_reward_batch_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance a few eras so there are multiple eras to claim
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 4);
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        let (first_era, second_era, third_era) =
+            (current_era - 3, current_era - 2, current_era - 1);
+
+        // Claim one era upfront, it must be skipped by the batch
+        assert_claim_dapp_reward(account, &smart_contract, second_era);
+
+        let eras: BoundedVec<EraNumber, <Test as Config>::MaxDAppClaimBatch> =
+            vec![first_era, second_era, third_era, current_era]
+                .try_into()
+                .unwrap();
+        assert_ok!(DappStaking::claim_dapp_reward_batch(
+            RuntimeOrigin::signed(account),
+            smart_contract.clone(),
+            eras
+        ));
+
+        // One reward event per successfully claimed era
+        let claimed_eras: Vec<EraNumber> = dapp_staking_events()
+            .iter()
+            .filter_map(|event| match event {
+                Event::DAppReward { era, .. } => Some(*era),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(claimed_eras, vec![second_era, first_era, third_era]);
+
+        // Claimed eras can no longer be claimed
+        for era in [first_era, third_era] {
+            assert_noop!(
+                DappStaking::claim_dapp_reward(
+                    RuntimeOrigin::signed(account),
+                    smart_contract.clone(),
+                    era
+                ),
+                Error::<Test>::NoClaimableRewards,
+            );
+        }
+    })
+}
+
+#[test]
+fn claim_dapp_reward_batch_fails_if_nothing_claimed() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_noop!(
+            DappStaking::claim_dapp_reward_batch(
+                RuntimeOrigin::signed(1),
+                smart_contract.clone(),
+                BoundedVec::new()
+            ),
+            Error::<Test>::ContractNotFound,
+        );
+
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 300);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        let claim_era = ActiveProtocolState::<Test>::get().era - 1;
+        assert_claim_dapp_reward(account, &smart_contract, claim_era);
+
+        // Only already claimed & future eras are listed
+        let eras: BoundedVec<EraNumber, <Test as Config>::MaxDAppClaimBatch> =
+            vec![claim_era, claim_era + 5].try_into().unwrap();
+        assert_noop!(
+            DappStaking::claim_dapp_reward_batch(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                eras
+            ),
+            Error::<Test>::NoClaimableRewards,
+        );
+        assert_noop!(
+            DappStaking::claim_dapp_reward_batch(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                BoundedVec::new()
+            ),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn dapp_tier_rewards_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::Wasm(1);
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // No tier assignment for voting subperiod eras
+        let voting_era = ActiveProtocolState::<Test>::get().era;
+        advance_to_next_subperiod();
+        assert!(DappStaking::dapp_tier_rewards(voting_era).is_none());
+
+        // Tier assignment becomes available once the build&earn era ends
+        let era = ActiveProtocolState::<Test>::get().era;
+        assert!(DappStaking::dapp_tier_rewards(era).is_none());
+        advance_to_next_era();
+
+        let tier_rewards = DAppTiers::<Test>::get(era).expect("Must exist for finished era.");
+        let view = DappStaking::dapp_tier_rewards(era).expect("Must exist for finished era.");
+        assert_eq!(view.dapps, tier_rewards.dapps.into_inner());
+        assert_eq!(view.rewards, tier_rewards.rewards.into_inner());
+        assert_eq!(view.period, tier_rewards.period);
+        assert_eq!(view.rank_rewards, tier_rewards.rank_rewards.into_inner());
+        assert_eq!(view.remainders, tier_rewards.remainders.into_inner());
+        assert!(view.dapps.contains_key(&0));
+    })
+}
+
+#[test]
+fn current_reward_pools_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let era_reward = |era: EraNumber| {
+            EraRewards::<Test>::get(DappStaking::era_reward_span_index(era))
+                .and_then(|span| span.get(era).cloned())
+                .expect("Era reward must exist.")
+        };
+        let bonus_pool = <Test as Config>::StakingRewardHandler::bonus_reward_pool();
+
+        // No rewards during the voting subperiod
+        assert_eq!(DappStaking::current_reward_pools(), (0, bonus_pool, 0));
+
+        // Pools match the ones used once the era ends
+        advance_to_next_subperiod();
+        let (staker_pool, _, dapp_pool) = DappStaking::current_reward_pools();
+        assert!(!staker_pool.is_zero());
+        advance_to_next_era();
+        let reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(reward.staker_reward_pool, staker_pool);
+        assert_eq!(reward.dapp_reward_pool, dapp_pool);
+
+        // Reward split override is taken into account
+        let split = Perbill::from_percent(40);
+        assert_ok!(DappStaking::set_reward_split(
+            RuntimeOrigin::root(),
+            Some(split)
+        ));
+        let (staker_pool, _, dapp_pool) = DappStaking::current_reward_pools();
+        advance_to_next_era();
+        let reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(reward.staker_reward_pool, staker_pool);
+        assert_eq!(reward.dapp_reward_pool, dapp_pool);
+    })
+}
+
+#[test]
+fn reward_pool_top_up_increases_next_era_pools() {
+    ExtBuilder::default().build_and_execute(|| {
+        let era_reward = |era: EraNumber| {
+            EraRewards::<Test>::get(DappStaking::era_reward_span_index(era))
+                .and_then(|span| span.get(era).cloned())
+                .expect("Era reward must exist.")
+        };
+        let (staker_pool, dapp_pool) =
+            <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0);
+
+        // Top-up isn't consumed during the voting subperiod, since there are no rewards for it
+        let top_up = 1_001_000;
+        REWARD_POOL_TOP_UP.with(|v| *v.borrow_mut() = top_up);
+        advance_to_next_subperiod();
+        assert_eq!(REWARD_POOL_TOP_UP.with(|v| *v.borrow()), top_up);
+
+        // Top-up is split proportionally between the pools of the next rewarded era
+        advance_to_next_era();
+        assert!(REWARD_POOL_TOP_UP.with(|v| v.borrow().is_zero()));
+        let staker_top_up = Perbill::from_rational(staker_pool, staker_pool + dapp_pool) * top_up;
+        let topped_up_reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(
+            topped_up_reward.staker_reward_pool,
+            staker_pool + staker_top_up
+        );
+        assert_eq!(
+            topped_up_reward.dapp_reward_pool,
+            dapp_pool + top_up - staker_top_up
+        );
+
+        // Top-up is only applied once
+        advance_to_next_era();
+        let reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(reward.staker_reward_pool, staker_pool);
+        assert_eq!(reward.dapp_reward_pool, dapp_pool);
+    })
+}
+
+#[test]
+fn set_reward_split_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let era_reward = |era: EraNumber| {
+            EraRewards::<Test>::get(DappStaking::era_reward_span_index(era))
+                .and_then(|span| span.get(era).cloned())
+                .expect("Era reward must exist.")
+        };
+
+        // Default split, as provided by the reward handler
+        advance_to_next_subperiod();
+        advance_to_next_era();
+        let (staker_pool, dapp_pool) =
+            <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0);
+        let default_reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(default_reward.staker_reward_pool, staker_pool);
+        assert_eq!(default_reward.dapp_reward_pool, dapp_pool);
+
+        // Override the split, total amount remains the same
+        let split = Perbill::from_percent(40);
+        assert_ok!(DappStaking::set_reward_split(
+            RuntimeOrigin::signed(ManagerAccount::get()),
+            Some(split)
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::RewardPoolSplitOverrideSet { split: Some(split) },
+        ));
+
+        advance_to_next_era();
+        let total_pool = staker_pool + dapp_pool;
+        let overridden_reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(overridden_reward.staker_reward_pool, split * total_pool);
+        assert_eq!(
+            overridden_reward.dapp_reward_pool,
+            total_pool - split * total_pool
+        );
+
+        // Once cleared, the handler split is used again
+        assert_ok!(DappStaking::set_reward_split(RuntimeOrigin::root(), None));
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::RewardPoolSplitOverrideSet { split: None },
+        ));
+        advance_to_next_era();
+        let restored_reward = era_reward(ActiveProtocolState::<Test>::get().era - 1);
+        assert_eq!(restored_reward.staker_reward_pool, staker_pool);
+        assert_eq!(restored_reward.dapp_reward_pool, dapp_pool);
+    })
+}
+
+#[test]
+fn set_reward_split_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            DappStaking::set_reward_split(RuntimeOrigin::signed(1), Some(Perbill::one())),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn stake_needed_for_tier_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let tier_config = TierConfig::<Test>::get();
+        assert_eq!(tier_config.slots_per_tier[0], 2, "Sanity check");
+        let threshold = tier_config.tier_thresholds[0];
+
+        // Invalid tier
+        assert!(
+            DappStaking::stake_needed_for_tier(tier_config.tier_thresholds.len() as u8).is_none()
+        );
+
+        // Both slots of the first tier are taken
+        let lowest_stake = threshold + 10;
+        for (id, amount) in [(1, lowest_stake), (2, threshold + 100)] {
+            let smart_contract = MockSmartContract::wasm(id);
+            assert_register(1, &smart_contract);
+
+            let account = 10 + id;
+            Balances::make_free_balance_be(&account, amount * 2);
+            assert_lock(account, amount);
+            assert_stake(account, &smart_contract, amount);
+        }
+
+        // No tier assignment during voting subperiod, so the threshold is returned.
+        assert_eq!(DappStaking::stake_needed_for_tier(0), Some(threshold));
+
+        // Tier is full, the lowest staked dApp must be outstaked.
+        advance_to_next_subperiod();
+        let threshold = TierConfig::<Test>::get().tier_thresholds[0];
+        assert_eq!(
+            DappStaking::stake_needed_for_tier(0),
+            Some(threshold.max(lowest_stake + 1))
+        );
+
+        // Second tier still has open slots, so the threshold is enough.
+        assert_eq!(
+            DappStaking::stake_needed_for_tier(1),
+            Some(TierConfig::<Test>::get().tier_thresholds[1])
+        );
+    })
+}
+
+#[test]
+fn force_set_bonus_status_is_ok() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(2)
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+            let account = 2;
+            assert_lock(account, 300);
+            assert_stake(account, &smart_contract, 100);
+
+            let bonus_status = |account| {
+                StakerInfo::<Test>::get(&account, &smart_contract)
+                    .unwrap()
+                    .bonus_status
+            };
+            assert_eq!(bonus_status(account), 3, "Sanity check");
+
+            // Set the status directly
+            assert_ok!(DappStaking::force_set_bonus_status(
+                RuntimeOrigin::signed(ManagerAccount::get()),
+                account,
+                smart_contract.clone(),
+                0
+            ));
+            System::assert_last_event(RuntimeEvent::DappStaking(Event::BonusStatusForced {
+                account,
+                smart_contract: smart_contract.clone(),
+                old_status: 3,
+                new_status: 0,
+            }));
+            assert_eq!(bonus_status(account), 0);
+
+            // Value is clamped to `MaxBonusSafeMovesPerPeriod + 1`
+            assert_ok!(DappStaking::force_set_bonus_status(
+                RuntimeOrigin::root(),
+                account,
+                smart_contract.clone(),
+                u8::MAX
+            ));
+            System::assert_last_event(RuntimeEvent::DappStaking(Event::BonusStatusForced {
+                account,
+                smart_contract: smart_contract.clone(),
+                old_status: 0,
+                new_status: 3,
+            }));
+            assert_eq!(bonus_status(account), 3);
+        })
+}
+
+#[test]
+fn force_set_bonus_status_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let account = 2;
+
+        // Only the manager origin can change the bonus status
+        assert_noop!(
+            DappStaking::force_set_bonus_status(
+                RuntimeOrigin::signed(account),
+                account,
+                smart_contract.clone(),
+                1
+            ),
+            BadOrigin
+        );
+
+        // Staking info must exist
+        assert_noop!(
+            DappStaking::force_set_bonus_status(
+                RuntimeOrigin::root(),
+                account,
+                smart_contract.clone(),
+                1
+            ),
+            Error::<Test>::NoStakingInfo
+        );
+    })
+}
+
+#[test]
+fn move_stake_limited_per_period() {
+    ExtBuilder::default()
+        .with_max_moves_per_period(Some(2))
+        .build_and_execute(|| {
+            let contracts: Vec<_> = (1..=5)
+                .map(|id| {
+                    let smart_contract = MockSmartContract::wasm(id as AccountId);
+                    assert_register(1, &smart_contract);
+                    smart_contract
+                })
+                .collect();
+
+            let account = 2;
+            assert_lock(account, 300);
+            advance_to_next_subperiod();
+            assert_stake(account, &contracts[0], 300);
+
+            // Moves are allowed until the cap is reached
+            assert_move_stake(account, &contracts[0], &contracts[1], 50);
+            assert_move_stake(account, &contracts[0], &contracts[2], 50);
+            assert_eq!(
+                MovesInPeriod::<Test>::get(&account),
+                Some((ActiveProtocolState::<Test>::get().period_number(), 2))
+            );
+            assert_noop!(
+                DappStaking::move_stake(
+                    RuntimeOrigin::signed(account),
+                    contracts[0].clone(),
+                    contracts[1].clone(),
+                    50
+                ),
+                Error::<Test>::TooManyMovesThisPeriod
+            );
+
+            // Counter is reset once the new period starts
+            advance_to_next_period();
+            for _ in 0..required_number_of_reward_claims(account) {
+                assert_claim_staker_rewards(account);
+            }
+            assert_stake(account, &contracts[3], 100);
+            assert_move_stake(account, &contracts[3], &contracts[4], 50);
+            assert_eq!(
+                MovesInPeriod::<Test>::get(&account),
+                Some((ActiveProtocolState::<Test>::get().period_number(), 1))
+            );
+        })
+}
+
+#[test]
+fn move_stake_limit_applies_after_position_transfer() {
+    ExtBuilder::default()
+        .with_max_moves_per_period(Some(2))
+        .build_and_execute(|| {
+            let contracts: Vec<_> = (1..=3)
+                .map(|id| {
+                    let smart_contract = MockSmartContract::wasm(id as AccountId);
+                    assert_register(1, &smart_contract);
+                    smart_contract
+                })
+                .collect();
+
+            let (source, dest) = (2, 100);
+            assert_lock(source, 300);
+            advance_to_next_subperiod();
+            assert_stake(source, &contracts[0], 300);
+            assert_ok!(DappStaking::set_auto_relock(
+                RuntimeOrigin::signed(source),
+                true
+            ));
+
+            // Use up the move limit, then transfer the position
+            assert_move_stake(source, &contracts[0], &contracts[1], 50);
+            assert_move_stake(source, &contracts[0], &contracts[2], 50);
+            assert_ok!(DappStaking::approve_position_transfer(
+                RuntimeOrigin::signed(dest),
+                source,
+                dest
+            ));
+            assert_transfer_position(source, dest);
+
+            // Move counter & auto-relock setting follow the position
+            assert_eq!(
+                MovesInPeriod::<Test>::get(&dest),
+                Some((ActiveProtocolState::<Test>::get().period_number(), 2))
+            );
+            assert!(AutoRelock::<Test>::get(&dest));
+            assert_noop!(
+                DappStaking::move_stake(
+                    RuntimeOrigin::signed(dest),
+                    contracts[0].clone(),
+                    contracts[1].clone(),
+                    50
+                ),
+                Error::<Test>::TooManyMovesThisPeriod
+            );
+        })
+}
+
+#[test]
+fn move_stake_not_tracked_without_limit() {
+    ExtBuilder::default().build_and_execute(|| {
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let dest_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &dest_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &source_contract, 300);
+
+        for _ in 0..5 {
+            assert_move_stake(account, &source_contract, &dest_contract, 10);
+        }
+        assert!(MovesInPeriod::<Test>::get(&account).is_none());
+    })
+}
+
+#[test]
+fn effective_staking_power_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let staked_contract = MockSmartContract::wasm(1 as AccountId);
+        let idle_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &staked_contract);
+        assert_register(1, &idle_contract);
+
+        // Unregistered contract
+        assert!(DappStaking::effective_staking_power(&MockSmartContract::wasm(3)).is_none());
+
+        let amount = TierConfig::<Test>::get().tier_thresholds[0] + 100;
+        let account = 2;
+        Balances::make_free_balance_be(&account, amount * 2);
+        assert_lock(account, amount);
+        assert_stake(account, &staked_contract, amount);
+
+        // Stake only becomes effective in the next era
+        advance_to_next_subperiod();
+
+        let dapp_id = IntegratedDApps::<Test>::get(&staked_contract).unwrap().id;
+        let expected_tier = DappStaking::get_dapp_tier_assignment()[&dapp_id].tier();
+        assert_eq!(
+            DappStaking::effective_staking_power(&staked_contract),
+            Some((amount, expected_tier))
+        );
+
+        // Contract without any stake doesn't make it into a tier
+        assert!(DappStaking::effective_staking_power(&idle_contract).is_none());
+    })
+}
+
+#[test]
+fn auto_relock_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let unlocking_blocks = DappStaking::unlocking_period();
+        let account = 2;
+        let lock_amount = 103;
+        assert_lock(account, lock_amount);
+
+        assert_ok!(DappStaking::set_auto_relock(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoRelockUpdated {
+            account,
+            enabled: true,
+        }));
+        assert!(AutoRelock::<Test>::get(&account));
+
+        // Matured chunk is relocked instead of being claimed
+        let unlock_amount = 3;
+        assert_unlock(account, unlock_amount);
+        run_for_blocks(unlocking_blocks);
+        let pre_era_info = CurrentEraInfo::<Test>::get();
+        assert_ok!(DappStaking::claim_unlocked(RuntimeOrigin::signed(account)));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Relock {
+            account,
+            amount: unlock_amount,
+        }));
+
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.active_locked_amount(), lock_amount);
+        assert!(ledger.unlocking.is_empty());
+        let era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(
+            era_info.total_locked,
+            pre_era_info.total_locked + unlock_amount
+        );
+        assert_eq!(era_info.unlocking, pre_era_info.unlocking - unlock_amount);
+
+        // Matured chunk is relocked when locking more
+        assert_unlock(account, unlock_amount);
+        run_for_blocks(unlocking_blocks);
+        assert_ok!(DappStaking::lock(RuntimeOrigin::signed(account), 1));
+        assert_eq!(
+            Ledger::<Test>::get(&account).active_locked_amount(),
+            lock_amount + 1
+        );
+        assert!(Ledger::<Test>::get(&account).unlocking.is_empty());
+
+        // Once disabled, matured chunks are claimed as usual
+        assert_ok!(DappStaking::set_auto_relock(
+            RuntimeOrigin::signed(account),
+            false
+        ));
+        assert!(!AutoRelock::<Test>::contains_key(&account));
+        assert_unlock(account, unlock_amount);
+        run_for_blocks(unlocking_blocks);
+        assert_claim_unlocked(account);
+    })
+}
+
+#[test]
+fn auto_relock_respects_minimum_locked_amount() {
+    ExtBuilder::default().build_and_execute(|| {
+        let unlocking_blocks = DappStaking::unlocking_period();
+        let minimum_locked_amount: Balance = <Test as Config>::MinimumLockedAmount::get();
+        let account = 2;
+        let first_chunk = 3;
+        assert_lock(account, minimum_locked_amount + first_chunk);
+        assert_ok!(DappStaking::set_auto_relock(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        // Unlock everything, in two chunks
+        assert_unlock(account, first_chunk);
+        run_for_blocks(1);
+        assert_unlock(account, minimum_locked_amount);
+        assert!(Ledger::<Test>::get(&account)
+            .active_locked_amount()
+            .is_zero());
+
+        // Only the first chunk has matured, relocking it would be below the minimum locked amount
+        run_for_blocks(unlocking_blocks - 1);
+        assert_claim_unlocked(account);
+        assert_eq!(
+            Ledger::<Test>::get(&account).unlocking_amount(),
+            minimum_locked_amount
+        );
+    })
+}
+
+#[test]
+fn contract_crossed_tier_threshold_event_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let thresholds = TierConfig::<Test>::get().tier_thresholds.clone();
+        assert!(thresholds[0] > thresholds[1], "Sanity check");
+        let account = 2;
+        Balances::make_free_balance_be(&account, thresholds[0] * 2);
+        assert_lock(account, thresholds[0] * 2);
+
+        let crossing_events = || {
+            dapp_staking_events()
+                .into_iter()
+                .filter(|event| matches!(event, Event::ContractCrossedTierThreshold { .. }))
+                .collect::<Vec<_>>()
+        };
+
+        // Reaching the second tier threshold
+        assert_stake(account, &smart_contract, thresholds[1]);
+        assert_eq!(
+            crossing_events(),
+            vec![Event::ContractCrossedTierThreshold {
+                smart_contract: smart_contract.clone(),
+                tier_id: 1,
+                crossed_up: true,
+            }]
+        );
+
+        // Staking within the same tier doesn't deposit an event
+        System::reset_events();
+        assert_stake(account, &smart_contract, 1);
+        assert!(crossing_events().is_empty());
+
+        // Reaching the first tier threshold
+        assert_stake(account, &smart_contract, thresholds[0] - thresholds[1] - 1);
+        assert_eq!(
+            crossing_events(),
+            vec![Event::ContractCrossedTierThreshold {
+                smart_contract: smart_contract.clone(),
+                tier_id: 0,
+                crossed_up: true,
+            }]
+        );
+
+        // Falling below the first tier threshold
+        System::reset_events();
+        assert_unstake(account, &smart_contract, 1);
+        assert_eq!(
+            crossing_events(),
+            vec![Event::ContractCrossedTierThreshold {
+                smart_contract: smart_contract.clone(),
+                tier_id: 0,
+                crossed_up: false,
+            }]
+        );
+    })
+}
+
+#[test]
+fn stake_age_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert!(DappStaking::stake_age(&account, &smart_contract).is_none());
+
+        // Stake only becomes effective in the next era
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        let since = ActiveProtocolState::<Test>::get().era + 1;
+        assert_eq!(
+            DappStaking::stake_age(&account, &smart_contract),
+            Some((since, 0))
+        );
+
+        advance_to_era(since + 3);
+        assert_eq!(
+            DappStaking::stake_age(&account, &smart_contract),
+            Some((since, 3))
+        );
+
+        // Staking more resets the age
+        assert_stake(account, &smart_contract, 50);
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        assert_eq!(
+            DappStaking::stake_age(&account, &smart_contract),
+            Some((current_era + 1, 0))
+        );
+    })
+}
+
+#[test]
+fn dapps_by_owner_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        let other_contract = MockSmartContract::wasm(3 as AccountId);
+        assert!(DappStaking::dapps_by_owner(&owner).is_empty());
+
+        assert_register(owner, &smart_contract_1);
+        assert_register(owner, &smart_contract_2);
+        assert_register(owner + 1, &other_contract);
+
+        let owned = DappStaking::dapps_by_owner(&owner);
+        assert_eq!(owned.len(), 2);
+        assert!(owned.contains(&smart_contract_1));
+        assert!(owned.contains(&smart_contract_2));
+
+        // Unregistered contracts & ownership transfers are reflected
+        assert_unregister(&smart_contract_1);
+        assert_set_dapp_owner(Some(owner), &smart_contract_2, owner + 1);
+        assert!(DappStaking::dapps_by_owner(&owner).is_empty());
+        assert_eq!(DappStaking::dapps_by_owner(&(owner + 1)).len(), 2);
+    })
+}
+
+#[test]
+fn dapp_ids_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        let unregistered = MockSmartContract::wasm(3 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+        let id_1 = IntegratedDApps::<Test>::get(&smart_contract_1).unwrap().id;
+        let id_2 = IntegratedDApps::<Test>::get(&smart_contract_2).unwrap().id;
+
+        // Output is aligned with the input
+        assert_eq!(
+            DappStaking::dapp_ids(vec![smart_contract_2, unregistered, smart_contract_1]),
+            vec![Some(id_2), None, Some(id_1)]
+        );
+        assert!(DappStaking::dapp_ids(vec![]).is_empty());
+
+        // Input length is bounded
+        let max_contracts = <Test as Config>::MaxNumberOfContracts::get() as usize;
+        assert_eq!(
+            DappStaking::dapp_ids(vec![smart_contract_1; max_contracts + 1]).len(),
+            max_contracts
+        );
+    })
+}
+
+#[test]
+fn stake_expiry_warning_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let account = 2;
+        assert!(DappStaking::stake_expiry_warning(&account).is_empty());
+
+        // Stakes from the ongoing period don't expire soon
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        let staked_period = ActiveProtocolState::<Test>::get().period_number();
+        assert!(DappStaking::stake_expiry_warning(&account).is_empty());
+
+        // Once the period ends, rewards are listed until they expire
+        let expires_at = staked_period + <Test as Config>::RewardRetentionInPeriods::get() + 1;
+        advance_to_next_period();
+        assert_eq!(
+            DappStaking::stake_expiry_warning(&account),
+            vec![(smart_contract, expires_at)]
+        );
+
+        advance_to_period(expires_at - 1);
+        assert_eq!(
+            DappStaking::stake_expiry_warning(&account),
+            vec![(smart_contract, expires_at)]
+        );
+
+        // Expiry matches the claim logic
+        advance_to_period(expires_at);
+        assert!(DappStaking::stake_expiry_warning(&account).is_empty());
+        assert_noop!(
+            DappStaking::claim_bonus_reward(RuntimeOrigin::signed(account), smart_contract),
+            Error::<Test>::RewardExpired
+        );
+    })
+}
+
+#[test]
+fn stake_fails_when_staker_cap_reached() {
+    ExtBuilder::default()
+        .with_max_total_stakers(Some(1))
+        .build_and_execute(|| {
+            let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+            let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+            assert_register(1, &smart_contract_1);
+            assert_register(1, &smart_contract_2);
+            let (staker, new_staker) = (2, 3);
+            assert_lock(staker, 300);
+            assert_lock(new_staker, 300);
+
+            assert_stake(staker, &smart_contract_1, 100);
+            assert_eq!(TotalStakers::<Test>::get(), 1);
+
+            // New stakers are rejected once the cap is reached
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(new_staker), smart_contract_1, 100),
+                Error::<Test>::StakerCapReached
+            );
+
+            // Existing stakers can still stake, on the same or another contract
+            assert_stake(staker, &smart_contract_1, 50);
+            assert_stake(staker, &smart_contract_2, 100);
+            assert_eq!(TotalStakers::<Test>::get(), 1);
+
+            // Once the staker fully exits, there's room for a new one
+            assert_unstake(staker, &smart_contract_1, 150);
+            assert_eq!(TotalStakers::<Test>::get(), 1);
+            assert_unstake(staker, &smart_contract_2, 100);
+            assert_eq!(TotalStakers::<Test>::get(), 0);
+
+            assert_stake(new_staker, &smart_contract_1, 100);
+            assert_eq!(TotalStakers::<Test>::get(), 1);
+        })
+}
+
+#[test]
+fn set_max_total_stakers_is_ok() {
+    ExtBuilder::default()
+        .with_max_total_stakers(Some(1))
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+            let (staker, new_staker) = (2, 3);
+            assert_lock(staker, 300);
+            assert_lock(new_staker, 300);
+            assert_stake(staker, &smart_contract, 100);
+            assert_eq!(
+                DappStaking::can_stake(&new_staker, &smart_contract, 100),
+                Err(StakePreflightError::StakerCapReached)
+            );
+
+            // Raising the cap makes room for a new staker
+            assert_ok!(DappStaking::set_max_total_stakers(
+                RuntimeOrigin::signed(ManagerAccount::get()),
+                Some(2)
+            ));
+            System::assert_last_event(RuntimeEvent::DappStaking(
+                Event::MaxTotalStakersOverrideSet {
+                    max_stakers: Some(2),
+                },
+            ));
+            assert_stake(new_staker, &smart_contract, 100);
+            assert_eq!(TotalStakers::<Test>::get(), 2);
+
+            // Once cleared, the configured cap is used again
+            assert_ok!(DappStaking::set_max_total_stakers(
+                RuntimeOrigin::root(),
+                None
+            ));
+            System::assert_last_event(RuntimeEvent::DappStaking(
+                Event::MaxTotalStakersOverrideSet { max_stakers: None },
+            ));
+            let another_staker = 4;
+            assert_lock(another_staker, 300);
+            assert_noop!(
+                DappStaking::stake(RuntimeOrigin::signed(another_staker), smart_contract, 100),
+                Error::<Test>::StakerCapReached
+            );
+        })
+}
+
+#[test]
+fn set_max_total_stakers_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            DappStaking::set_max_total_stakers(RuntimeOrigin::signed(1), Some(1)),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn stake_rounds_down_to_increment() {
+    ExtBuilder::default()
+        .with_stake_increment(Some(10))
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+            let account = 2;
+            assert_lock(account, 300);
+
+            assert_ok!(DappStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                37
+            ));
+            System::assert_last_event(RuntimeEvent::DappStaking(Event::Stake {
+                account,
+                smart_contract: smart_contract.clone(),
+                amount: 30,
+            }));
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, &smart_contract)
+                    .expect("Entry must exist after staking.")
+                    .total_staked_amount(),
+                30
+            );
+
+            // Exact multiples are kept as is
+            assert_ok!(DappStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                20
+            ));
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, &smart_contract)
+                    .expect("Entry must exist after staking.")
+                    .total_staked_amount(),
+                50
+            );
+        })
+}
+
+#[test]
+fn stake_rounded_below_minimum_fails() {
+    let minimum_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+    let increment = minimum_stake_amount - 1;
+    ExtBuilder::default()
+        .with_stake_increment(Some(increment))
+        .build_and_execute(|| {
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+            let account = 2;
+            assert_lock(account, 300);
+
+            // Rounds down to zero
+            assert_noop!(
+                DappStaking::stake(
+                    RuntimeOrigin::signed(account),
+                    smart_contract.clone(),
+                    increment - 1
+                ),
+                Error::<Test>::ZeroAmount
+            );
+
+            // Above the minimum, but rounds down below it
+            assert_noop!(
+                DappStaking::stake(
+                    RuntimeOrigin::signed(account),
+                    smart_contract.clone(),
+                    minimum_stake_amount
+                ),
+                Error::<Test>::InsufficientStakeAmount
+            );
+        })
+}
+
+#[test]
+fn claim_for_contract_stakers_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let other_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract);
+        assert_register(dev_account, &other_contract);
+
+        let stakers = [2, 3, 4];
+        for staker in stakers {
+            assert_lock(staker, 300);
+            assert_stake(staker, &smart_contract, 100);
+        }
+        let other_staker = 5;
+        assert_lock(other_staker, 300);
+        assert_stake(other_staker, &other_contract, 100);
+        assert_eq!(
+            ContractStakers::<Test>::iter_key_prefix(&smart_contract).count(),
+            stakers.len()
+        );
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let init_balances: Vec<_> = stakers
+            .iter()
+            .map(|staker| Balances::free_balance(staker))
+            .collect();
+        let init_other_balance = Balances::free_balance(&other_staker);
+        let init_dev_balance = Balances::free_balance(&dev_account);
+
+        // Only the dApp owner can trigger the claims
+        assert_noop!(
+            DappStaking::claim_for_contract_stakers(
+                RuntimeOrigin::signed(other_staker),
+                smart_contract.clone(),
+                10,
+                None
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        // Process the stakers in pages, continuing from the cursor
+        let mut cursor = None;
+        let mut total_claimed = 0;
+        let mut pages = 0;
+        loop {
+            pages += 1;
+            assert_ok!(DappStaking::claim_for_contract_stakers(
+                RuntimeOrigin::signed(dev_account),
+                smart_contract.clone(),
+                2,
+                cursor.clone()
+            ));
+            match dapp_staking_events().last() {
+                Some(Event::ContractStakersClaimed {
+                    claimed,
+                    cursor: next_cursor,
+                    ..
+                }) => {
+                    total_claimed += claimed;
+                    cursor = next_cursor.clone();
+                }
+                _ => panic!("Last event must be ContractStakersClaimed."),
+            }
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(total_claimed, stakers.len() as u32);
+        // Only the stakers of the contract are iterated over
+        assert_eq!(pages, 2);
+
+        // Rewards go to the stakers of the contract only
+        for (staker, init_balance) in stakers.iter().zip(init_balances) {
+            assert!(Balances::free_balance(staker) > init_balance);
+        }
+        assert_eq!(Balances::free_balance(&other_staker), init_other_balance);
+        assert_eq!(Balances::free_balance(&dev_account), init_dev_balance);
+
+        // Nothing left to claim, accounts are skipped
+        assert_ok!(DappStaking::claim_for_contract_stakers(
+            RuntimeOrigin::signed(dev_account),
+            smart_contract.clone(),
+            10,
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ContractStakersClaimed {
+            smart_contract,
+            claimed: 0,
+            cursor: None,
+        }));
+    })
+}
+
+#[test]
+fn schedule_cycle_config_change_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let old_blocks_per_era = <Test as Config>::CycleConfiguration::blocks_per_era();
+        // Shorter voting subperiod with longer eras, the cycle length remains the same
+        let config = CycleParameters {
+            periods_per_cycle: 4,
+            eras_per_voting_subperiod: 4,
+            eras_per_build_and_earn_subperiod: 16,
+            blocks_per_era: 12,
+        };
+
+        assert_ok!(DappStaking::schedule_cycle_config_change(
+            RuntimeOrigin::root(),
+            config
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::CycleConfigChangeScheduled { config },
+        ));
+        assert_eq!(PendingCycleConfig::<Test>::get(), Some(config));
+
+        // The ongoing period keeps using the old configuration, including the build&earn subperiod.
+        advance_to_next_era();
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        assert_eq!(protocol_state.subperiod(), Subperiod::BuildAndEarn);
+        assert_eq!(
+            protocol_state.period_info.next_subperiod_start_era,
+            protocol_state.era
+                + <Test as Config>::CycleConfiguration::eras_per_build_and_earn_subperiod()
+        );
+        assert_eq!(
+            protocol_state.next_era_start,
+            System::block_number() + old_blocks_per_era
+        );
+
+        // Run to the last block of the period, change hasn't been applied yet.
+        advance_to_era(protocol_state.period_info.next_subperiod_start_era - 1);
+        run_to_block(ActiveProtocolState::<Test>::get().next_era_start - 1);
+        assert!(ActiveCycleConfig::<Test>::get().is_none());
+        assert_eq!(PendingCycleConfig::<Test>::get(), Some(config));
+
+        // Change is applied exactly at the period boundary.
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        run_for_blocks(1);
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        assert_eq!(protocol_state.period_number(), period + 1);
+        assert_eq!(ActiveCycleConfig::<Test>::get(), Some(config));
+        assert!(PendingCycleConfig::<Test>::get().is_none());
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::DappStaking(Event::CycleConfigChangeApplied { config })));
+
+        // New period uses the new configuration.
+        assert_eq!(
+            protocol_state.next_era_start,
+            System::block_number() + config.blocks_per_era * config.eras_per_voting_subperiod
+        );
+        advance_to_next_era();
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        assert_eq!(
+            protocol_state.period_info.next_subperiod_start_era,
+            protocol_state.era + config.eras_per_build_and_earn_subperiod
+        );
+        assert_eq!(
+            protocol_state.next_era_start,
+            System::block_number() + config.blocks_per_era
+        );
+    })
+}
+
+#[test]
+fn schedule_cycle_config_change_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let config = CycleParameters {
+            periods_per_cycle: 4,
+            eras_per_voting_subperiod: 4,
+            eras_per_build_and_earn_subperiod: 16,
+            blocks_per_era: 12,
+        };
+
+        assert_noop!(
+            DappStaking::schedule_cycle_config_change(RuntimeOrigin::signed(1), config),
+            BadOrigin
+        );
+
+        let invalid_config = CycleParameters {
+            blocks_per_era: 0,
+            ..config
+        };
+        assert_noop!(
+            DappStaking::schedule_cycle_config_change(RuntimeOrigin::root(), invalid_config),
+            Error::<Test>::InvalidCycleConfiguration
+        );
+
+        // Cycle length must remain the same, in both eras & blocks
+        for changed_config in [
+            CycleParameters {
+                blocks_per_era: 20,
+                ..config
+            },
+            CycleParameters {
+                eras_per_build_and_earn_subperiod: 12,
+                eras_per_voting_subperiod: 8,
+                ..config
+            },
+            CycleParameters {
+                periods_per_cycle: 2,
+                eras_per_build_and_earn_subperiod: 33,
+                eras_per_voting_subperiod: 15,
+                blocks_per_era: 5,
+            },
+        ] {
+            assert_noop!(
+                DappStaking::schedule_cycle_config_change(RuntimeOrigin::root(), changed_config),
+                Error::<Test>::CycleLengthMismatch
+            );
+        }
+    })
+}
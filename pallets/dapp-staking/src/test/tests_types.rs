@@ -162,6 +162,8 @@ fn dapp_info_basic_checks() {
         owner,
         id: 7,
         reward_beneficiary: None,
+        registered_era: 2,
+        min_stake: None,
     };
 
     // Owner receives reward in case no beneficiary is set
@@ -170,6 +172,15 @@ fn dapp_info_basic_checks() {
     // Beneficiary receives rewards in case it is set
     dapp_info.reward_beneficiary = Some(beneficiary);
     assert_eq!(*dapp_info.reward_beneficiary(), beneficiary);
+
+    // Global minimum stake applies in case no override is set
+    assert_eq!(dapp_info.min_stake_amount(10), 10);
+
+    // Override only applies if it's above the global minimum
+    dapp_info.min_stake = Some(15);
+    assert_eq!(dapp_info.min_stake_amount(10), 15);
+    dapp_info.min_stake = Some(5);
+    assert_eq!(dapp_info.min_stake_amount(10), 10);
 }
 
 #[test]
@@ -249,6 +260,66 @@ fn account_ledger_subtract_lock_amount_basic_usage_works() {
     );
 }
 
+#[test]
+fn account_ledger_refresh_locked_since_works() {
+    get_u32_type!(UnlockingDummy, 5);
+    let mut acc_ledger = AccountLedger::<UnlockingDummy>::default();
+
+    // Nothing is locked, no streak
+    acc_ledger.refresh_locked_since(3);
+    assert!(acc_ledger.locked_since().is_none());
+
+    // Streak starts once funds are locked, and isn't affected by subsequent locks
+    acc_ledger.add_lock_amount(20);
+    acc_ledger.refresh_locked_since(5);
+    assert_eq!(acc_ledger.locked_since(), Some(5));
+    acc_ledger.add_lock_amount(10);
+    acc_ledger.refresh_locked_since(7);
+    assert_eq!(acc_ledger.locked_since(), Some(5));
+
+    // Partial unlock keeps the streak, full unlock resets it
+    acc_ledger.subtract_lock_amount(15);
+    acc_ledger.refresh_locked_since(9);
+    assert_eq!(acc_ledger.locked_since(), Some(5));
+    acc_ledger.subtract_lock_amount(15);
+    acc_ledger.refresh_locked_since(11);
+    assert!(acc_ledger.locked_since().is_none());
+}
+
+#[test]
+fn account_ledger_fresh_lock_works() {
+    get_u32_type!(UnlockingDummy, 5);
+    let mut acc_ledger = AccountLedger::<UnlockingDummy>::default();
+    let delay = 3;
+
+    // Without delay, nothing is tracked
+    acc_ledger.add_lock_amount(20);
+    acc_ledger.note_fresh_lock(20, 2, 0);
+    assert!(acc_ledger.fresh_lock.is_none());
+    assert!(acc_ledger.fresh_locked_amount(2, delay).is_zero());
+
+    // Locks made before the previous one has aged are aggregated
+    acc_ledger.add_lock_amount(30);
+    acc_ledger.note_fresh_lock(30, 4, delay);
+    acc_ledger.add_lock_amount(10);
+    acc_ledger.note_fresh_lock(10, 6, delay);
+    assert_eq!(acc_ledger.fresh_locked_amount(6, delay), 40);
+    assert_eq!(acc_ledger.fresh_locked_amount(8, delay), 40);
+    assert!(acc_ledger.fresh_locked_amount(9, delay).is_zero());
+
+    // Once aged, the next lock starts a new aggregate
+    acc_ledger.add_lock_amount(5);
+    acc_ledger.note_fresh_lock(5, 9, delay);
+    assert_eq!(acc_ledger.fresh_locked_amount(9, delay), 5);
+
+    // Fresh funds are unlocked first
+    acc_ledger.subtract_lock_amount(3);
+    assert_eq!(acc_ledger.fresh_locked_amount(9, delay), 2);
+    acc_ledger.subtract_lock_amount(10);
+    assert!(acc_ledger.fresh_locked_amount(9, delay).is_zero());
+    assert_eq!(acc_ledger.active_locked_amount(), 52);
+}
+
 #[test]
 fn account_ledger_add_unlocking_chunk_works() {
     get_u32_type!(UnlockingDummy, 5);
@@ -354,6 +425,52 @@ fn account_ledger_staked_amount_works() {
     assert!(acc_ledger.staked_amount(period + 1).is_zero());
 }
 
+#[test]
+fn account_ledger_staked_amount_for_era_works() {
+    get_u32_type!(UnlockingDummy, 5);
+    let mut acc_ledger = AccountLedger::<UnlockingDummy>::default();
+
+    // Sanity check
+    assert!(acc_ledger.staked_amount_for_era(1, 1).is_zero());
+
+    // Only 'future' entry exists
+    let (amount_1, era_1, period) = (23, 4, 3);
+    acc_ledger.staked_future = Some(StakeAmount {
+        voting: amount_1,
+        build_and_earn: 0,
+        era: era_1,
+        period,
+    });
+    assert!(acc_ledger
+        .staked_amount_for_era(era_1 - 1, period)
+        .is_zero());
+    assert_eq!(acc_ledger.staked_amount_for_era(era_1, period), amount_1);
+    assert_eq!(
+        acc_ledger.staked_amount_for_era(era_1 + 1, period),
+        amount_1
+    );
+    assert!(acc_ledger
+        .staked_amount_for_era(era_1, period + 1)
+        .is_zero());
+
+    // Both entries exist, 'current' entry is relevant for the era before the 'future' one
+    let amount_2 = 11;
+    acc_ledger.staked = StakeAmount {
+        voting: 0,
+        build_and_earn: amount_2,
+        era: era_1 - 1,
+        period,
+    };
+    assert_eq!(
+        acc_ledger.staked_amount_for_era(era_1 - 1, period),
+        amount_2
+    );
+    assert_eq!(acc_ledger.staked_amount_for_era(era_1, period), amount_1);
+    assert!(acc_ledger
+        .staked_amount_for_era(era_1 - 2, period)
+        .is_zero());
+}
+
 #[test]
 fn account_ledger_staked_amount_for_type_works() {
     get_u32_type!(UnlockingDummy, 5);
@@ -3640,6 +3757,7 @@ fn dapp_tier_rewards_basic_tests() {
         tier_rewards.clone(),
         period,
         vec![0, 0, 0],
+        BTreeMap::new(),
     )
     .expect("Bounds are respected.");
 
@@ -3729,6 +3847,7 @@ fn dapp_tier_rewards_with_rank() {
         tier_rewards.clone(),
         period,
         rank_rewards.clone(),
+        BTreeMap::new(),
     )
     .expect("Bounds are respected.");
 
@@ -3785,6 +3904,35 @@ fn tier_thresholds_conversion_test() {
     assert_eq!(tier_thresholds[1], 50_000); // 5% of total issuance
 }
 
+#[test]
+fn tier_configuration_smooth_tier_thresholds_works() {
+    get_u32_type!(TiersNum, 3);
+
+    let previous = TiersConfiguration::<TiersNum> {
+        slots_per_tier: BoundedVec::try_from(vec![10, 20, 30]).unwrap(),
+        reward_portion: BoundedVec::try_from(vec![Permill::from_percent(33); 3]).unwrap(),
+        tier_thresholds: BoundedVec::try_from(vec![1000, 500, 100]).unwrap(),
+    };
+
+    // Thresholds move by the smoothing fraction of the difference, in both directions
+    let mut new_config = previous.clone();
+    new_config.tier_thresholds = BoundedVec::try_from(vec![2000, 100, 100]).unwrap();
+    new_config.smooth_tier_thresholds(&previous, Permill::from_percent(25));
+    assert_eq!(new_config.tier_thresholds.to_vec(), vec![1250, 400, 100]);
+
+    // Full smoothing fraction applies the target immediately
+    let mut new_config = previous.clone();
+    new_config.tier_thresholds = BoundedVec::try_from(vec![2000, 100, 50]).unwrap();
+    new_config.smooth_tier_thresholds(&previous, Permill::one());
+    assert_eq!(new_config.tier_thresholds.to_vec(), vec![2000, 100, 50]);
+
+    // Mismatched number of thresholds is ignored
+    let mut new_config = previous.clone();
+    new_config.tier_thresholds = BoundedVec::try_from(vec![2000, 100]).unwrap();
+    new_config.smooth_tier_thresholds(&previous, Permill::from_percent(25));
+    assert_eq!(new_config.tier_thresholds.to_vec(), vec![2000, 100]);
+}
+
 #[test]
 fn tier_configuration_calculate_new_with_fixed_thresholds() {
     get_u32_type!(TiersNum, 4);
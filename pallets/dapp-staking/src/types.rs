@@ -46,7 +46,8 @@
 //!
 //! * `UnlockingChunk` - describes some amount undergoing the unlocking process.
 //! * `StakeAmount` - contains information about the staked amount in a particular era, and period.
-//! * `AccountLedger` - keeps track of total locked & staked balance, unlocking chunks and number of stake entries.
+//! * `LockPurpose` - a tag used to categorize an account's locked funds.
+//! * `AccountLedger` - keeps track of total locked & staked balance, unlocking chunks, number of stake entries and lock purpose.
 //! * `SingularStakingInfo` - contains information about a particular staker's stake on a specific smart contract. Used to track loyalty.
 //!
 //! ## Era Information
@@ -62,6 +63,9 @@
 //! * `TiersConfiguration` - contains dynamic information about tiers, derived from `TierParameters` and onchain data.
 //! * `DAppTier` - a compact struct describing a dApp's tier.
 //! * `DAppTierRewards` - composite of `DAppTier` objects, describing the entire reward distribution for a particular era.
+//! * `RewardRounding` - an enum describing how the tier reward division remainder is handled.
+//! * `OptOutRewardHandling` - an enum describing what happens with the reward of dApps which opted out of dApp rewards.
+//! * `DAppStakesSnapshot` - dApp stakes for an era, collected incrementally to speed up the tier assignment.
 //!
 
 use core::ops::Deref;
@@ -75,7 +79,9 @@ use sp_runtime::{
 pub use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec};
 
 use astar_primitives::{
-    dapp_staking::{DAppId, EraNumber, PeriodNumber, RankedTier, FIXED_NUMBER_OF_TIER_SLOTS},
+    dapp_staking::{
+        CycleConfiguration, DAppId, EraNumber, PeriodNumber, RankedTier, FIXED_NUMBER_OF_TIER_SLOTS,
+    },
     Balance, BlockNumber,
 };
 
@@ -88,6 +94,9 @@ pub type AccountLedgerFor<T> = AccountLedger<<T as Config>::MaxUnlockingChunks>;
 pub type DAppTierRewardsFor<T> =
     DAppTierRewards<<T as Config>::MaxNumberOfContracts, <T as Config>::NumberOfTiers>;
 
+// Convenience type for `DAppStakesSnapshot` usage.
+pub type DAppStakesSnapshotFor<T> = DAppStakesSnapshot<<T as Config>::MaxNumberOfContracts>;
+
 // Convenience type for `EraRewardSpan` usage.
 pub type EraRewardSpanFor<T> = EraRewardSpan<<T as Config>::EraRewardSpanLength>;
 
@@ -108,6 +117,9 @@ pub type BonusUpdateCursorFor<T> = (
 
 pub type BonusUpdateCursor<AccountId, SmartContract> = (AccountId, SmartContract);
 
+/// Tag used to categorize locked funds, e.g. `*b"gov\0"`. All zeroes is the default tag.
+pub type LockPurpose = [u8; 4];
+
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
 pub enum BonusUpdateState<AccountId, SmartContract> {
     /// No update in progress yet
@@ -228,6 +240,66 @@ pub enum ForcingType {
     Subperiod,
 }
 
+/// Cycle configuration parameters, used instead of the runtime provided `CycleConfiguration`.
+///
+/// Meaning of each field matches the equally named method of the `CycleConfiguration` trait.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+)]
+pub struct CycleParameters {
+    /// How many different periods are there in a cycle.
+    pub periods_per_cycle: PeriodNumber,
+    /// For how many standard era lengths does the voting subperiod last.
+    pub eras_per_voting_subperiod: EraNumber,
+    /// How many standard eras are there in the build&earn subperiod.
+    pub eras_per_build_and_earn_subperiod: EraNumber,
+    /// How many blocks are there per standard era.
+    pub blocks_per_era: BlockNumber,
+}
+
+impl CycleParameters {
+    /// `true` if all parameters are valid, i.e. non-zero, `false` otherwise.
+    pub fn is_valid(&self) -> bool {
+        self.periods_per_cycle > 0
+            && self.eras_per_voting_subperiod > 0
+            && self.eras_per_build_and_earn_subperiod > 0
+            && self.blocks_per_era > 0
+    }
+
+    /// `true` if the cycle has the same length as the one of `C`, both in eras & blocks,
+    /// and the same number of build&earn eras, `false` otherwise.
+    ///
+    /// Inflation is calculated per cycle, using these values, so they must remain unchanged.
+    pub fn has_same_cycle_as<C: CycleConfiguration>(&self) -> bool {
+        let periods_per_cycle = self.periods_per_cycle;
+        let eras_per_cycle = self
+            .eras_per_build_and_earn_subperiod
+            .saturating_add(1)
+            .saturating_mul(periods_per_cycle);
+        let build_and_earn_eras_per_cycle = self
+            .eras_per_build_and_earn_subperiod
+            .saturating_mul(periods_per_cycle);
+        let blocks_per_cycle = self.blocks_per_era.saturating_mul(
+            self.eras_per_voting_subperiod
+                .saturating_add(self.eras_per_build_and_earn_subperiod)
+                .saturating_mul(periods_per_cycle),
+        );
+
+        eras_per_cycle == C::eras_per_cycle()
+            && build_and_earn_eras_per_cycle == C::build_and_earn_eras_per_cycle()
+            && blocks_per_cycle == C::blocks_per_cycle()
+    }
+}
+
 /// General information & state of the dApp staking protocol.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub struct ProtocolState {
@@ -326,6 +398,11 @@ pub struct DAppInfo<AccountId> {
     pub(crate) id: DAppId,
     // If `None`, rewards goes to the developer account, otherwise to the account Id in `Some`.
     pub(crate) reward_beneficiary: Option<AccountId>,
+    /// Era in which the dApp was registered.
+    #[codec(compact)]
+    pub(crate) registered_era: EraNumber,
+    // If `None`, the global minimum stake amount applies, otherwise the greater of the two.
+    pub(crate) min_stake: Option<Balance>,
 }
 
 impl<AccountId> DAppInfo<AccountId> {
@@ -341,6 +418,23 @@ impl<AccountId> DAppInfo<AccountId> {
             None => &self.owner,
         }
     }
+
+    /// Era in which the dApp was registered.
+    pub fn registered_era(&self) -> EraNumber {
+        self.registered_era
+    }
+
+    /// Minimum stake amount override set by the dApp owner, if any.
+    pub fn min_stake(&self) -> Option<Balance> {
+        self.min_stake
+    }
+
+    /// Minimum total stake amount required to stake on this dApp, given the global minimum.
+    pub fn min_stake_amount(&self, global_min_stake: Balance) -> Balance {
+        self.min_stake.map_or(global_min_stake, |min_stake| {
+            min_stake.max(global_min_stake)
+        })
+    }
 }
 
 /// How much was unlocked in some block.
@@ -437,6 +531,12 @@ pub struct AccountLedger<UnlockingLen: Get<u32>> {
     /// Number of contract stake entries in storage.
     #[codec(compact)]
     pub(crate) contract_stake_count: u32,
+    /// Tag used to categorize the account's locked funds.
+    pub(crate) purpose: LockPurpose,
+    /// Era since which the account has continuously had some active locked amount.
+    pub(crate) locked_since: Option<EraNumber>,
+    /// Era of the most recent lock, and the amount locked since the lock-to-stake delay last elapsed.
+    pub(crate) fresh_lock: Option<(EraNumber, Balance)>,
 }
 
 impl<UnlockingLen> AccountLedger<UnlockingLen>
@@ -453,6 +553,51 @@ where
         &self.unlocking
     }
 
+    /// Tag used to categorize the account's locked funds.
+    pub fn purpose(&self) -> LockPurpose {
+        self.purpose
+    }
+
+    /// Era since which the account has continuously had some active locked amount.
+    pub fn locked_since(&self) -> Option<EraNumber> {
+        self.locked_since
+    }
+
+    /// Refresh the continuous lock tracking.
+    ///
+    /// The streak starts in the specified era if the account has just locked some funds,
+    /// and it's reset once the active locked amount drops to zero.
+    pub fn refresh_locked_since(&mut self, era: EraNumber) {
+        if self.locked.is_zero() {
+            self.locked_since = None;
+        } else if self.locked_since.is_none() {
+            self.locked_since = Some(era);
+        }
+    }
+
+    /// Record a lock of `amount` in the specified era.
+    ///
+    /// Amounts locked before the previous lock has aged `delay` eras are aggregated,
+    /// and only become stakeable once `delay` eras pass after the most recent lock.
+    pub fn note_fresh_lock(&mut self, amount: Balance, era: EraNumber, delay: EraNumber) {
+        if delay.is_zero() || amount.is_zero() {
+            return;
+        }
+
+        let fresh_amount = self.fresh_locked_amount(era, delay);
+        self.fresh_lock = Some((era, fresh_amount.saturating_add(amount)));
+    }
+
+    /// Active locked amount which hasn't aged `delay` eras yet, in respect to the specified era.
+    pub fn fresh_locked_amount(&self, era: EraNumber, delay: EraNumber) -> Balance {
+        match self.fresh_lock {
+            Some((lock_era, amount)) if era < lock_era.saturating_add(delay) => {
+                amount.min(self.locked)
+            }
+            _ => Balance::zero(),
+        }
+    }
+
     /// Empty if no locked/unlocking/staked info exists.
     pub fn is_empty(&self) -> bool {
         self.locked.is_zero()
@@ -490,8 +635,13 @@ where
     }
 
     /// Subtracts the specified amount of the total locked amount.
+    ///
+    /// Freshly locked funds are subtracted first.
     pub fn subtract_lock_amount(&mut self, amount: Balance) {
         self.locked.saturating_reduce(amount);
+        if let Some((_, fresh_amount)) = self.fresh_lock.as_mut() {
+            fresh_amount.saturating_reduce(amount);
+        }
     }
 
     /// Adds the specified amount to the unlocking chunks.
@@ -583,6 +733,22 @@ where
         }
     }
 
+    /// Amount that is staked & effective in the specified era, in respect to the specified period.
+    pub fn staked_amount_for_era(&self, era: EraNumber, period: PeriodNumber) -> Balance {
+        // First check the 'future' entry, afterwards check the 'first' entry
+        match self.staked_future {
+            Some(stake_amount) if stake_amount.era <= era && stake_amount.period == period => {
+                stake_amount.total()
+            }
+            _ => match self.staked {
+                stake_amount if stake_amount.era <= era && stake_amount.period == period => {
+                    stake_amount.total()
+                }
+                _ => Balance::zero(),
+            },
+        }
+    }
+
     /// How much is staked for the specified subperiod, in respect to the specified era.
     pub fn staked_amount_for_type(&self, subperiod: Subperiod, period: PeriodNumber) -> Balance {
         // First check the 'future' entry, afterwards check the 'first' entry
@@ -1843,6 +2009,48 @@ impl<NT: Get<u32>> TiersConfiguration<NT> {
             tier_thresholds: new_tier_thresholds,
         }
     }
+
+    /// Moves the tier thresholds of `previous` configuration towards the thresholds of this one,
+    /// by the `smoothing` fraction of the difference, and uses the result as the new thresholds.
+    ///
+    /// In case the number of thresholds doesn't match, thresholds are left unchanged.
+    pub fn smooth_tier_thresholds(&mut self, previous: &Self, smoothing: Permill) {
+        if previous.tier_thresholds.len() != self.tier_thresholds.len() {
+            return;
+        }
+
+        for (threshold, previous_threshold) in self
+            .tier_thresholds
+            .iter_mut()
+            .zip(previous.tier_thresholds.iter())
+        {
+            *threshold = if *threshold >= *previous_threshold {
+                previous_threshold.saturating_add(smoothing * (*threshold - previous_threshold))
+            } else {
+                previous_threshold.saturating_sub(smoothing * (previous_threshold - *threshold))
+            };
+        }
+    }
+}
+
+/// Describes how the remainder of the tier reward division is handled.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub enum RewardRounding {
+    /// Remainder is truncated and isn't distributed to anyone.
+    #[default]
+    Truncate,
+    /// Remainder is given to the top dApp of the tier.
+    DistributeRemainder,
+}
+
+/// Describes what happens with the tier reward of dApps which opted out of dApp rewards.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub enum OptOutRewardHandling {
+    /// Opted-out dApps don't occupy tier slots, so their slots go to the next best dApps.
+    #[default]
+    Redistribute,
+    /// Opted-out dApps occupy their tier slots, but their reward isn't distributed to anyone.
+    ReturnToPool,
 }
 
 /// Information about all of the dApps that got into tiers, and tier rewards
@@ -1868,6 +2076,8 @@ pub struct DAppTierRewards<MD: Get<u32>, NT: Get<u32>> {
     pub(crate) period: PeriodNumber,
     /// Rank reward for each tier. First entry refers to the first tier, and so on.
     pub(crate) rank_rewards: BoundedVec<Balance, NT>,
+    /// Undistributed reward remainders, assigned to the top dApp of each tier.
+    pub(crate) remainders: BoundedBTreeMap<DAppId, Balance, NT>,
 }
 
 impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
@@ -1878,15 +2088,18 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
         rewards: Vec<Balance>,
         period: PeriodNumber,
         rank_rewards: Vec<Balance>,
+        remainders: BTreeMap<DAppId, Balance>,
     ) -> Result<Self, ()> {
         let dapps = BoundedBTreeMap::try_from(dapps).map_err(|_| ())?;
         let rewards = BoundedVec::try_from(rewards).map_err(|_| ())?;
         let rank_rewards = BoundedVec::try_from(rank_rewards).map_err(|_| ())?;
+        let remainders = BoundedBTreeMap::try_from(remainders).map_err(|_| ())?;
         Ok(Self {
             dapps,
             rewards,
             period,
             rank_rewards,
+            remainders,
         })
     }
 
@@ -1913,6 +2126,10 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
         let additional_reward = reward_per_rank.saturating_mul(rank.into());
         amount = amount.saturating_add(additional_reward);
 
+        if let Some(remainder) = self.remainders.remove(&dapp_id) {
+            amount = amount.saturating_add(remainder);
+        }
+
         Ok((amount, ranked_tier))
     }
 }
@@ -1946,3 +2163,94 @@ impl CleanupMarker {
             || self.dapp_tiers_index != self.oldest_valid_era
     }
 }
+
+/// Progress of the automatic dApp reward claiming for an era.
+#[derive(Encode, Decode, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, Clone, Copy, TypeInfo)]
+pub struct AutoClaimProgress {
+    /// Era for which the dApp rewards are claimed.
+    #[codec(compact)]
+    pub(crate) era: EraNumber,
+    /// Last processed `DAppAutoClaim` entry key, `None` if no entries have been processed yet.
+    pub(crate) last_processed: Option<DAppId>,
+    /// `true` if all `DAppAutoClaim` entries have been processed, `false` otherwise.
+    pub(crate) complete: bool,
+}
+
+impl AutoClaimProgress {
+    /// Create a new progress tracker for the specified era, with no processed entries.
+    pub(crate) fn new(era: EraNumber) -> Self {
+        Self {
+            era,
+            last_processed: None,
+            complete: false,
+        }
+    }
+}
+
+/// Non-zero dApp stakes for an era, collected incrementally during `on_idle`.
+///
+/// Used to pre-compute the input for the tier assignment, so it doesn't need to be read in the era transition block.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(MD))]
+pub struct DAppStakesSnapshot<MD: Get<u32>> {
+    /// Era for which the dApp stakes are collected.
+    #[codec(compact)]
+    pub(crate) era: EraNumber,
+    /// Last processed `ContractStake` entry key, `None` if no entries have been processed yet.
+    pub(crate) last_processed: Option<DAppId>,
+    /// `true` if all `ContractStake` entries have been processed, `false` otherwise.
+    pub(crate) complete: bool,
+    /// dApps and their non-zero stake amounts for the era.
+    pub(crate) stakes: BoundedVec<(DAppId, Balance), MD>,
+}
+
+impl<MD: Get<u32>> DAppStakesSnapshot<MD> {
+    /// Create a new, empty snapshot for the specified era.
+    pub(crate) fn new(era: EraNumber) -> Self {
+        Self {
+            era,
+            last_processed: None,
+            complete: false,
+            stakes: BoundedVec::default(),
+        }
+    }
+
+    /// Set the stake amount for the specified dApp.
+    /// Zero amount removes the dApp from the snapshot.
+    ///
+    /// Returns an error if the snapshot is full.
+    pub(crate) fn set(&mut self, dapp_id: DAppId, amount: Balance) -> Result<(), ()> {
+        if amount.is_zero() {
+            self.stakes.retain(|(id, _)| *id != dapp_id);
+            return Ok(());
+        }
+
+        match self.stakes.iter_mut().find(|(id, _)| *id == dapp_id) {
+            Some(entry) => {
+                entry.1 = amount;
+                Ok(())
+            }
+            None => self.stakes.try_push((dapp_id, amount)).map_err(|_| ()),
+        }
+    }
+}
+
+/// Claim reminder of a staker.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct ClaimReminder {
+    /// Number of eras between two consecutive reminders.
+    #[codec(compact)]
+    pub every: EraNumber,
+    /// Era in which the next reminder is due.
+    #[codec(compact)]
+    pub next_era: EraNumber,
+}
@@ -51,13 +51,21 @@ pub trait WeightInfo {
 	fn maintenance_mode() -> Weight;
 	fn register() -> Weight;
 	fn set_dapp_reward_beneficiary() -> Weight;
+	fn set_dapp_auto_compound() -> Weight;
+	fn set_tier_cap() -> Weight;
+	fn set_dapp_reward_opt_out() -> Weight;
+	fn set_claim_reminder() -> Weight;
 	fn set_dapp_owner() -> Weight;
 	fn unregister() -> Weight;
+	fn unregister_inactive() -> Weight;
 	fn lock_new_account() -> Weight;
 	fn lock_existing_account() -> Weight;
 	fn unlock() -> Weight;
 	fn claim_unlocked(x: u32, ) -> Weight;
+	fn reclaim_abandoned(x: u32, ) -> Weight;
 	fn relock_unlocking() -> Weight;
+	fn approve_position_transfer() -> Weight;
+	fn transfer_position(x: u32, ) -> Weight;
 	fn stake() -> Weight;
 	fn unstake() -> Weight;
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight;
@@ -73,8 +81,19 @@ pub trait WeightInfo {
 	fn on_initialize_build_and_earn_to_voting() -> Weight;
 	fn on_initialize_build_and_earn_to_build_and_earn() -> Weight;
 	fn dapp_tier_assignment(x: u32, ) -> Weight;
+	fn dapp_tier_assignment_from_snapshot(x: u32, ) -> Weight;
+	fn dapp_stakes_snapshot(x: u32, ) -> Weight;
+	fn process_claim_reminders(x: u32, ) -> Weight;
 	fn on_idle_cleanup() -> Weight;
 	fn set_static_tier_params() -> Weight;
+	fn set_slots_per_tier_override() -> Weight;
+	fn set_reward_split() -> Weight;
+	fn force_set_bonus_status() -> Weight;
+	fn set_auto_relock() -> Weight;
+	fn schedule_cycle_config_change() -> Weight;
+	fn set_dapp_min_stake() -> Weight;
+	fn set_dapp_auto_claim() -> Weight;
+	fn set_stake_delegate() -> Weight;
 }
 
 /// Weights for pallet_dapp_staking using the Substrate node and recommended hardware.
@@ -93,6 +112,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::NextDAppId` (r:1 w:1)
 	/// Proof: `DappStaking::NextDAppId` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -100,7 +121,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 12_235_000 picoseconds.
 		Weight::from_parts(12_512_000, 3086)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -113,6 +134,58 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_dapp_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 11_862_000 picoseconds.
+		Weight::from_parts(12_109_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierCap` (r:0 w:1)
+	/// Proof: `DappStaking::DAppTierCap` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_tier_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 11_862_000 picoseconds.
+		Weight::from_parts(12_109_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppRewardOptOut` (r:0 w:1)
+	/// Proof: `DappStaking::DAppRewardOptOut` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_dapp_reward_opt_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 11_862_000 picoseconds.
+		Weight::from_parts(12_109_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::Ledger` (r:1 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ClaimReminderEra` (r:0 w:1)
+	/// Proof: `DappStaking::ClaimReminderEra` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_claim_reminder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3779`
+		// Minimum execution time: 10_412_000 picoseconds.
+		Weight::from_parts(10_739_000, 3779)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	fn set_dapp_owner() -> Weight {
@@ -130,17 +203,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppLifetimeRewards` (r:0 w:1)
+	/// Proof: `DappStaking::DAppLifetimeRewards` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `97`
 		//  Estimated: `3086`
 		// Minimum execution time: 14_595_000 picoseconds.
 		Weight::from_parts(14_935_000, 3086)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForIntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:1 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppLifetimeRewards` (r:0 w:1)
+	/// Proof: `DappStaking::DAppLifetimeRewards` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	fn unregister_inactive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 16_163_000 picoseconds.
+		Weight::from_parts(16_447_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -149,34 +253,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::AutoRelock` (r:1 w:0)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
 		//  Estimated: `4764`
 		// Minimum execution time: 31_874_000 picoseconds.
 		Weight::from_parts(32_108_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::AutoRelock` (r:1 w:0)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
 		//  Estimated: `4764`
 		// Minimum execution time: 32_204_000 picoseconds.
 		Weight::from_parts(32_658_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
@@ -193,7 +309,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
@@ -201,6 +317,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 16]`.
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::AutoRelock` (r:1 w:0)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn claim_unlocked(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `190`
@@ -209,11 +331,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(31_172_595, 4764)
 			// Standard Error: 2_859
 			.saturating_add(Weight::from_parts(124_029, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn reclaim_abandoned(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `293`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_204_000 picoseconds.
+		Weight::from_parts(60_377_916, 6196)
+			// Standard Error: 2_859
+			.saturating_add(Weight::from_parts(124_029, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
@@ -229,10 +377,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `DappStaking::PositionTransferApprovals` (r:0 w:1)
+	/// Proof: `DappStaking::PositionTransferApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn approve_position_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(9_204_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::PositionTransferApprovals` (r:1 w:1)
+	/// Proof: `DappStaking::PositionTransferApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:2 w:2)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:16 w:32)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn transfer_position(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `302 + x * (73 ±0)`
+		//  Estimated: `7496 + x * (2653 ±0)`
+		// Minimum execution time: 78_415_000 picoseconds.
+		Weight::from_parts(80_127_441, 7496)
+			// Standard Error: 9_812
+			.saturating_add(Weight::from_parts(6_904_318, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:1 w:1)
@@ -243,19 +428,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:1 w:0)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `274`
 		//  Estimated: `4764`
 		// Minimum execution time: 40_988_000 picoseconds.
 		Weight::from_parts(41_562_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:1 w:1)
@@ -266,17 +461,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn unstake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
 		//  Estimated: `4764`
 		// Minimum execution time: 45_212_000 picoseconds.
 		Weight::from_parts(45_611_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::EraRewards` (r:1 w:0)
 	/// Proof: `DappStaking::EraRewards` (`max_values`: None, `max_size`: Some(789), added: 3264, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::PeriodEnd` (r:1 w:0)
@@ -286,6 +487,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `541`
@@ -294,11 +497,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(50_088_222, 4764)
 			// Standard Error: 4_812
 			.saturating_add(Weight::from_parts(1_932_998, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::EraRewards` (r:1 w:0)
 	/// Proof: `DappStaking::EraRewards` (`max_values`: None, `max_size`: Some(789), added: 3264, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -306,6 +509,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `519`
@@ -314,67 +519,81 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(47_718_494, 4764)
 			// Standard Error: 3_774
 			.saturating_add(Weight::from_parts(1_922_497, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::PeriodEnd` (r:1 w:0)
 	/// Proof: `DappStaking::PeriodEnd` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
 		//  Estimated: `3775`
 		// Minimum execution time: 37_169_000 picoseconds.
 		Weight::from_parts(37_719_000, 3775)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:1 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:1 w:0)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppLifetimeRewards` (r:1 w:1)
+	/// Proof: `DappStaking::DAppLifetimeRewards` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
 	fn claim_dapp_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2672`
 		//  Estimated: `5113`
 		// Minimum execution time: 54_124_000 picoseconds.
 		Weight::from_parts(54_932_000, 5113)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn unstake_from_unregistered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `322`
 		//  Estimated: `4764`
 		// Minimum execution time: 37_145_000 picoseconds.
 		Weight::from_parts(37_697_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:17 w:16)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn cleanup_expired_entries(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `257 + x * (73 ±0)`
@@ -383,26 +602,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(34_175_483, 4764)
 			// Standard Error: 8_747
 			.saturating_add(Weight::from_parts(4_882_773, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
 	}
 	/// Storage: `DappStaking::Safeguard` (r:1 w:0)
 	/// Proof: `DappStaking::Safeguard` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastForcedSubperiodEra` (r:1 w:1)
+	/// Proof: `DappStaking::LastForcedSubperiodEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `1486`
 		// Minimum execution time: 8_714_000 picoseconds.
 		Weight::from_parts(8_924_000, 1486)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:2 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:2 w:2)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:2 w:2)
@@ -413,21 +635,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::MovesInPeriod` (r:1 w:1)
+	/// Proof: `DappStaking::MovesInPeriod` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn move_stake_from_registered_source() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `553`
 		//  Estimated: `6296`
 		// Minimum execution time: 71_782_000 picoseconds.
 		Weight::from_parts(72_398_000, 6296)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:2 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:2 w:2)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -436,14 +668,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:1 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::MovesInPeriod` (r:1 w:1)
+	/// Proof: `DappStaking::MovesInPeriod` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn move_stake_unregistered_source() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `419`
 		//  Estimated: `6296`
 		// Minimum execution time: 63_766_000 picoseconds.
 		Weight::from_parts(64_076_000, 6296)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
@@ -455,13 +697,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::TierConfig` (r:1 w:1)
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:0)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:1 w:0)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveCycleConfig` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize_voting_to_build_and_earn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `212`
 		//  Estimated: `4254`
 		// Minimum execution time: 26_591_000 picoseconds.
 		Weight::from_parts(27_310_000, 4254)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
@@ -480,14 +728,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:0 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:0)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:1 w:0)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:1 w:0)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::PendingCycleConfig` (r:1 w:1)
+	/// Proof: `DappStaking::PendingCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveCycleConfig` (r:1 w:1)
+	/// Proof: `DappStaking::ActiveCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Inflation::PendingRewardPoolTopUp` (r:1 w:1)
+	/// Proof: `Inflation::PendingRewardPoolTopUp` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize_build_and_earn_to_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `719`
 		//  Estimated: `4254`
 		// Minimum execution time: 42_054_000 picoseconds.
 		Weight::from_parts(43_015_000, 4254)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
@@ -501,19 +761,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:0 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:0)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:1 w:0)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:1 w:0)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveCycleConfig` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Inflation::PendingRewardPoolTopUp` (r:1 w:1)
+	/// Proof: `Inflation::PendingRewardPoolTopUp` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize_build_and_earn_to_build_and_earn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `264`
 		//  Estimated: `4254`
 		// Minimum execution time: 29_233_000 picoseconds.
 		Weight::from_parts(30_149_000, 4254)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::ContractStake` (r:101 w:0)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierCap` (r:100 w:0)
+	/// Proof: `DappStaking::DAppTierCap` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 100]`.
 	fn dapp_tier_assignment(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -524,9 +796,67 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 2_388
 			.saturating_add(Weight::from_parts(2_420_114, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierCap` (r:16 w:0)
+	/// Proof: `DappStaking::DAppTierCap` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_tier_assignment_from_snapshot(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132 + x * (18 ±0)`
+		//  Estimated: `3061`
+		// Minimum execution time: 7_912_000 picoseconds.
+		Weight::from_parts(9_874_320, 3061)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(612_483, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:17 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_stakes_snapshot(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152 + x * (33 ±0)`
+		//  Estimated: `1783 + x * (2071 ±0)`
+		// Minimum execution time: 6_482_000 picoseconds.
+		Weight::from_parts(7_210_442, 1783)
+			// Standard Error: 2_197
+			.saturating_add(Weight::from_parts(2_395_106, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
 	}
+	/// Storage: `DappStaking::ClaimReminderCursor` (r:1 w:1)
+	/// Proof: `DappStaking::ClaimReminderCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ClaimReminderEra` (r:17 w:16)
+	/// Proof: `DappStaking::ClaimReminderEra` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:16 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn process_claim_reminders(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143 + x * (134 ±0)`
+		//  Estimated: `3523 + x * (2789 ±0)`
+		// Minimum execution time: 5_923_000 picoseconds.
+		Weight::from_parts(6_418_305, 3523)
+			// Standard Error: 9_871
+			.saturating_add(Weight::from_parts(9_214_557, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2789).saturating_mul(x.into()))
+	}
 	/// Storage: `DappStaking::HistoryCleanupMarker` (r:1 w:1)
 	/// Proof: `DappStaking::HistoryCleanupMarker` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::EraRewards` (r:1 w:1)
@@ -552,6 +882,94 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(7_452_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:0 w:1)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	fn set_slots_per_tier_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_914_000 picoseconds.
+		Weight::from_parts(7_103_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:0 w:1)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_reward_split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_871_000 picoseconds.
+		Weight::from_parts(7_058_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	fn force_set_bonus_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `274`
+		//  Estimated: `3643`
+		// Minimum execution time: 11_942_000 picoseconds.
+		Weight::from_parts(12_306_000, 3643)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::AutoRelock` (r:0 w:1)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_auto_relock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_630_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::PendingCycleConfig` (r:0 w:1)
+	/// Proof: `DappStaking::PendingCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn schedule_cycle_config_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1498`
+		// Minimum execution time: 8_512_000 picoseconds.
+		Weight::from_parts(8_804_000, 1498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(133), added: 2113, mode: `MaxEncodedLen`)
+	fn set_dapp_min_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3103`
+		// Minimum execution time: 13_911_000 picoseconds.
+		Weight::from_parts(14_236_000, 3103)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(133), added: 2113, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoClaim` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoClaim` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn set_dapp_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3103`
+		// Minimum execution time: 11_702_000 picoseconds.
+		Weight::from_parts(12_013_000, 3103)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakeDelegate` (r:0 w:1)
+	/// Proof: `DappStaking::StakeDelegate` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_stake_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_503_000 picoseconds.
+		Weight::from_parts(7_721_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -569,6 +987,8 @@ impl WeightInfo for () {
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::NextDAppId` (r:1 w:1)
 	/// Proof: `DappStaking::NextDAppId` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -576,7 +996,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 12_235_000 picoseconds.
 		Weight::from_parts(12_512_000, 3086)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -589,6 +1009,58 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_dapp_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 11_862_000 picoseconds.
+		Weight::from_parts(12_109_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierCap` (r:0 w:1)
+	/// Proof: `DappStaking::DAppTierCap` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_tier_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 11_862_000 picoseconds.
+		Weight::from_parts(12_109_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppRewardOptOut` (r:0 w:1)
+	/// Proof: `DappStaking::DAppRewardOptOut` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_dapp_reward_opt_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 11_862_000 picoseconds.
+		Weight::from_parts(12_109_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::Ledger` (r:1 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ClaimReminderEra` (r:0 w:1)
+	/// Proof: `DappStaking::ClaimReminderEra` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_claim_reminder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3779`
+		// Minimum execution time: 10_412_000 picoseconds.
+		Weight::from_parts(10_739_000, 3779)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	fn set_dapp_owner() -> Weight {
@@ -606,17 +1078,48 @@ impl WeightInfo for () {
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppLifetimeRewards` (r:0 w:1)
+	/// Proof: `DappStaking::DAppLifetimeRewards` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `97`
 		//  Estimated: `3086`
 		// Minimum execution time: 14_595_000 picoseconds.
 		Weight::from_parts(14_935_000, 3086)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForIntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:1 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppLifetimeRewards` (r:0 w:1)
+	/// Proof: `DappStaking::DAppLifetimeRewards` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	fn unregister_inactive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 16_163_000 picoseconds.
+		Weight::from_parts(16_447_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -625,34 +1128,46 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::AutoRelock` (r:1 w:0)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
 		//  Estimated: `4764`
 		// Minimum execution time: 31_874_000 picoseconds.
 		Weight::from_parts(32_108_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::AutoRelock` (r:1 w:0)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
 		//  Estimated: `4764`
 		// Minimum execution time: 32_204_000 picoseconds.
 		Weight::from_parts(32_658_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
@@ -669,7 +1184,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
@@ -677,6 +1192,12 @@ impl WeightInfo for () {
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 16]`.
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::AutoRelock` (r:1 w:0)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn claim_unlocked(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `190`
@@ -685,11 +1206,37 @@ impl WeightInfo for () {
 		Weight::from_parts(31_172_595, 4764)
 			// Standard Error: 2_859
 			.saturating_add(Weight::from_parts(124_029, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	/// Storage: `DappStaking::LockedPerPurpose` (r:1 w:1)
+	/// Proof: `DappStaking::LockedPerPurpose` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn reclaim_abandoned(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `293`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_204_000 picoseconds.
+		Weight::from_parts(60_377_916, 6196)
+			// Standard Error: 2_859
+			.saturating_add(Weight::from_parts(124_029, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
@@ -705,10 +1252,47 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `DappStaking::PositionTransferApprovals` (r:0 w:1)
+	/// Proof: `DappStaking::PositionTransferApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn approve_position_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(9_204_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::PositionTransferApprovals` (r:1 w:1)
+	/// Proof: `DappStaking::PositionTransferApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:2 w:2)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:16 w:32)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn transfer_position(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `302 + x * (73 ±0)`
+		//  Estimated: `7496 + x * (2653 ±0)`
+		// Minimum execution time: 78_415_000 picoseconds.
+		Weight::from_parts(80_127_441, 7496)
+			// Standard Error: 9_812
+			.saturating_add(Weight::from_parts(6_904_318, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:1 w:1)
@@ -719,19 +1303,29 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:1 w:0)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `274`
 		//  Estimated: `4764`
 		// Minimum execution time: 40_988_000 picoseconds.
 		Weight::from_parts(41_562_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:1 w:1)
@@ -742,17 +1336,23 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn unstake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
 		//  Estimated: `4764`
 		// Minimum execution time: 45_212_000 picoseconds.
 		Weight::from_parts(45_611_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::EraRewards` (r:1 w:0)
 	/// Proof: `DappStaking::EraRewards` (`max_values`: None, `max_size`: Some(789), added: 3264, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::PeriodEnd` (r:1 w:0)
@@ -762,6 +1362,8 @@ impl WeightInfo for () {
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `541`
@@ -770,11 +1372,11 @@ impl WeightInfo for () {
 		Weight::from_parts(50_088_222, 4764)
 			// Standard Error: 4_812
 			.saturating_add(Weight::from_parts(1_932_998, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::EraRewards` (r:1 w:0)
 	/// Proof: `DappStaking::EraRewards` (`max_values`: None, `max_size`: Some(789), added: 3264, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -782,6 +1384,8 @@ impl WeightInfo for () {
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `519`
@@ -790,67 +1394,81 @@ impl WeightInfo for () {
 		Weight::from_parts(47_718_494, 4764)
 			// Standard Error: 3_774
 			.saturating_add(Weight::from_parts(1_922_497, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::PeriodEnd` (r:1 w:0)
 	/// Proof: `DappStaking::PeriodEnd` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
 		//  Estimated: `3775`
 		// Minimum execution time: 37_169_000 picoseconds.
 		Weight::from_parts(37_719_000, 3775)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:1 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:1)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoCompound` (r:1 w:0)
+	/// Proof: `DappStaking::DAppAutoCompound` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppLifetimeRewards` (r:1 w:1)
+	/// Proof: `DappStaking::DAppLifetimeRewards` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
 	fn claim_dapp_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2672`
 		//  Estimated: `5113`
 		// Minimum execution time: 54_124_000 picoseconds.
 		Weight::from_parts(54_932_000, 5113)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn unstake_from_unregistered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `322`
 		//  Estimated: `4764`
 		// Minimum execution time: 37_145_000 picoseconds.
 		Weight::from_parts(37_697_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:17 w:16)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn cleanup_expired_entries(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `257 + x * (73 ±0)`
@@ -859,26 +1477,29 @@ impl WeightInfo for () {
 		Weight::from_parts(34_175_483, 4764)
 			// Standard Error: 8_747
 			.saturating_add(Weight::from_parts(4_882_773, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
 	}
 	/// Storage: `DappStaking::Safeguard` (r:1 w:0)
 	/// Proof: `DappStaking::Safeguard` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastForcedSubperiodEra` (r:1 w:1)
+	/// Proof: `DappStaking::LastForcedSubperiodEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `1486`
 		// Minimum execution time: 8_714_000 picoseconds.
 		Weight::from_parts(8_924_000, 1486)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:2 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:2 w:2)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:2 w:2)
@@ -889,21 +1510,31 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::MovesInPeriod` (r:1 w:1)
+	/// Proof: `DappStaking::MovesInPeriod` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn move_stake_from_registered_source() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `553`
 		//  Estimated: `6296`
 		// Minimum execution time: 71_782_000 picoseconds.
 		Weight::from_parts(72_398_000, 6296)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:2 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::StakerInfo` (r:2 w:2)
 	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
-	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:1)
@@ -912,14 +1543,24 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:1 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LastStakedPeriod` (r:0 w:1)
+	/// Proof: `DappStaking::LastStakedPeriod` (`max_values`: None, `max_size`: Some(14), added: 1994, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::MovesInPeriod` (r:1 w:1)
+	/// Proof: `DappStaking::MovesInPeriod` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalStakers` (r:1 w:1)
+	/// Proof: `DappStaking::TotalStakers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn move_stake_unregistered_source() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `419`
 		//  Estimated: `6296`
 		// Minimum execution time: 63_766_000 picoseconds.
 		Weight::from_parts(64_076_000, 6296)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
@@ -931,13 +1572,19 @@ impl WeightInfo for () {
 	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::TierConfig` (r:1 w:1)
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:0)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:1 w:0)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveCycleConfig` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize_voting_to_build_and_earn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `212`
 		//  Estimated: `4254`
 		// Minimum execution time: 26_591_000 picoseconds.
 		Weight::from_parts(27_310_000, 4254)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
@@ -956,14 +1603,26 @@ impl WeightInfo for () {
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:0 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:0)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:1 w:0)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:1 w:0)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::PendingCycleConfig` (r:1 w:1)
+	/// Proof: `DappStaking::PendingCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveCycleConfig` (r:1 w:1)
+	/// Proof: `DappStaking::ActiveCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Inflation::PendingRewardPoolTopUp` (r:1 w:1)
+	/// Proof: `Inflation::PendingRewardPoolTopUp` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize_build_and_earn_to_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `719`
 		//  Estimated: `4254`
 		// Minimum execution time: 42_054_000 picoseconds.
 		Weight::from_parts(43_015_000, 4254)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
@@ -977,19 +1636,31 @@ impl WeightInfo for () {
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:0 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TotalRewardsPaid` (r:1 w:0)
+	/// Proof: `DappStaking::TotalRewardsPaid` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:1 w:0)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:1 w:0)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveCycleConfig` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Inflation::PendingRewardPoolTopUp` (r:1 w:1)
+	/// Proof: `Inflation::PendingRewardPoolTopUp` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize_build_and_earn_to_build_and_earn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `264`
 		//  Estimated: `4254`
 		// Minimum execution time: 29_233_000 picoseconds.
 		Weight::from_parts(30_149_000, 4254)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::ContractStake` (r:101 w:0)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
 	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierCap` (r:100 w:0)
+	/// Proof: `DappStaking::DAppTierCap` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 100]`.
 	fn dapp_tier_assignment(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1000,9 +1671,67 @@ impl WeightInfo for () {
 			// Standard Error: 2_388
 			.saturating_add(Weight::from_parts(2_420_114, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierCap` (r:16 w:0)
+	/// Proof: `DappStaking::DAppTierCap` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_tier_assignment_from_snapshot(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132 + x * (18 ±0)`
+		//  Estimated: `3061`
+		// Minimum execution time: 7_912_000 picoseconds.
+		Weight::from_parts(9_874_320, 3061)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(612_483, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::TierAssignmentSnapshot` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentSnapshot` (`max_values`: Some(1), `max_size`: Some(298), added: 793, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:17 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_stakes_snapshot(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152 + x * (33 ±0)`
+		//  Estimated: `1783 + x * (2071 ±0)`
+		// Minimum execution time: 6_482_000 picoseconds.
+		Weight::from_parts(7_210_442, 1783)
+			// Standard Error: 2_197
+			.saturating_add(Weight::from_parts(2_395_106, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
 	}
+	/// Storage: `DappStaking::ClaimReminderCursor` (r:1 w:1)
+	/// Proof: `DappStaking::ClaimReminderCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ClaimReminderEra` (r:17 w:16)
+	/// Proof: `DappStaking::ClaimReminderEra` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:16 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn process_claim_reminders(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143 + x * (134 ±0)`
+		//  Estimated: `3523 + x * (2789 ±0)`
+		// Minimum execution time: 5_923_000 picoseconds.
+		Weight::from_parts(6_418_305, 3523)
+			// Standard Error: 9_871
+			.saturating_add(Weight::from_parts(9_214_557, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2789).saturating_mul(x.into()))
+	}
 	/// Storage: `DappStaking::HistoryCleanupMarker` (r:1 w:1)
 	/// Proof: `DappStaking::HistoryCleanupMarker` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::EraRewards` (r:1 w:1)
@@ -1028,4 +1757,92 @@ impl WeightInfo for () {
 		Weight::from_parts(7_452_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DappStaking::SlotsPerTierOverride` (r:0 w:1)
+	/// Proof: `DappStaking::SlotsPerTierOverride` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	fn set_slots_per_tier_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_914_000 picoseconds.
+		Weight::from_parts(7_103_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::RewardPoolSplitOverride` (r:0 w:1)
+	/// Proof: `DappStaking::RewardPoolSplitOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_reward_split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_871_000 picoseconds.
+		Weight::from_parts(7_058_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	fn force_set_bonus_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `274`
+		//  Estimated: `3643`
+		// Minimum execution time: 11_942_000 picoseconds.
+		Weight::from_parts(12_306_000, 3643)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::AutoRelock` (r:0 w:1)
+	/// Proof: `DappStaking::AutoRelock` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_auto_relock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_630_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::PendingCycleConfig` (r:0 w:1)
+	/// Proof: `DappStaking::PendingCycleConfig` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn schedule_cycle_config_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1498`
+		// Minimum execution time: 8_512_000 picoseconds.
+		Weight::from_parts(8_804_000, 1498)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(133), added: 2113, mode: `MaxEncodedLen`)
+	fn set_dapp_min_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3103`
+		// Minimum execution time: 13_911_000 picoseconds.
+		Weight::from_parts(14_236_000, 3103)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(133), added: 2113, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppAutoClaim` (r:0 w:1)
+	/// Proof: `DappStaking::DAppAutoClaim` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	fn set_dapp_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3103`
+		// Minimum execution time: 11_702_000 picoseconds.
+		Weight::from_parts(12_013_000, 3103)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakeDelegate` (r:0 w:1)
+	/// Proof: `DappStaking::StakeDelegate` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_stake_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_503_000 picoseconds.
+		Weight::from_parts(7_721_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -87,6 +87,12 @@
 //! dApp rewards are paid out per dApp, _on-demand_. The reward is decided by the dApp staking protocol, or the tier system to be more precise.
 //! This pallet only provides the total reward pool for all dApps per era.
 //!
+//! ### Reward Pool Top-up
+//!
+//! Additional funds, e.g. a fraction of transaction fees routed via the `DappStakingRewardTopUp` adapter, can be used to top up the reward pools.
+//! The routed funds are burned and only accounted for, since rewards are minted when paid out.
+//! Accumulated top-up is taken by the dApp staking protocol at the end of the next build&earn era, and split between the staker & dApp reward pools.
+//!
 //! # Interface
 //!
 //! ## StakingRewardHandler
@@ -100,7 +106,8 @@ pub use pallet::*;
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, Observer as DappStakingObserver, StakingRewardHandler,
+        CycleConfiguration, EraNumber, Observer as DappStakingObserver, RewardPoolTopUp,
+        StakingRewardHandler,
     },
     Balance,
 };
@@ -193,6 +200,11 @@ pub mod pallet {
     #[pallet::whitelist_storage]
     pub type DoRecalculation<T: Config> = StorageValue<_, EraNumber, OptionQuery>;
 
+    /// Amount accumulated to top up the dApp staking reward pools, e.g. from routed transaction fees.
+    /// It's taken by dApp staking once the next era's reward pools are determined.
+    #[pallet::storage]
+    pub type PendingRewardPoolTopUp<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(DefaultNoBound)]
     pub struct GenesisConfig<T> {
@@ -547,6 +559,20 @@ pub mod pallet {
             let _ = T::Currency::deposit(account, reward, Precision::Exact);
             Ok(())
         }
+
+        fn take_reward_pool_top_up() -> Balance {
+            PendingRewardPoolTopUp::<T>::take()
+        }
+    }
+
+    impl<T: Config> RewardPoolTopUp for Pallet<T> {
+        fn top_up(amount: Balance) {
+            if !amount.is_zero() {
+                PendingRewardPoolTopUp::<T>::mutate(|pending| {
+                    *pending = pending.saturating_add(amount)
+                });
+            }
+        }
     }
 }
 
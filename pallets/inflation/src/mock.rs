@@ -23,14 +23,14 @@ use crate::{
 
 use frame_support::{
     construct_runtime, derive_impl, parameter_types,
-    traits::{fungible::Balanced, ConstU128, Hooks},
+    traits::{fungible::Balanced, ConstU128, Hooks, OnUnbalanced},
     weights::Weight,
     PalletId,
 };
 use sp_io::TestExternalities;
-use sp_runtime::{traits::AccountIdConversion, BuildStorage, Perquintill};
+use sp_runtime::{traits::AccountIdConversion, BuildStorage, Perbill, Perquintill};
 
-use astar_primitives::{Balance, BlockNumber};
+use astar_primitives::{dapp_staking::DappStakingRewardTopUp, Balance, BlockNumber};
 
 /// Initial inflation params set by the mock.
 pub const INIT_PARAMS: InflationParameters = InflationParameters {
@@ -82,6 +82,27 @@ impl PayoutPerBlock<CreditOf<Test>> for DummyPayoutPerBlock {
     }
 }
 
+parameter_types! {
+    pub const RewardTopUpFraction: Perbill = Perbill::from_percent(30);
+}
+
+/// Routes a fraction of the received funds into the reward pool top-up, the rest goes to the collator pot.
+pub type DummyRewardTopUp = DappStakingRewardTopUp<
+    <Test as frame_system::Config>::AccountId,
+    Balances,
+    Inflation,
+    RewardTopUpFraction,
+    DummyCollatorPot,
+>;
+
+pub struct DummyCollatorPot;
+impl OnUnbalanced<CreditOf<Test>> for DummyCollatorPot {
+    fn on_nonzero_unbalanced(amount: CreditOf<Test>) {
+        Balances::resolve(&COLLATOR_POT.into_account_truncating(), amount)
+            .expect("Must succeed for test.");
+    }
+}
+
 pub struct DummyCycleConfiguration;
 impl CycleConfiguration for DummyCycleConfiguration {
     fn periods_per_cycle() -> u32 {
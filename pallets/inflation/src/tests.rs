@@ -17,7 +17,10 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, Event, *};
-use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::Hooks};
+use frame_support::{
+    assert_noop, assert_ok, assert_storage_noop,
+    traits::{Hooks, OnUnbalanced},
+};
 use mock::*;
 use sp_runtime::{
     traits::{AccountIdConversion, BadOrigin, Zero},
@@ -455,6 +458,33 @@ fn basic_payout_reward_is_ok() {
     })
 }
 
+#[test]
+fn reward_pool_top_up_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let collator_pot = COLLATOR_POT.into_account_truncating();
+        let init_collator_balance = Balances::free_balance(&collator_pot);
+        let init_issuance = Balances::total_issuance();
+        assert!(PendingRewardPoolTopUp::<Test>::get().is_zero());
+
+        // Route fees, the fraction is burned and accounted for as a top-up
+        let fees = 1_000;
+        DummyRewardTopUp::on_unbalanced(Balances::issue(fees));
+        let top_up = RewardTopUpFraction::get() * fees;
+        assert_eq!(PendingRewardPoolTopUp::<Test>::get(), top_up);
+        assert_eq!(
+            Balances::free_balance(&collator_pot),
+            init_collator_balance + fees - top_up
+        );
+        assert_eq!(Balances::total_issuance(), init_issuance + fees - top_up);
+
+        // Top-ups accumulate until taken
+        DummyRewardTopUp::on_unbalanced(Balances::issue(fees));
+        assert_eq!(Inflation::take_reward_pool_top_up(), 2 * top_up);
+        assert!(PendingRewardPoolTopUp::<Test>::get().is_zero());
+        assert!(Inflation::take_reward_pool_top_up().is_zero());
+    })
+}
+
 #[test]
 fn cycle_configuration_works() {
     ExternalityBuilder::build().execute_with(|| {
@@ -93,6 +93,31 @@ benchmarks! {
         assert!(!AssetIdToLocation::<T>::contains_key(asset_id));
         assert!(!AssetLocationUnitsPerSecond::<T>::contains_key(asset_location.into_versioned()));
     }
+
+    set_reserve_override {
+        let asset_location = Location::parent();
+        let asset_id = T::AssetId::default();
+        let reserve_location = Location::new(1, [xcm::v5::Junction::Parachain(1000)]);
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+
+    }: _(RawOrigin::Root, asset_id, Box::new(reserve_location.clone().into_versioned()))
+    verify {
+        assert_eq!(ReserveOverride::<T>::get(asset_id), Some(reserve_location.into_versioned()));
+    }
+
+    clear_reserve_override {
+        let asset_location = Location::parent();
+        let asset_id = T::AssetId::default();
+        let reserve_location = Location::new(1, [xcm::v5::Junction::Parachain(1000)]);
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::set_reserve_override(RawOrigin::Root.into(), asset_id, Box::new(reserve_location.into_versioned()))?;
+
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!ReserveOverride::<T>::contains_key(asset_id));
+    }
 }
 
 #[cfg(test)]
@@ -37,6 +37,8 @@
 //! - `change_existing_asset_location` - changes the remote location of an existing local asset Id
 //! - `remove_payment_asset` - removes asset from the set of supported payment assets
 //! - `remove_asset` - removes all information related to this asset
+//! - `set_reserve_override` - sets an explicit reserve location for an asset
+//! - `clear_reserve_override` - clears the explicit reserve location of an asset
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive documentation.
 //!
@@ -50,6 +52,9 @@
 //! - `get_units_per_second`
 //!
 //! - `weight_to_fee` method is used to convert weight to fee based on units per second and weight.
+//!
+//! `ReserveLocationOverride` interface for fetching explicitly configured reserve location of an asset
+//! - `get_reserve_override`
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -87,6 +92,15 @@ pub mod pallet {
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
+    /// Execution fee for the given weight, at the given `units per second` rate.
+    ///
+    /// Only `ref_time` is charged, and the result is rounded down.
+    /// Shared by the fee queries and the weight trader, so fee quotes always match the charged amount.
+    pub fn weight_to_fee(weight: Weight, units_per_second: u128) -> u128 {
+        units_per_second.saturating_mul(weight.ref_time() as u128)
+            / (WEIGHT_REF_TIME_PER_SECOND as u128)
+    }
+
     /// Defines conversion between asset Id and cross-chain asset location
     pub trait XcAssetLocation<AssetId> {
         /// Get asset type from assetId
@@ -102,6 +116,12 @@ pub mod pallet {
         fn get_units_per_second(asset_location: Location) -> Option<u128>;
     }
 
+    /// Used to fetch explicitly configured reserve location of a cross-chain asset.
+    pub trait ReserveLocationOverride {
+        /// Returns reserve location override for the asset with the specified location, or `None` if there is no override.
+        fn get_reserve_override(asset_location: &Location) -> Option<Location>;
+    }
+
     impl<T: Config> XcAssetLocation<T::AssetId> for Pallet<T> {
         fn get_xc_asset_location(asset_id: T::AssetId) -> Option<Location> {
             AssetIdToLocation::<T>::get(asset_id).and_then(|x| x.try_into().ok())
@@ -118,11 +138,18 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> ReserveLocationOverride for Pallet<T> {
+        fn get_reserve_override(asset_location: &Location) -> Option<Location> {
+            AssetLocationToId::<T>::get(asset_location.clone().into_versioned())
+                .and_then(ReserveOverride::<T>::get)
+                .and_then(|x| x.try_into().ok())
+        }
+    }
+
     impl<T: Config> Pallet<T> {
         /// Convert weight to fee based on units per second and weight.
         pub fn weight_to_fee(weight: Weight, units_per_second: u128) -> u128 {
-            units_per_second.saturating_mul(weight.ref_time() as u128)
-                / (WEIGHT_REF_TIME_PER_SECOND as u128)
+            weight_to_fee(weight, units_per_second)
         }
     }
 
@@ -148,6 +175,8 @@ pub mod pallet {
         AssetDoesNotExist,
         /// Failed to convert to latest versioned Location
         MultiLocationNotSupported,
+        /// Asset has no reserve location override.
+        ReserveOverrideDoesNotExist,
     }
 
     #[allow(clippy::large_enum_variant)]
@@ -177,6 +206,13 @@ pub mod pallet {
             asset_location: VersionedLocation,
             asset_id: T::AssetId,
         },
+        /// Reserve location override has been set for an asset Id
+        ReserveOverrideSet {
+            asset_id: T::AssetId,
+            reserve_location: VersionedLocation,
+        },
+        /// Reserve location override has been cleared for an asset Id
+        ReserveOverrideCleared { asset_id: T::AssetId },
     }
 
     /// Mapping from an asset id to asset type.
@@ -201,6 +237,14 @@ pub mod pallet {
     pub type AssetLocationUnitsPerSecond<T: Config> =
         StorageMap<_, Twox64Concat, VersionedLocation, u128>;
 
+    /// Explicit reserve location for an asset Id.
+    /// Used for assets whose canonical reserve differs from the one derived from their location.
+    ///
+    /// If value doesn't exist here, reserve is derived from the asset location.
+    #[pallet::storage]
+    pub type ReserveOverride<T: Config> =
+        StorageMap<_, Twox64Concat, T::AssetId, VersionedLocation>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register new asset location to asset Id mapping.
@@ -338,6 +382,7 @@ pub mod pallet {
             AssetIdToLocation::<T>::remove(asset_id);
             AssetLocationToId::<T>::remove(&asset_location);
             AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+            ReserveOverride::<T>::remove(asset_id);
 
             Self::deposit_event(Event::AssetRemoved {
                 asset_id,
@@ -345,5 +390,56 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Set an explicit reserve location for the given asset Id.
+        ///
+        /// The override takes precedence over the reserve derived from the asset location.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_reserve_override())]
+        pub fn set_reserve_override(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            reserve_location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AssetIdToLocation::<T>::contains_key(asset_id),
+                Error::<T>::AssetDoesNotExist
+            );
+
+            let v5_reserve_loc = Location::try_from(*reserve_location)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+            let reserve_location = VersionedLocation::V5(v5_reserve_loc);
+
+            ReserveOverride::<T>::insert(asset_id, reserve_location.clone());
+
+            Self::deposit_event(Event::ReserveOverrideSet {
+                asset_id,
+                reserve_location,
+            });
+            Ok(())
+        }
+
+        /// Clear the explicit reserve location of the given asset Id.
+        ///
+        /// Reserve will again be derived from the asset location.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::clear_reserve_override())]
+        pub fn clear_reserve_override(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                ReserveOverride::<T>::contains_key(asset_id),
+                Error::<T>::ReserveOverrideDoesNotExist
+            );
+            ReserveOverride::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::ReserveOverrideCleared { asset_id });
+            Ok(())
+        }
     }
 }
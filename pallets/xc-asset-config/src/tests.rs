@@ -69,6 +69,20 @@ fn only_root_as_origin() {
             XcAssetConfig::remove_asset(RuntimeOrigin::signed(1), asset_id,),
             BadOrigin
         );
+
+        assert_noop!(
+            XcAssetConfig::set_reserve_override(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                Box::new(asset_location.clone()),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::clear_reserve_override(RuntimeOrigin::signed(1), asset_id),
+            BadOrigin
+        );
     })
 }
 
@@ -386,3 +400,89 @@ fn different_xcm_versions_are_ok() {
         );
     })
 }
+
+#[test]
+fn reserve_override_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Prepare location, Id and reserve
+        let asset_location = Location::new(1, [Parachain(2007), GeneralIndex(3)]);
+        let asset_id = 17;
+        let reserve_location = Location::new(1, [Parachain(1000)]);
+
+        // Override cannot be set for an asset which isn't registered
+        assert_noop!(
+            XcAssetConfig::set_reserve_override(
+                RuntimeOrigin::root(),
+                asset_id,
+                Box::new(reserve_location.clone().into_versioned()),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        assert!(XcAssetConfig::get_reserve_override(&asset_location).is_none());
+
+        // Set the override and verify it's returned via the public interface
+        assert_ok!(XcAssetConfig::set_reserve_override(
+            RuntimeOrigin::root(),
+            asset_id,
+            Box::new(reserve_location.clone().into_versioned()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveOverrideSet {
+                asset_id,
+                reserve_location: reserve_location.clone().into_versioned(),
+            },
+        ));
+        assert_eq!(
+            ReserveOverride::<Test>::get(asset_id),
+            Some(reserve_location.clone().into_versioned())
+        );
+        assert_eq!(
+            XcAssetConfig::get_reserve_override(&asset_location),
+            Some(reserve_location.clone())
+        );
+
+        // Clear the override
+        assert_ok!(XcAssetConfig::clear_reserve_override(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::ReserveOverrideCleared { asset_id },
+        ));
+        assert!(XcAssetConfig::get_reserve_override(&asset_location).is_none());
+
+        // Cannot clear a non-existing override
+        assert_noop!(
+            XcAssetConfig::clear_reserve_override(RuntimeOrigin::root(), asset_id),
+            Error::<Test>::ReserveOverrideDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn remove_asset_clears_reserve_override() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = Location::new(1, [Parachain(2007), GeneralIndex(3)]);
+        let asset_id = 17;
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        assert_ok!(XcAssetConfig::set_reserve_override(
+            RuntimeOrigin::root(),
+            asset_id,
+            Box::new(Location::new(1, [Parachain(1000)]).into_versioned()),
+        ));
+
+        assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id));
+        assert!(!ReserveOverride::<Test>::contains_key(asset_id));
+    })
+}
@@ -56,6 +56,8 @@ pub trait WeightInfo {
 	fn change_existing_asset_location() -> Weight;
 	fn remove_payment_asset() -> Weight;
 	fn remove_asset() -> Weight;
+	fn set_reserve_override() -> Weight;
+	fn clear_reserve_override() -> Weight;
 }
 
 /// Weight functions for `pallet_xc_asset_config`.
@@ -122,6 +124,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `XcAssetConfig::AssetLocationUnitsPerSecond` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `XcAssetConfig::AssetLocationToId` (r:0 w:1)
 	/// Proof: `XcAssetConfig::AssetLocationToId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcAssetConfig::ReserveOverride` (r:0 w:1)
+	/// Proof: `XcAssetConfig::ReserveOverride` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn remove_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `128`
@@ -130,7 +134,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(16_674_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `XcAssetConfig::AssetIdToLocation` (r:1 w:0)
+	/// Proof: `XcAssetConfig::AssetIdToLocation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcAssetConfig::ReserveOverride` (r:0 w:1)
+	/// Proof: `XcAssetConfig::ReserveOverride` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_reserve_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `128`
+		//  Estimated: `3593`
+		// Minimum execution time: 13_520_000 picoseconds.
+		Weight::from_parts(13_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcAssetConfig::ReserveOverride` (r:1 w:1)
+	/// Proof: `XcAssetConfig::ReserveOverride` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn clear_reserve_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3558`
+		// Minimum execution time: 12_913_000 picoseconds.
+		Weight::from_parts(13_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 3558))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
 // For backwards compatibility and tests
@@ -188,11 +218,33 @@ impl WeightInfo for () {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig ReserveOverride (r:0 w:1)
+	// Proof Skipped: XcAssetConfig ReserveOverride (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
 		// Minimum execution time: 16_674_000 picoseconds.
 		Weight::from_parts(16_674_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig ReserveOverride (r:0 w:1)
+	// Proof Skipped: XcAssetConfig ReserveOverride (max_values: None, max_size: None, mode: Measured)
+	fn set_reserve_override() -> Weight {
+		// Minimum execution time: 13_874_000 picoseconds.
+		Weight::from_parts(13_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig ReserveOverride (r:1 w:1)
+	// Proof Skipped: XcAssetConfig ReserveOverride (max_values: None, max_size: None, mode: Measured)
+	fn clear_reserve_override() -> Weight {
+		// Minimum execution time: 13_240_000 picoseconds.
+		Weight::from_parts(13_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 3558))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
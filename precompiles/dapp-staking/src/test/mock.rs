@@ -23,7 +23,7 @@ use frame_support::{
     assert_ok, construct_runtime, derive_impl, parameter_types,
     traits::{
         fungible::{Mutate as FunMutate, Unbalanced as FunUnbalanced},
-        ConstBool, ConstU128, ConstU64, Hooks,
+        ConstBool, ConstU128, ConstU64, GetDefault, Hooks,
     },
     weights::{RuntimeDbWeight, Weight},
 };
@@ -212,6 +212,10 @@ impl pallet_dapp_staking::BenchmarkHelper<MockSmartContract, AccountId>
     fn set_balance(_account: &AccountId, _amount: Balance) {}
 }
 
+parameter_types! {
+    pub AbandonedChunkBeneficiary: AccountId = AccountId::from([0xab; 32]);
+}
+
 impl pallet_dapp_staking::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -230,11 +234,36 @@ impl pallet_dapp_staking::Config for Test {
     type MaxUnlockingChunks = ConstU32<5>;
     type MinimumLockedAmount = ConstU128<10>;
     type UnlockingPeriod = ConstU32<2>;
+    type UnlockingPeriodFn = ();
     type MaxNumberOfStakedContracts = ConstU32<5>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierRewardRounding = ();
+    type TopTierBoost = ();
     type MaxBonusSafeMovesPerPeriod = ConstU8<0>;
+    type MinRegistrationEras = ConstU32<0>;
+    type InactivityUnregisterPeriods = GetDefault;
+    type KeeperClaimWindow = ConstU32<{ u32::MAX }>;
+    type MaxStakePerContract = GetDefault;
+    type MaxClaimRemindersPerEra = ConstU32<16>;
+    type LockUnlockDuringVoting = ConstBool<true>;
+    type AbandonedChunkEras = GetDefault;
+    type AbandonedChunkBeneficiary = AbandonedChunkBeneficiary;
+    type MaxDAppClaimBatch = ConstU32<16>;
+    type MaxMovesPerPeriod = GetDefault;
+    type FinalEraStakeGrace = ConstU128<0>;
+    type StakeIncrement = GetDefault;
+    type MaxTotalStakers = GetDefault;
+    type MaxAutoClaimsPerBlock = ConstU32<16>;
+    type LoyaltyBoost = GetDefault;
+    type PostLockMinFreeBalance = ConstU128<0>;
+    type MinSubperiodForceInterval = ConstU32<0>;
+    type ThresholdSmoothing = GetDefault;
+    type MaxLockedIssuanceFraction = GetDefault;
+    type LockToStakeDelayEras = ConstU32<0>;
+    type OptedOutRewardHandling = ();
+    type MaxEraRewardsPerClaim = ConstU32<8>;
     type WeightInfo = pallet_dapp_staking::weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
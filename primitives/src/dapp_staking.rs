@@ -20,11 +20,18 @@ use super::{Balance, BlockNumber};
 
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 
-use frame_support::pallet_prelude::{RuntimeDebug, Weight};
-use sp_arithmetic::ArithmeticError;
+use frame_support::{
+    pallet_prelude::{RuntimeDebug, Weight},
+    traits::{
+        fungible::{Balanced, Credit},
+        tokens::imbalance::{Imbalance, OnUnbalanced},
+        Get,
+    },
+};
+use sp_arithmetic::{ArithmeticError, Perbill, Permill};
 use sp_core::{DecodeWithMemTracking, H160};
 use sp_runtime::traits::Zero;
-use sp_std::hash::Hash;
+use sp_std::{collections::btree_map::BTreeMap, hash::Hash, marker::PhantomData, vec::Vec};
 
 /// Era number type
 pub type EraNumber = u32;
@@ -126,6 +133,46 @@ pub trait StakingRewardHandler<AccountId> {
 
     /// Attempts to pay out the rewards to the beneficiary.
     fn payout_reward(beneficiary: &AccountId, reward: Balance) -> Result<(), ()>;
+
+    /// Takes the amount accumulated so far to top up the reward pools, e.g. from routed transaction fees.
+    ///
+    /// Called once per era, when the era's staker & dApp reward pools are determined.
+    fn take_reward_pool_top_up() -> Balance {
+        Balance::zero()
+    }
+}
+
+/// Interface for topping up the dApp staking reward pools.
+pub trait RewardPoolTopUp {
+    /// Adds the specified amount to the reward pools of the next era in which rewards are distributed.
+    fn top_up(amount: Balance);
+}
+
+/// `OnUnbalanced` adapter which routes a fraction of the received funds into the dApp staking reward pools.
+///
+/// The routed fraction is burned and accounted for via `TopUp`, since rewards are minted when paid out.
+/// The remainder is passed on to the `Remainder` handler.
+pub struct DappStakingRewardTopUp<AccountId, Currency, TopUp, Fraction, Remainder>(
+    PhantomData<(AccountId, Currency, TopUp, Fraction, Remainder)>,
+);
+impl<AccountId, Currency, TopUp, Fraction, Remainder> OnUnbalanced<Credit<AccountId, Currency>>
+    for DappStakingRewardTopUp<AccountId, Currency, TopUp, Fraction, Remainder>
+where
+    Currency: Balanced<AccountId, Balance = Balance>,
+    TopUp: RewardPoolTopUp,
+    Fraction: Get<Perbill>,
+    Remainder: OnUnbalanced<Credit<AccountId, Currency>>,
+{
+    fn on_nonzero_unbalanced(amount: Credit<AccountId, Currency>) {
+        let top_up_amount = Fraction::get() * amount.peek();
+        let (top_up, remainder) = amount.split(top_up_amount);
+
+        // Burn the routed part, it's minted again once paid out as a reward.
+        TopUp::top_up(top_up.peek());
+        drop(top_up);
+
+        Remainder::on_unbalanced(remainder);
+    }
 }
 
 /// Trait defining the interface for dApp staking `smart contract types` handler.
@@ -136,6 +183,8 @@ pub trait SmartContractHandle<AccountId> {
     fn evm(address: H160) -> Self;
     /// Create a new smart contract representation for the specified Wasm address.
     fn wasm(address: AccountId) -> Self;
+    /// `true` if the smart contract handle is valid, i.e. doesn't point to the zero address.
+    fn is_valid(&self) -> bool;
 }
 
 /// Multi-VM pointer to smart contract instance.
@@ -159,7 +208,7 @@ pub enum SmartContract<AccountId> {
     Wasm(AccountId),
 }
 
-impl<AccountId> SmartContractHandle<AccountId> for SmartContract<AccountId> {
+impl<AccountId: Encode> SmartContractHandle<AccountId> for SmartContract<AccountId> {
     fn evm(address: H160) -> Self {
         Self::Evm(address)
     }
@@ -167,6 +216,13 @@ impl<AccountId> SmartContractHandle<AccountId> for SmartContract<AccountId> {
     fn wasm(address: AccountId) -> Self {
         Self::Wasm(address)
     }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Evm(address) => !address.is_zero(),
+            Self::Wasm(address) => address.using_encoded(|bytes| bytes.iter().any(|b| *b != 0)),
+        }
+    }
 }
 
 /// Used to check whether an account is allowed to participate in dApp staking or not.
@@ -181,6 +237,145 @@ impl<AccountId> AccountCheck<AccountId> for () {
     }
 }
 
+/// Used to determine the unlocking period, based on the amount being unlocked.
+pub trait UnlockingPeriodFn {
+    /// Number of blocks it takes for the unlocking `amount` to become claimable.
+    ///
+    /// `unlocking_period` is the standard unlocking period, expressed in the number of blocks.
+    fn unlocking_period(amount: Balance, unlocking_period: BlockNumber) -> BlockNumber;
+}
+
+/// Standard unlocking period, regardless of the unlocking amount.
+impl UnlockingPeriodFn for () {
+    fn unlocking_period(_amount: Balance, unlocking_period: BlockNumber) -> BlockNumber {
+        unlocking_period
+    }
+}
+
+/// Staking limits & constants, useful for validating user input before submitting a call.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct StakingConstants {
+    /// Minimum amount an account has to lock in dApp staking.
+    pub minimum_locked_amount: Balance,
+    /// Minimum amount staker can stake on a contract.
+    pub minimum_stake_amount: Balance,
+    /// Maximum amount of stake contract entries an account is allowed to have at once.
+    pub max_number_of_staked_contracts: u32,
+    /// Maximum number of unlocking chunks an account is allowed to have at once.
+    pub max_unlocking_chunks: u32,
+    /// Number of blocks it takes for the unlocking chunk to become claimable.
+    pub unlocking_period: BlockNumber,
+}
+
+/// Tier configuration in effect for the ongoing period, as used for the dApp tier assignment.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct TierConfigView {
+    /// Number of slots per tier, ordered from the best tier to the worst one.
+    pub slots_per_tier: Vec<u16>,
+    /// Minimum stake amount a dApp needs to enter each tier.
+    pub tier_thresholds: Vec<Balance>,
+    /// Portion of the dApp reward pool allocated to each tier.
+    pub reward_portion: Vec<Permill>,
+    /// Total number of slots across all tiers.
+    pub total_number_of_slots: u16,
+}
+
+/// dApp tier assignment & rewards for a finished era, as used when claiming dApp rewards.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct DAppTierRewardsView {
+    /// dApps and their ranked tiers. dApps which have already claimed their reward are excluded.
+    pub dapps: BTreeMap<DAppId, RankedTier>,
+    /// Reward for each tier, ordered from the best tier to the worst one.
+    pub rewards: Vec<Balance>,
+    /// Period during which the era ended.
+    pub period: PeriodNumber,
+    /// Reward per rank for each tier, ordered from the best tier to the worst one.
+    pub rank_rewards: Vec<Balance>,
+    /// Undistributed reward remainders, assigned to the top dApp of each tier.
+    pub remainders: BTreeMap<DAppId, Balance>,
+}
+
+/// Reason why staking would currently fail, as reported by the stake preflight check.
+///
+/// Each variant corresponds to the dApp staking pallet error of the same name.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum StakePreflightError {
+    /// Pallet is in maintenance mode.
+    Disabled,
+    /// Stake amount is zero.
+    ZeroAmount,
+    /// Smart contract isn't registered.
+    ContractNotFound,
+    /// Stake cannot be added since the period ends in the next era.
+    PeriodEndsInNextEra,
+    /// Past staker rewards must be claimed before staking again.
+    UnclaimedRewards,
+    /// Account doesn't have enough locked funds available for staking.
+    UnavailableStakeFunds,
+    /// Resulting stake on the contract would be below the minimum stake amount.
+    InsufficientStakeAmount,
+    /// Account would exceed the maximum number of staked contracts.
+    TooManyStakedContracts,
+    /// Contract's total stake would exceed the maximum allowed amount.
+    ContractStakeCapExceeded,
+    /// Part of the stake amount was locked too recently to be staked.
+    LockedFundsNotAged,
+    /// Maximum number of stakers has been reached, only existing stakers can stake.
+    StakerCapReached,
+    /// Any other, unexpected, failure.
+    Other,
+}
+
+/// Stake amount for a particular era & period, as exported by [`StakerStateExport`].
+#[derive(
+    Copy, Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo,
+)]
+pub struct StakeAmountExport {
+    /// Amount staked during the voting subperiod.
+    pub voting: Balance,
+    /// Amount staked during the build&earn subperiod.
+    pub build_and_earn: Balance,
+    /// Era to which the stake amount refers to.
+    pub era: EraNumber,
+    /// Period to which the stake amount refers to.
+    pub period: PeriodNumber,
+}
+
+/// Staking info of an account on a single smart contract, as exported by [`StakerStateExport`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct StakerContractExport<SmartContract> {
+    /// Smart contract on which the stake is placed.
+    pub smart_contract: SmartContract,
+    /// Amount staked before, if anything.
+    pub previous_staked: StakeAmountExport,
+    /// Staked amount.
+    pub staked: StakeAmountExport,
+    /// Raw bonus status, `0` means the bonus reward is forfeited.
+    pub bonus_status: u8,
+}
+
+/// Complete dApp staking state of a single account.
+///
+/// Contains everything needed to deterministically reconstruct the account's ledger & staking info entries,
+/// e.g. when verifying a migration.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct StakerStateExport<SmartContract> {
+    /// Active locked amount.
+    pub locked: Balance,
+    /// Unlocking chunks, as `(amount, unlock_block)` pairs.
+    pub unlocking: Vec<(Balance, BlockNumber)>,
+    /// Stake amount of the ledger.
+    pub staked: StakeAmountExport,
+    /// Stake amount of the ledger for the next era, if any.
+    pub staked_future: Option<StakeAmountExport>,
+    /// Number of staking info entries, as tracked by the ledger.
+    pub contract_stake_count: u32,
+    /// Tag used to categorize the locked funds.
+    pub purpose: [u8; 4],
+    /// All staking info entries of the account, in storage iteration order.
+    pub stakes: Vec<StakerContractExport<SmartContract>>,
+}
+
 /// Fixed number of tier slots used by dApp-staking recalculation.
 pub const FIXED_NUMBER_OF_TIER_SLOTS: u16 = 16;
 
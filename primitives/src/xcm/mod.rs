@@ -25,7 +25,12 @@
 //! - `AssetLocationIdConverter` - conversion between local asset Id and cross-chain asset multilocation
 //! - `FixedRateOfForeignAsset` - weight trader for execution payment in foreign asset
 //! - `ReserveAssetFilter` - used to check whether asset/origin are a valid reserve location
+//! - `OverridableReserveFilter` - same as `ReserveAssetFilter`, but respects explicitly configured reserve overrides
+//! - `RelayTokenFromAssetHub` - reserve provider which selects Asset Hub as the reserve of the relay chain token
+//! - `SiblingParachainReserve` - reserve provider which selects the sibling parachain as the reserve of its assets
+//! - `PreferredReserve` - deterministic reserve selection for assets matched by multiple reserve providers
 //! - `XcmFungibleFeeHandler` - used to handle XCM fee execution fees
+//! - `LocalAccountToSiblingLocation` - converts local account into its location as seen from a sibling parachain
 //!
 //! Please refer to implementation below for more info.
 //!
@@ -35,7 +40,6 @@ use crate::AccountId;
 use frame_support::{
     ensure,
     traits::{tokens::fungibles, Contains, ContainsPair, Get, ProcessMessageError},
-    weights::constants::WEIGHT_REF_TIME_PER_SECOND,
 };
 use sp_runtime::traits::{Bounded, Convert, MaybeEquivalence, Zero};
 use sp_std::marker::PhantomData;
@@ -48,7 +52,7 @@ use xcm_executor::traits::{MatchesFungibles, Properties, ShouldExecute, WeightTr
 // ORML imports
 use orml_traits::location::Reserve;
 
-use pallet_xc_asset_config::{ExecutionPaymentRate, XcAssetLocation};
+use pallet_xc_asset_config::{ExecutionPaymentRate, ReserveLocationOverride, XcAssetLocation};
 
 #[cfg(test)]
 mod tests;
@@ -125,8 +129,7 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> WeightTrader for FixedRateOfForeig
                 fun: Fungibility::Fungible(_),
             } => {
                 if let Some(units_per_second) = T::get_units_per_second(asset_location.clone()) {
-                    let amount = units_per_second.saturating_mul(weight.ref_time() as u128) // TODO: change this to u64?
-                        / (WEIGHT_REF_TIME_PER_SECOND as u128);
+                    let amount = pallet_xc_asset_config::weight_to_fee(weight, units_per_second);
                     if amount == 0 {
                         return Ok(payment);
                     }
@@ -168,8 +171,7 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> WeightTrader for FixedRateOfForeig
             self.asset_location_and_units_per_second.clone()
         {
             let weight = weight.min(self.weight);
-            let amount = units_per_second.saturating_mul(weight.ref_time() as u128)
-                / (WEIGHT_REF_TIME_PER_SECOND as u128);
+            let amount = pallet_xc_asset_config::weight_to_fee(weight, units_per_second);
 
             self.weight = self.weight.saturating_sub(weight);
             self.consumed = self.consumed.saturating_sub(amount);
@@ -200,16 +202,75 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> Drop for FixedRateOfForeignAsset<T
 /// Basically, we trust any cross-chain asset from any location to act as a reserve since
 /// in order to support the xc-asset, we need to first register it in the `XcAssetConfig` pallet.
 ///
-pub struct ReserveAssetFilter;
-impl ContainsPair<Asset, Location> for ReserveAssetFilter {
-    fn contains(asset: &Asset, origin: &Location) -> bool {
+/// Relay token (DOT/KSM) is only accepted from Asset Hub, while sibling parachain assets are
+/// accepted from the sibling parachain itself.
+pub type ReserveAssetFilter = PreferredReserve<RelayTokenFromAssetHub, SiblingParachainReserve>;
+
+/// Reserve provider which selects the sibling parachain as the reserve of its own assets.
+///
+/// No reserve is provided for any other asset.
+pub struct SiblingParachainReserve;
+impl Reserve for SiblingParachainReserve {
+    fn reserve(asset: &Asset) -> Option<Location> {
         let AssetId(location) = &asset.id;
         match (location.parents, location.first_interior()) {
-            // sibling parachain reserve
-            (1, Some(Parachain(id))) => origin == &Location::new(1, [Parachain(*id)]),
-            // relay token (DOT/KSM) - only Asset Hub is valid reserve now
-            (1, None) => origin == &Location::new(1, [Parachain(ASSET_HUB_PARA_ID)]),
-            _ => false,
+            (1, Some(Parachain(id))) => Some(Location::new(1, [Parachain(*id)])),
+            _ => None,
+        }
+    }
+}
+
+/// Reserve provider which selects Asset Hub as the reserve of the relay chain token (DOT/KSM).
+///
+/// No reserve is provided for any other asset.
+pub struct RelayTokenFromAssetHub;
+impl Reserve for RelayTokenFromAssetHub {
+    fn reserve(asset: &Asset) -> Option<Location> {
+        let AssetId(location) = &asset.id;
+        location
+            .contains_parents_only(1)
+            .then(|| Location::new(1, [Parachain(ASSET_HUB_PARA_ID)]))
+    }
+}
+
+/// Used to select the reserve of an asset which can be matched by more than one reserve provider.
+///
+/// `Preferred` is consulted first, and `Fallback` is only used if `Preferred` provides no reserve
+/// for the asset. This makes the selection deterministic, instead of relying on the implicit order
+/// of the providers. Combinators can be nested to express a longer list of preferences.
+///
+/// When used as a reserve filter, only the selected reserve is accepted as the origin.
+pub struct PreferredReserve<Preferred, Fallback>(PhantomData<(Preferred, Fallback)>);
+impl<Preferred: Reserve, Fallback: Reserve> Reserve for PreferredReserve<Preferred, Fallback> {
+    fn reserve(asset: &Asset) -> Option<Location> {
+        Preferred::reserve(asset).or_else(|| Fallback::reserve(asset))
+    }
+}
+
+impl<Preferred: Reserve, Fallback: Reserve> ContainsPair<Asset, Location>
+    for PreferredReserve<Preferred, Fallback>
+{
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        Self::reserve(asset).is_some_and(|reserve| &reserve == origin)
+    }
+}
+
+/// Used to determine whether the cross-chain asset is coming from a trusted reserve or not,
+/// taking into account explicitly configured reserve location overrides.
+///
+/// If the asset has a reserve override, only the override location is accepted as the reserve.
+/// Otherwise, `Reserves` filter is used, `ReserveAssetFilter` by default.
+pub struct OverridableReserveFilter<ReserveOverrides, Reserves = ReserveAssetFilter>(
+    PhantomData<(ReserveOverrides, Reserves)>,
+);
+impl<ReserveOverrides: ReserveLocationOverride, Reserves: ContainsPair<Asset, Location>>
+    ContainsPair<Asset, Location> for OverridableReserveFilter<ReserveOverrides, Reserves>
+{
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        let AssetId(location) = &asset.id;
+        match ReserveOverrides::get_reserve_override(location) {
+            Some(reserve) => origin == &reserve,
+            None => Reserves::contains(asset, origin),
         }
     }
 }
@@ -271,6 +332,28 @@ impl Convert<AccountId, Location> for AccountIdToMultiLocation {
     }
 }
 
+/// Convert local `AccountId` to `Location` as seen from a sibling parachain.
+///
+/// Produces `(Parent, Parachain(SelfParaId), AccountId32)`, which is useful when constructing
+/// transfers which target an account on this chain from another chain's perspective.
+pub struct LocalAccountToSiblingLocation<SelfParaId>(PhantomData<SelfParaId>);
+impl<SelfParaId: Get<u32>> Convert<AccountId, Location>
+    for LocalAccountToSiblingLocation<SelfParaId>
+{
+    fn convert(account: AccountId) -> Location {
+        Location::new(
+            1,
+            [
+                Parachain(SelfParaId::get()),
+                AccountId32 {
+                    network: None,
+                    id: account.into(),
+                },
+            ],
+        )
+    }
+}
+
 /// `Asset` reserve location provider.
 /// Converts self absolute location to relative location.
 pub struct AbsoluteAndRelativeReserveProvider<AbsoluteLocation>(PhantomData<AbsoluteLocation>);
@@ -17,7 +17,7 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
 use once_cell::unsync::Lazy;
 use sp_runtime::traits::{MaybeEquivalence, Zero};
 
@@ -70,6 +70,17 @@ impl ExecutionPaymentRate for ExecutionPayment {
     }
 }
 
+/// Helper struct used for testing `OverridableReserveFilter`
+struct ReserveOverrides;
+impl ReserveLocationOverride for ReserveOverrides {
+    fn get_reserve_override(asset_location: &Location) -> Option<Location> {
+        match asset_location {
+            a if a == &*PARACHAIN => Some(Location::new(1, [Parachain(ASSET_HUB_PARA_ID)])),
+            _ => None,
+        }
+    }
+}
+
 /// Execution fee for the specified weight, using provided `units_per_second`
 fn execution_fee(weight: Weight, units_per_second: u128) -> u128 {
     units_per_second * (weight.ref_time() as u128) / (WEIGHT_REF_TIME_PER_SECOND as u128)
@@ -435,3 +446,127 @@ fn reserve_asset_filter_accepts_asset_hub_as_reserve() {
 
     assert!(ReserveAssetFilter::contains(&multi_asset, &origin));
 }
+
+#[test]
+fn local_account_to_sibling_location_is_ok() {
+    const SELF_PARA_ID: u32 = 2006;
+    let account = AccountId::from([7u8; 32]);
+
+    let location =
+        LocalAccountToSiblingLocation::<frame_support::traits::ConstU32<SELF_PARA_ID>>::convert(
+            account.clone(),
+        );
+
+    assert_eq!(
+        location,
+        Location::new(
+            1,
+            [
+                Parachain(SELF_PARA_ID),
+                AccountId32 {
+                    network: None,
+                    id: account.into(),
+                },
+            ],
+        )
+    );
+    assert_eq!(location.parent_count(), 1);
+    assert_eq!(location.first_interior(), Some(&Parachain(SELF_PARA_ID)));
+}
+
+#[test]
+fn overridable_reserve_filter_respects_override() {
+    // Asset with an override, which would otherwise have its reserve derived as `Parachain(10)`
+    let overridden_asset = Asset {
+        id: xcm::latest::AssetId((*PARACHAIN).clone()),
+        fun: Fungibility::Fungible(123456),
+    };
+    let derived_reserve = Location::new(1, [Parachain(10)]);
+    let override_reserve = Location::new(1, [Parachain(ASSET_HUB_PARA_ID)]);
+
+    assert!(ReserveAssetFilter::contains(
+        &overridden_asset,
+        &derived_reserve
+    ));
+    assert!(!OverridableReserveFilter::<ReserveOverrides>::contains(
+        &overridden_asset,
+        &derived_reserve
+    ));
+    assert!(OverridableReserveFilter::<ReserveOverrides>::contains(
+        &overridden_asset,
+        &override_reserve
+    ));
+
+    // Asset without an override falls back to the derived reserve
+    let asset = Asset {
+        id: xcm::latest::AssetId(Location::new(1, [Parachain(20), GeneralIndex(30)])),
+        fun: Fungibility::Fungible(123456),
+    };
+    assert!(OverridableReserveFilter::<ReserveOverrides>::contains(
+        &asset,
+        &Location::new(1, [Parachain(20)])
+    ));
+    assert!(!OverridableReserveFilter::<ReserveOverrides>::contains(
+        &asset,
+        &override_reserve
+    ));
+}
+
+/// Helper struct used for testing `PreferredReserve`, treats the relay chain as the reserve of its token
+struct RelayChainReserve;
+impl Reserve for RelayChainReserve {
+    fn reserve(asset: &Asset) -> Option<Location> {
+        let AssetId(location) = &asset.id;
+        (location == &PARENT).then_some(PARENT)
+    }
+}
+
+#[test]
+fn preferred_reserve_selects_preferred_provider_for_overlapping_asset() {
+    // Relay token is matched by both providers
+    let relay_token = Asset {
+        id: xcm::latest::AssetId(PARENT),
+        fun: Fungibility::Fungible(123456),
+    };
+    let asset_hub = Location::new(1, [Parachain(ASSET_HUB_PARA_ID)]);
+    assert_eq!(
+        RelayTokenFromAssetHub::reserve(&relay_token),
+        Some(asset_hub.clone())
+    );
+    assert_eq!(RelayChainReserve::reserve(&relay_token), Some(PARENT));
+
+    // Asset Hub is preferred
+    type PreferAssetHub = PreferredReserve<RelayTokenFromAssetHub, RelayChainReserve>;
+    assert_eq!(
+        PreferAssetHub::reserve(&relay_token),
+        Some(asset_hub.clone())
+    );
+    assert!(PreferAssetHub::contains(&relay_token, &asset_hub));
+    assert!(!PreferAssetHub::contains(&relay_token, &PARENT));
+
+    // Reversing the preference selects the relay chain instead
+    type PreferRelay = PreferredReserve<RelayChainReserve, RelayTokenFromAssetHub>;
+    assert_eq!(PreferRelay::reserve(&relay_token), Some(PARENT));
+    assert!(PreferRelay::contains(&relay_token, &PARENT));
+    assert!(!PreferRelay::contains(&relay_token, &asset_hub));
+}
+
+#[test]
+fn preferred_reserve_uses_fallback_provider() {
+    // Sibling asset isn't handled by the preferred provider
+    let sibling_asset = Asset {
+        id: xcm::latest::AssetId(Location::new(1, [Parachain(20), GeneralIndex(30)])),
+        fun: Fungibility::Fungible(123456),
+    };
+    let sibling = Location::new(1, [Parachain(20)]);
+    assert!(RelayTokenFromAssetHub::reserve(&sibling_asset).is_none());
+
+    type Reserves = PreferredReserve<RelayTokenFromAssetHub, ReserveAssetFilter>;
+    assert_eq!(Reserves::reserve(&sibling_asset), Some(sibling.clone()));
+    assert!(Reserves::contains(&sibling_asset, &sibling));
+
+    // Neither provider handles the asset
+    type NoFallback = PreferredReserve<RelayTokenFromAssetHub, RelayChainReserve>;
+    assert!(NoFallback::reserve(&sibling_asset).is_none());
+    assert!(!NoFallback::contains(&sibling_asset, &sibling));
+}
@@ -39,7 +39,7 @@ use frame_support::{
     traits::{
         fungible::{Balanced, Credit, HoldConsideration},
         AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains,
-        EqualPrivilegeOnly, FindAuthor, Get, Imbalance, InsideBoth, InstanceFilter,
+        EqualPrivilegeOnly, FindAuthor, Get, GetDefault, Imbalance, InsideBoth, InstanceFilter,
         LinearStoragePrice, Nothing, OnFinalize, OnUnbalanced, Randomness, WithdrawReasons,
     },
     weights::{
@@ -87,8 +87,10 @@ use xcm_runtime_apis::{
 
 use astar_primitives::{
     dapp_staking::{
-        AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, EraNumber,
-        PeriodNumber, RankedTier, SmartContract, FIXED_NUMBER_OF_TIER_SLOTS,
+        AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, DAppTierRewardsView,
+        DappStakingRewardTopUp, EraNumber, PeriodNumber, RankedTier, SmartContract,
+        StakePreflightError, StakerStateExport, StakingConstants, TierConfigView, TierId,
+        FIXED_NUMBER_OF_TIER_SLOTS,
     },
     evm::{EVMFungibleAdapterWrapper, EvmRevertCodeHandler, TX_MAX_GAS_LIMIT},
     governance::{
@@ -422,6 +424,11 @@ impl pallet_preimage::Config for Runtime {
 
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 500 * ASTR;
+    pub const TierRewardRounding: pallet_dapp_staking::RewardRounding =
+        pallet_dapp_staking::RewardRounding::Truncate;
+    pub const OptedOutRewardHandling: pallet_dapp_staking::OptOutRewardHandling =
+        pallet_dapp_staking::OptOutRewardHandling::Redistribute;
+    pub const TopTierBoost: Permill = Permill::zero();
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -434,6 +441,8 @@ impl pallet_dapp_staking::BenchmarkHelper<SmartContract<AccountId>, AccountId>
         let id_bytes = id.to_le_bytes();
         let mut account = [0u8; 32];
         account[..id_bytes.len()].copy_from_slice(&id_bytes);
+        // Zero address isn't a valid smart contract
+        account[31] = 1;
 
         SmartContract::Wasm(AccountId::from(account))
     }
@@ -470,11 +479,36 @@ impl pallet_dapp_staking::Config for Runtime {
     type MaxUnlockingChunks = ConstU32<8>;
     type MinimumLockedAmount = MinimumStakingAmount;
     type UnlockingPeriod = ConstU32<9>;
+    type UnlockingPeriodFn = ();
     type MaxNumberOfStakedContracts = ConstU32<16>;
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierRewardRounding = TierRewardRounding;
+    type TopTierBoost = TopTierBoost;
     type MaxBonusSafeMovesPerPeriod = ConstU8<2>;
+    type MinRegistrationEras = ConstU32<0>;
+    type InactivityUnregisterPeriods = GetDefault;
+    type KeeperClaimWindow = ConstU32<{ u32::MAX }>;
+    type MaxStakePerContract = GetDefault;
+    type MaxClaimRemindersPerEra = ConstU32<16>;
+    type LockUnlockDuringVoting = ConstBool<true>;
+    type AbandonedChunkEras = GetDefault;
+    type AbandonedChunkBeneficiary = TreasuryAccountId;
+    type MaxDAppClaimBatch = ConstU32<16>;
+    type MaxMovesPerPeriod = GetDefault;
+    type FinalEraStakeGrace = ConstU128<0>;
+    type StakeIncrement = GetDefault;
+    type MaxTotalStakers = GetDefault;
+    type MaxAutoClaimsPerBlock = ConstU32<16>;
+    type LoyaltyBoost = GetDefault;
+    type PostLockMinFreeBalance = ConstU128<0>;
+    type MinSubperiodForceInterval = ConstU32<0>;
+    type ThresholdSmoothing = GetDefault;
+    type MaxLockedIssuanceFraction = GetDefault;
+    type LockToStakeDelayEras = ConstU32<0>;
+    type OptedOutRewardHandling = OptedOutRewardHandling;
+    type MaxEraRewardsPerClaim = ConstU32<16>;
     type WeightInfo = weights::pallet_dapp_staking::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = DAppStakingBenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -853,6 +887,15 @@ impl WeightToFeeT for XcmWeightToFee {
     }
 }
 
+parameter_types! {
+    /// Fraction of the burned transaction fees used to top up the dApp staking reward pools.
+    pub const FeeRewardPoolTopUp: Perbill = Perbill::zero();
+}
+
+/// Burns the received funds, except for the fraction routed into the dApp staking reward pools.
+pub type BurnWithRewardPoolTopUp =
+    DappStakingRewardTopUp<AccountId, Balances, Inflation, FeeRewardPoolTopUp, ()>;
+
 pub struct DealWithFees;
 impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
     fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = Credit<AccountId, Balances>>) {
@@ -863,8 +906,8 @@ impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
                 tips.merge_into(&mut collator);
             }
 
-            // burn part of the fees
-            drop(to_burn);
+            // burn part of the fees, except for the fraction routed into the dApp staking reward pools
+            <BurnWithRewardPoolTopUp as OnUnbalanced<_>>::on_unbalanced(to_burn);
 
             // pay fees to collator
             <CollatorRewardPot as OnUnbalanced<_>>::on_unbalanced(collator);
@@ -1355,10 +1398,14 @@ impl pallet_treasury::Config<MainTreasuryInst> for Runtime {
     type ApproveOrigin = EnsureRootOrTwoThirdsMainCouncil;
     type RejectOrigin = EnsureRootOrTwoThirdsMainCouncil;
 
+    // Any signed account can propose a spend, as long as it can cover the bond
+    type ProposeOrigin = EnsureSigned<AccountId>;
+
     type OnSlash = Treasury;
     type ProposalBond = ProposalBond;
     type ProposalBondMinimum = ConstU128<{ 100 * ASTR }>;
     type ProposalBondMaximum = ConstU128<{ 1000 * ASTR }>;
+    type MaxProposalFractionOfPot = ();
     type SpendPeriod = ConstU32<{ 7 * DAYS }>;
 
     // We don't do periodic burns of the treasury
@@ -1383,10 +1430,14 @@ impl pallet_treasury::Config<CommunityTreasuryInst> for Runtime {
     type ApproveOrigin = EnsureRootOrTwoThirdsCommunityCouncil;
     type RejectOrigin = EnsureRootOrTwoThirdsCommunityCouncil;
 
+    // Any signed account can propose a spend, as long as it can cover the bond
+    type ProposeOrigin = EnsureSigned<AccountId>;
+
     type OnSlash = CommunityTreasury;
     type ProposalBond = ProposalBond;
     type ProposalBondMinimum = ConstU128<{ 100 * ASTR }>;
     type ProposalBondMaximum = ConstU128<{ 1000 * ASTR }>;
+    type MaxProposalFractionOfPot = ();
     type SpendPeriod = ConstU32<{ 7 * DAYS }>;
 
     // We don't do periodic burns of the community treasury
@@ -1711,6 +1762,17 @@ pub type Migrations = (Unreleased, Permanent);
 pub type Unreleased = (
     cumulus_pallet_xcmp_queue::migration::v6::MigrateV5ToV6<Runtime>,
     cumulus_pallet_xcmp_queue::migration::v7::MigrateV6ToV7<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V12ToV13<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V13ToV14<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V14ToV15<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V15ToV16<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V16ToV17<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V17ToV18<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V18ToV19<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V19ToV20<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V20ToV21<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V21ToV22<Runtime>,
+    pallet_dapp_staking::migration::versioned_migrations::V22ToV23<Runtime>,
 );
 
 /// Migrations/checks that do not need to be versioned and can run on every upgrade.
@@ -2281,24 +2343,142 @@ impl_runtime_apis! {
 
     impl dapp_staking_runtime_api::DappStakingApi<Block> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
-            InflationCycleConfig::periods_per_cycle()
+            pallet_dapp_staking::ActiveCycleConfiguration::<Runtime>::periods_per_cycle()
         }
 
         fn eras_per_voting_subperiod() -> EraNumber {
-            InflationCycleConfig::eras_per_voting_subperiod()
+            pallet_dapp_staking::ActiveCycleConfiguration::<Runtime>::eras_per_voting_subperiod()
         }
 
         fn eras_per_build_and_earn_subperiod() -> EraNumber {
-            InflationCycleConfig::eras_per_build_and_earn_subperiod()
+            pallet_dapp_staking::ActiveCycleConfiguration::<Runtime>::eras_per_build_and_earn_subperiod()
         }
 
         fn blocks_per_era() -> BlockNumber {
-            InflationCycleConfig::blocks_per_era()
+            pallet_dapp_staking::ActiveCycleConfiguration::<Runtime>::blocks_per_era()
         }
 
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn estimate_apr(smart_contract: SmartContract<AccountId>, stake: Balance) -> Option<Perbill> {
+            DappStaking::estimate_apr(&smart_contract, stake)
+        }
+
+        fn staking_constants() -> StakingConstants {
+            DappStaking::staking_constants()
+        }
+
+        fn dapp_current_standing(smart_contract: SmartContract<AccountId>) -> Option<(Balance, Balance, Option<TierId>)> {
+            DappStaking::dapp_current_standing(&smart_contract)
+        }
+
+        fn bonus_status(account: AccountId, smart_contract: SmartContract<AccountId>) -> Option<u8> {
+            DappStaking::bonus_status(&account, &smart_contract)
+        }
+
+        fn period_era_range(period: PeriodNumber) -> Option<(EraNumber, EraNumber)> {
+            DappStaking::period_era_range(period)
+        }
+
+        fn tier_config() -> TierConfigView {
+            DappStaking::tier_config()
+        }
+
+        fn available_tier_slots() -> Vec<u16> {
+            DappStaking::available_tier_slots().into_inner()
+        }
+
+        fn history_cleanup_status() -> (EraNumber, EraNumber, u32) {
+            DappStaking::history_cleanup_status()
+        }
+
+        fn can_stake(
+            account: AccountId,
+            smart_contract: SmartContract<AccountId>,
+            amount: Balance,
+        ) -> Result<(), StakePreflightError> {
+            DappStaking::can_stake(&account, &smart_contract, amount)
+        }
+
+        fn total_unlocking() -> Balance {
+            DappStaking::total_unlocking()
+        }
+
+        fn dapp_lifetime_rewards(smart_contract: SmartContract<AccountId>) -> Balance {
+            DappStaking::dapp_lifetime_rewards(&smart_contract)
+        }
+
+        fn export_staker_state(account: AccountId) -> StakerStateExport<SmartContract<AccountId>> {
+            DappStaking::export_staker_state(&account)
+        }
+
+        fn claimed_eras(account: AccountId) -> Vec<EraNumber> {
+            DappStaking::claimed_eras(&account)
+        }
+
+        fn stake_needed_for_tier(tier_id: TierId) -> Option<Balance> {
+            DappStaking::stake_needed_for_tier(tier_id)
+        }
+
+        fn effective_staking_power(smart_contract: SmartContract<AccountId>) -> Option<(Balance, TierId)> {
+            DappStaking::effective_staking_power(&smart_contract)
+        }
+
+        fn stake_age(account: AccountId, smart_contract: SmartContract<AccountId>) -> Option<(EraNumber, u32)> {
+            DappStaking::stake_age(&account, &smart_contract)
+        }
+
+        fn stake_expiry_warning(account: AccountId) -> Vec<(SmartContract<AccountId>, PeriodNumber)> {
+            DappStaking::stake_expiry_warning(&account)
+        }
+
+        fn dapp_ids(contracts: Vec<SmartContract<AccountId>>) -> Vec<Option<DAppId>> {
+            DappStaking::dapp_ids(contracts)
+        }
+
+        fn dapps_by_owner(owner: AccountId) -> Vec<SmartContract<AccountId>> {
+            DappStaking::dapps_by_owner(&owner)
+        }
+
+        fn current_reward_pools() -> (Balance, Balance, Balance) {
+            DappStaking::current_reward_pools()
+        }
+
+        fn dapp_tier_rewards(era: EraNumber) -> Option<DAppTierRewardsView> {
+            DappStaking::dapp_tier_rewards(era)
+        }
+
+        fn retained_era_window() -> (EraNumber, EraNumber) {
+            DappStaking::retained_era_window()
+        }
+
+        fn reconcile_held_funds() -> (Balance, Balance, bool) {
+            DappStaking::reconcile_held_funds()
+        }
+
+        fn claimable_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<EraNumber> {
+            DappStaking::claimable_dapp_rewards(&smart_contract)
+        }
+
+        fn eras_until_subperiod_change() -> EraNumber {
+            DappStaking::eras_until_subperiod_change()
+        }
+    }
+
+    impl pallet_treasury_runtime_api::TreasuryApi<Block, AccountId, Balance, Address, BlockNumber> for Runtime {
+        fn pending_proposals() -> Vec<pallet_treasury_runtime_api::ProposalView<AccountId, Balance>> {
+            Treasury::pending_proposals()
+        }
+
+        fn resolve_beneficiary(source: Address) -> Option<AccountId> {
+            Treasury::resolve_beneficiary(source)
+        }
+
+        fn queued_spends() -> Vec<(pallet_treasury_runtime_api::ProposalIndex, Balance, Option<BlockNumber>)> {
+            Treasury::queued_spends()
+        }
     }
 
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
@@ -547,4 +547,63 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Calls below aren't benchmarked for this runtime yet, so the pallet's reference weights are used.
+	// They are replaced once this file is regenerated.
+	fn set_dapp_auto_compound() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_dapp_auto_compound()
+	}
+	fn set_tier_cap() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_tier_cap()
+	}
+	fn set_dapp_reward_opt_out() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_dapp_reward_opt_out()
+	}
+	fn set_claim_reminder() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_claim_reminder()
+	}
+	fn unregister_inactive() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::unregister_inactive()
+	}
+	fn reclaim_abandoned(x: u32, ) -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::reclaim_abandoned(x)
+	}
+	fn approve_position_transfer() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::approve_position_transfer()
+	}
+	fn transfer_position(x: u32, ) -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::transfer_position(x)
+	}
+	fn dapp_tier_assignment_from_snapshot(x: u32, ) -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::dapp_tier_assignment_from_snapshot(x)
+	}
+	fn dapp_stakes_snapshot(x: u32, ) -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::dapp_stakes_snapshot(x)
+	}
+	fn process_claim_reminders(x: u32, ) -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::process_claim_reminders(x)
+	}
+	fn set_slots_per_tier_override() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_slots_per_tier_override()
+	}
+	fn set_reward_split() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_reward_split()
+	}
+	fn force_set_bonus_status() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::force_set_bonus_status()
+	}
+	fn set_auto_relock() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_auto_relock()
+	}
+	fn schedule_cycle_config_change() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::schedule_cycle_config_change()
+	}
+	fn set_dapp_min_stake() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_dapp_min_stake()
+	}
+	fn set_dapp_auto_claim() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_dapp_auto_claim()
+	}
+	fn set_stake_delegate() -> Weight {
+		pallet_dapp_staking::weights::SubstrateWeight::<T>::set_stake_delegate()
+	}
 }
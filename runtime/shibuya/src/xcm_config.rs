@@ -56,7 +56,8 @@ use orml_xcm_support::DisabledParachainFee;
 // Astar imports
 use astar_primitives::xcm::{
     AbsoluteAndRelativeReserveProvider, AccountIdToMultiLocation, AllowTopLevelPaidExecutionFrom,
-    FixedRateOfForeignAsset, ReserveAssetFilter, XcmFungibleFeeHandler, MAX_ASSETS,
+    FixedRateOfForeignAsset, OverridableReserveFilter, PreferredReserve, RelayTokenFromAssetHub,
+    SiblingParachainReserve, XcmFungibleFeeHandler, MAX_ASSETS,
 };
 
 parameter_types! {
@@ -191,7 +192,11 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = ReserveAssetFilter;
+    // Asset Hub is preferred as the reserve of the relay token, the sibling parachain for its own assets.
+    type IsReserve = OverridableReserveFilter<
+        XcAssetConfig,
+        PreferredReserve<RelayTokenFromAssetHub, SiblingParachainReserve>,
+    >;
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = XcmBarrier;
@@ -31,7 +31,7 @@ use xcm::{
         Junctions::*,
         Location, Parent, Xcm, VERSION as V_5,
     },
-    VersionedAsset, VersionedAssetId, VersionedLocation, VersionedXcm,
+    IntoVersion, VersionedAsset, VersionedAssetId, VersionedLocation, VersionedXcm,
 };
 use xcm_runtime_apis::dry_run::runtime_decl_for_dry_run_api::DryRunApiV2;
 use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV2;
@@ -325,6 +325,46 @@ fn trusted_api_is_reserve_is_ok() {
     })
 }
 
+#[test]
+fn trusted_api_is_reserve_respects_reserve_override() {
+    new_test_ext().execute_with(|| {
+        let para_a: Location = (Parent, Parachain(1)).into();
+        let asset_hub: Location = (Parent, Parachain(1000)).into();
+        let para_a_asset: XcmAsset = (para_a.clone(), 10u128).into();
+        let is_trusted_reserve = |origin: &Location| {
+            Runtime::is_trusted_reserve(
+                VersionedAsset::V5(para_a_asset.clone()),
+                VersionedLocation::V5(origin.clone()),
+            )
+        };
+
+        let asset_id = 123;
+        prepare_asset(asset_id, VersionedLocation::V5(para_a.clone()), None);
+        assert_eq!(is_trusted_reserve(&para_a), Ok(true));
+        assert_eq!(is_trusted_reserve(&asset_hub), Ok(false));
+
+        // Once overridden, only the override location is a trusted reserve
+        assert_ok!(XcAssetConfig::set_reserve_override(
+            RuntimeOrigin::root(),
+            asset_id.into(),
+            Box::new(VersionedLocation::V5(asset_hub.clone())),
+        ));
+        assert_eq!(is_trusted_reserve(&para_a), Ok(false));
+        assert_eq!(is_trusted_reserve(&asset_hub), Ok(true));
+
+        // Older XCM versions are converted before the check
+        assert_eq!(
+            Runtime::is_trusted_reserve(
+                VersionedAsset::V5(para_a_asset.clone())
+                    .into_version(4)
+                    .unwrap(),
+                VersionedLocation::V5(asset_hub).into_version(4).unwrap(),
+            ),
+            Ok(true)
+        );
+    })
+}
+
 #[test]
 fn trusted_api_is_teleport_is_ok() {
     new_test_ext().execute_with(|| {
@@ -24,7 +24,7 @@ use frame_support::{
     parameter_types,
     traits::{
         AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains,
-        Disabled, Everything, InstanceFilter, Nothing,
+        Disabled, Everything, GetDefault, InstanceFilter, Nothing,
     },
     weights::{
         constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
@@ -63,7 +63,7 @@ use astar_primitives::{
     dapp_staking::{AccountCheck, CycleConfiguration, SmartContract, StakingRewardHandler},
     xcm::{
         AbsoluteAndRelativeReserveProvider, AllowTopLevelPaidExecutionFrom,
-        AssetLocationIdConverter, FixedRateOfForeignAsset, ReserveAssetFilter,
+        AssetLocationIdConverter, FixedRateOfForeignAsset, OverridableReserveFilter,
         XcmFungibleFeeHandler,
     },
 };
@@ -451,7 +451,7 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = ReserveAssetFilter;
+    type IsReserve = OverridableReserveFilter<XcAssetConfig>;
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = XcmBarrier;
@@ -649,11 +649,36 @@ impl pallet_dapp_staking::Config for Runtime {
     type MaxUnlockingChunks = ConstU32<5>;
     type MinimumLockedAmount = ConstU128<3>;
     type UnlockingPeriod = ConstU32<2>;
+    type UnlockingPeriodFn = ();
     type MaxNumberOfStakedContracts = ConstU32<5>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierRewardRounding = ();
+    type TopTierBoost = ();
     type MaxBonusSafeMovesPerPeriod = ConstU8<0>;
+    type MinRegistrationEras = ConstU32<0>;
+    type InactivityUnregisterPeriods = GetDefault;
+    type KeeperClaimWindow = ConstU32<{ u32::MAX }>;
+    type MaxStakePerContract = GetDefault;
+    type MaxClaimRemindersPerEra = ConstU32<16>;
+    type LockUnlockDuringVoting = ConstBool<true>;
+    type AbandonedChunkEras = GetDefault;
+    type AbandonedChunkBeneficiary = TreasuryAccountId;
+    type MaxDAppClaimBatch = ConstU32<16>;
+    type MaxMovesPerPeriod = GetDefault;
+    type FinalEraStakeGrace = ConstU128<0>;
+    type StakeIncrement = GetDefault;
+    type MaxTotalStakers = GetDefault;
+    type MaxAutoClaimsPerBlock = ConstU32<16>;
+    type LoyaltyBoost = GetDefault;
+    type PostLockMinFreeBalance = ConstU128<0>;
+    type MinSubperiodForceInterval = ConstU32<0>;
+    type ThresholdSmoothing = GetDefault;
+    type MaxLockedIssuanceFraction = GetDefault;
+    type LockToStakeDelayEras = ConstU32<0>;
+    type OptedOutRewardHandling = ();
+    type MaxEraRewardsPerClaim = ConstU32<1>;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;